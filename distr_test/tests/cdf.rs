@@ -8,7 +8,7 @@
 
 use core::f64;
 
-use special::{Beta, Gamma, Primitive};
+use rand_distr::special::{beta_p, gamma_p, ln_gamma};
 use statrs::distribution::ContinuousCDF;
 use statrs::distribution::DiscreteCDF;
 
@@ -213,7 +213,7 @@ fn gamma() {
             return 0.0;
         }
 
-        (x / scale).inc_gamma(shape)
+        gamma_p(shape, x / scale)
     }
 
     let parameters = [
@@ -237,7 +237,7 @@ fn chi_squared() {
             return 0.0;
         }
 
-        (x / 2.0).inc_gamma(k / 2.0)
+        gamma_p(k / 2.0, x / 2.0)
     }
 
     let parameters = [0.1, 1.0, 2.0, 10.0, 100.0, 1000.0];
@@ -251,7 +251,7 @@ fn chi_squared() {
 fn studend_t() {
     fn cdf(x: f64, df: f64) -> f64 {
         let h = df / (df + x.powi(2));
-        let ib = 0.5 * h.inc_beta(df / 2.0, 0.5, 0.5.ln_beta(df / 2.0));
+        let ib = 0.5 * beta_p(df / 2.0, 0.5, h);
         if x < 0.0 {
             ib
         } else {
@@ -276,7 +276,7 @@ fn fisher_f() {
             let k = m * x / (m * x + n);
             let d1 = m / 2.0;
             let d2 = n / 2.0;
-            k.inc_beta(d1, d2, d1.ln_beta(d2))
+            beta_p(d1, d2, k)
         }
     }
 
@@ -297,8 +297,7 @@ fn beta() {
         if x > 1.0 {
             return 1.0;
         }
-        let ln_beta_ab = alpha.ln_beta(beta);
-        x.inc_beta(alpha, beta, ln_beta_ab)
+        beta_p(alpha, beta, x)
     }
 
     let parameters = [(0.5, 0.5), (2.0, 3.5), (10.0, 1.0), (100.0, 50.0)];
@@ -351,9 +350,7 @@ fn binomial_cdf(k: i64, p: f64, n: u64) -> f64 {
 
     let q = 1.0 - p;
 
-    let ln_beta_ab = a.ln_beta(b);
-
-    q.inc_beta(a, b, ln_beta_ab)
+    beta_p(a, b, q)
 }
 
 #[test]
@@ -446,9 +443,215 @@ fn poisson() {
 }
 
 fn ln_factorial(n: u64) -> f64 {
-    (n as f64 + 1.0).lgamma().0
+    ln_gamma(n as f64 + 1.0)
 }
 
 fn ln_binomial(n: u64, k: u64) -> f64 {
     ln_factorial(n) - ln_factorial(k) - ln_factorial(n - k)
 }
+
+/// Compares the crate's own `Cdf` implementations with `statrs` at the given
+/// points, in both tails.
+fn check_continuous_cdf(
+    dist: impl rand_distr::Cdf<f64>,
+    analytic: impl ContinuousCDF<f64, f64>,
+    points: &[f64],
+) {
+    for &x in points {
+        let (cdf, sf) = (dist.cdf(x), dist.sf(x));
+        let (expected_cdf, expected_sf) = (analytic.cdf(x), analytic.sf(x));
+        assert!(
+            (cdf - expected_cdf).abs() < 1e-10,
+            "x = {}: cdf = {}, expected {}",
+            x,
+            cdf,
+            expected_cdf
+        );
+        assert!(
+            (sf - expected_sf).abs() < 1e-10,
+            "x = {}: sf = {}, expected {}",
+            x,
+            sf,
+            expected_sf
+        );
+        assert!(
+            (cdf + sf - 1.0).abs() < 1e-14,
+            "x = {}: cdf = {}, sf = {}",
+            x,
+            cdf,
+            sf
+        );
+    }
+}
+
+fn check_discrete_cdf(
+    dist: impl rand_distr::Cdf<f64>,
+    analytic: impl DiscreteCDF<u64, f64>,
+    points: &[u64],
+) {
+    for &k in points {
+        let (cdf, sf) = (dist.cdf(k as f64), dist.sf(k as f64));
+        let (expected_cdf, expected_sf) = (analytic.cdf(k), analytic.sf(k));
+        assert!(
+            (cdf - expected_cdf).abs() < 1e-10,
+            "k = {}: cdf = {}, expected {}",
+            k,
+            cdf,
+            expected_cdf
+        );
+        assert!(
+            (sf - expected_sf).abs() < 1e-10,
+            "k = {}: sf = {}, expected {}",
+            k,
+            sf,
+            expected_sf
+        );
+        assert!(
+            (cdf + sf - 1.0).abs() < 1e-14,
+            "k = {}: cdf = {}, sf = {}",
+            k,
+            cdf,
+            sf
+        );
+        // Discrete distribution functions are step functions
+        assert_eq!(dist.cdf(k as f64 + 0.5), cdf);
+    }
+}
+
+#[test]
+fn in_crate_cdf() {
+    use statrs::distribution as sd;
+    let points = [0.01, 0.1, 0.5, 0.9, 1.0, 1.5, 2.0, 3.0, 5.0, 10.0, 30.0];
+    let signed = [
+        -30.0, -5.0, -1.5, -0.5, -0.01, 0.0, 0.01, 0.5, 1.5, 5.0, 30.0,
+    ];
+
+    for &(mean, std_dev) in &[(0.0, 1.0), (1.0, 10.0), (-1.0, 0.1)] {
+        check_continuous_cdf(
+            rand_distr::Normal::new(mean, std_dev).unwrap(),
+            sd::Normal::new(mean, std_dev).unwrap(),
+            &signed,
+        );
+        check_continuous_cdf(
+            rand_distr::LogNormal::new(mean, std_dev).unwrap(),
+            sd::LogNormal::new(mean, std_dev).unwrap(),
+            &points,
+        );
+        check_continuous_cdf(
+            rand_distr::Cauchy::new(mean, std_dev).unwrap(),
+            sd::Cauchy::new(mean, std_dev).unwrap(),
+            &signed,
+        );
+    }
+    for &(a, b) in &[(0.5, 2.0), (1.0, 1.0), (3.0, 0.2), (50.0, 1.0)] {
+        check_continuous_cdf(
+            rand_distr::Gamma::new(a, b).unwrap(),
+            sd::Gamma::new(a, 1.0 / b).unwrap(),
+            &points,
+        );
+        check_continuous_cdf(
+            rand_distr::Weibull::new(b, a).unwrap(),
+            sd::Weibull::new(a, b).unwrap(),
+            &points,
+        );
+        check_continuous_cdf(
+            rand_distr::Pareto::new(b, a).unwrap(),
+            sd::Pareto::new(b, a).unwrap(),
+            &points,
+        );
+        check_continuous_cdf(
+            rand_distr::FisherF::new(a, b).unwrap(),
+            sd::FisherSnedecor::new(a, b).unwrap(),
+            &points,
+        );
+        check_continuous_cdf(
+            rand_distr::Beta::new(a, b).unwrap(),
+            sd::Beta::new(a, b).unwrap(),
+            &[0.0, 0.001, 0.1, 0.5, 0.9, 0.999, 1.0],
+        );
+    }
+    for &dof in &[0.5, 1.0, 3.0, 30.0, 1000.0] {
+        check_continuous_cdf(
+            rand_distr::ChiSquared::new(dof).unwrap(),
+            sd::ChiSquared::new(dof).unwrap(),
+            &points,
+        );
+        check_continuous_cdf(
+            rand_distr::StudentT::new(dof).unwrap(),
+            sd::StudentsT::new(0.0, 1.0, dof).unwrap(),
+            &signed,
+        );
+        check_continuous_cdf(
+            rand_distr::Exp::new(dof).unwrap(),
+            sd::Exp::new(dof).unwrap(),
+            &points,
+        );
+    }
+
+    let counts = [0, 1, 2, 5, 10, 20, 50, 100];
+    for &(p, n) in &[(0.5, 10), (0.1, 100), (0.9999, 2), (0.3, 1000)] {
+        check_discrete_cdf(
+            rand_distr::Binomial::new(n, p).unwrap(),
+            sd::Binomial::new(p, n).unwrap(),
+            &counts,
+        );
+    }
+    for &lambda in &[0.1, 1.0, 7.5, 45.0, 230.0] {
+        check_discrete_cdf(
+            rand_distr::Poisson::new(lambda).unwrap(),
+            sd::Poisson::new(lambda).unwrap(),
+            &counts,
+        );
+    }
+    for &(n, k, n_) in &[(15, 13, 10), (60, 10, 7), (70, 20, 50), (100, 50, 49)] {
+        check_discrete_cdf(
+            rand_distr::Hypergeometric::new(n, k, n_).unwrap(),
+            sd::Hypergeometric::new(n, k, n_).unwrap(),
+            &counts,
+        );
+    }
+}
+
+#[test]
+fn inverse_gaussian() {
+    use rand_distr::{Cdf, InverseGaussian};
+    let parameters = [(1.0, 1.0), (1.0, 0.1), (0.5, 20.0), (10.0, 3.0)];
+
+    for (seed, (mean, shape)) in parameters.into_iter().enumerate() {
+        let dist = InverseGaussian::new(mean, shape).unwrap();
+        test_continuous(seed as u64, dist, |x| dist.cdf(x));
+    }
+}
+
+#[test]
+fn pert() {
+    use rand_distr::{Cdf, Pert};
+    let parameters = [(-1.0, 3.0, 0.0), (0.0, 1.0, 0.5), (1.0, 2.0, 2.0)];
+
+    for (seed, (min, max, mode)) in parameters.into_iter().enumerate() {
+        let dist = Pert::new(min, max).with_mode(mode).unwrap();
+        test_continuous(seed as u64, dist, |x| dist.cdf(x));
+    }
+}
+
+#[test]
+fn inversion_sampling() {
+    use rand::distr::{Distribution, Open01};
+    use rand_distr::{Beta, Binomial, Cdf, Gamma, Poisson, Quantile, StudentT};
+
+    let gamma = Gamma::new(0.5, 2.0).unwrap();
+    test_continuous(0, Open01.map(|u| gamma.quantile(u)), |x| gamma.cdf(x));
+    let beta = Beta::new(0.5, 3.0).unwrap();
+    test_continuous(1, Open01.map(|u| beta.quantile(u)), |x| beta.cdf(x));
+    let t = StudentT::new(3.0).unwrap();
+    test_continuous(2, Open01.map(|u| t.quantile(u)), |x| t.cdf(x));
+
+    let poisson = Poisson::new(7.5).unwrap();
+    test_discrete(3, Open01.map(|u| poisson.quantile(u) as u64), |k| {
+        poisson.cdf(k as f64)
+    });
+    let binomial = Binomial::new(100, 0.1).unwrap();
+    test_discrete(4, Open01.map(|u| binomial.quantile(u) as u64), |k| {
+        binomial.cdf(k as f64)
+    });
+}
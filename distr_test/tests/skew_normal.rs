@@ -8,7 +8,7 @@
 
 mod ks;
 use ks::test_continuous;
-use special::Primitive;
+use rand_distr::special::erfc;
 
 #[test]
 fn skew_normal() {
@@ -257,7 +257,7 @@ fn owen_t(h: f64, a: f64) -> f64 {
 }
 
 fn normal_cdf(x: f64, mean: f64, std_dev: f64) -> f64 {
-    0.5 * ((mean - x) / (std_dev * core::f64::consts::SQRT_2)).erfc()
+    0.5 * erfc((mean - x) / (std_dev * core::f64::consts::SQRT_2))
 }
 
 /// standard normal cdf
@@ -12,7 +12,7 @@ use ks::test_discrete;
 #[test]
 fn zeta() {
     fn cdf(k: i64, s: f64) -> f64 {
-        use spfunc::zeta::zeta as zeta_func;
+        use rand_distr::special::zeta as zeta_func;
         if k < 1 {
             return 0.0;
         }
@@ -9,8 +9,17 @@
 
 //! The Beta distribution.
 
-use crate::{Distribution, Open01};
+use crate::divergence::dirichlet_kl;
+use crate::entropy::dirichlet_entropy;
+use crate::fit::{dirichlet_moments, fit_dirichlet, Welford};
+use crate::quantile::{invert_cdf, order_quantiles, solve_increasing};
+use crate::special::{beta_pq, ln_beta, ln_binomial_raw, std_normal_quantile};
+use crate::{
+    Cdf, ContinuousPdf, Distribution, Entropy, FitError, FitOptions, Fitted, KlDivergence, Moments,
+    Open01, Quantile, Summary, Support,
+};
 use core::fmt;
+use core::ops::Bound;
 use num_traits::Float;
 use rand::Rng;
 #[cfg(feature = "serde")]
@@ -89,14 +98,22 @@ where
     algorithm: BetaAlgorithm<F>,
 }
 
-/// Error type returned from [`Beta::new`].
+/// Error type returned from [`Beta::new`], [`Beta::from_mean_variance`] and
+/// [`Beta::from_quantiles`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum Error {
     /// `alpha <= 0` or `nan`.
     AlphaTooSmall,
     /// `beta <= 0` or `nan`.
     BetaTooSmall,
+    /// `mean <= 0`, `mean >= 1` or `nan`.
+    MeanOutOfRange,
+    /// `variance <= 0`, `variance >= mean * (1 - mean)` or `nan`.
+    VarianceOutOfRange,
+    /// The quantiles are invalid or not achievable.
+    QuantilesOutOfRange,
 }
 
 impl fmt::Display for Error {
@@ -104,6 +121,11 @@ impl fmt::Display for Error {
         f.write_str(match self {
             Error::AlphaTooSmall => "alpha is not positive in beta distribution",
             Error::BetaTooSmall => "beta is not positive in beta distribution",
+            Error::MeanOutOfRange => "mean is not in (0, 1) in beta distribution",
+            Error::VarianceOutOfRange => {
+                "variance is not in (0, mean * (1 - mean)) in beta distribution"
+            }
+            Error::QuantilesOutOfRange => "quantiles are not achievable in beta distribution",
         })
     }
 }
@@ -177,6 +199,165 @@ where
             })
         }
     }
+
+    /// Construct a beta distribution with the given `mean` and `variance`.
+    ///
+    /// With the precision `ν = mean (1 - mean) / variance - 1`, the
+    /// parameters are `alpha = mean ν` and `beta = (1 - mean) ν`. This
+    /// requires `0 < mean < 1` and `0 < variance < mean (1 - mean)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Beta;
+    ///
+    /// let beta = Beta::from_mean_variance(0.25, 0.0375).unwrap();
+    /// assert_eq!(beta, Beta::new(1.0, 3.0).unwrap());
+    /// ```
+    pub fn from_mean_variance(mean: F, variance: F) -> Result<Beta<F>, Error> {
+        if !(mean > F::zero() && mean < F::one()) {
+            return Err(Error::MeanOutOfRange);
+        }
+        let q = F::one() - mean;
+        let max_variance = mean * q;
+        if !(variance > F::zero() && variance < max_variance) {
+            return Err(Error::VarianceOutOfRange);
+        }
+        let precision = (max_variance - variance) / variance;
+        Beta::new(mean * precision, q * precision)
+    }
+
+    /// Construct a beta distribution from two quantiles.
+    ///
+    /// Each of `a` and `b` is a pair `(p, x)` requiring the quantile at
+    /// probability `p` to be `x`, for example `(0.5, median)`. The
+    /// probabilities must be distinct and in `(0, 1)`, and the values in
+    /// `(0, 1)` and in the same order as the probabilities.
+    ///
+    /// Both parameters are found numerically: for each `alpha` there is a
+    /// `beta` matching the first quantile, and along these distributions the
+    /// second quantile moves towards the first as they become more
+    /// concentrated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Beta, Quantile};
+    ///
+    /// // A success rate of about 20%, and below 40% with probability 0.9
+    /// let beta: Beta<f64> = Beta::from_quantiles((0.5, 0.2), (0.9, 0.4)).unwrap();
+    /// assert!((beta.quantile(0.5) - 0.2).abs() < 1e-9);
+    /// assert!((beta.quantile(0.9) - 0.4).abs() < 1e-9);
+    /// ```
+    pub fn from_quantiles(a: (F, F), b: (F, F)) -> Result<Beta<F>, Error> {
+        let ((p1, x1), (p2, x2)) = order_quantiles(a, b).ok_or(Error::QuantilesOutOfRange)?;
+        let one = F::one();
+        if !(x1 > F::zero() && x2 < one) {
+            return Err(Error::QuantilesOutOfRange);
+        }
+        let quantile = |alpha: F, beta: F, p: F| match Beta::new(alpha, beta) {
+            Ok(dist) => dist.quantile(p),
+            Err(_) => F::nan(),
+        };
+        // The parameters are searched for in terms of their logarithms. The
+        // quantile at `p1` decreases with `beta`; the guess matches the mean.
+        let solve_beta = |ln_alpha: F| {
+            let alpha = ln_alpha.exp();
+            let guess = ln_alpha + ((one - x1) / x1).ln();
+            solve_increasing(|t: F| x1 - quantile(alpha, t.exp(), p1), guess)
+        };
+        // Guess `alpha` by approximating the distribution as normal
+        let (z1, z2) = (std_normal_quantile(p1), std_normal_quantile(p2));
+        let std_dev = (x2 - x1) / (z2 - z1);
+        let mean = x1 - std_dev * z1;
+        let precision = mean * (one - mean) / (std_dev * std_dev) - one;
+        let guess = (mean * precision).ln();
+        let guess = if guess.is_finite() { guess } else { F::zero() };
+        let ln_alpha = solve_increasing(
+            |s: F| match solve_beta(s) {
+                Some(t) => x2 - quantile(s.exp(), t.exp(), p2),
+                None => F::nan(),
+            },
+            guess,
+        )
+        .ok_or(Error::QuantilesOutOfRange)?;
+        let beta = solve_beta(ln_alpha)
+            .ok_or(Error::QuantilesOutOfRange)?
+            .exp();
+        Beta::new(ln_alpha.exp(), beta).map_err(|_| Error::QuantilesOutOfRange)
+    }
+
+    /// The parameters `(alpha, beta)` as passed to [`Beta::new`].
+    pub(crate) fn params(&self) -> (F, F) {
+        if self.switched_params {
+            (self.b, self.a)
+        } else {
+            (self.a, self.b)
+        }
+    }
+
+    /// Fit to observations by maximum likelihood, with the default
+    /// [`FitOptions`].
+    ///
+    /// See [`Beta::fit_with`].
+    pub fn fit(data: &[F]) -> Result<Fitted<Beta<F>, F>, FitError> {
+        Self::fit_with(data, FitOptions::new())
+    }
+
+    /// Fit to observations by maximum likelihood.
+    ///
+    /// The estimates solve `ψ(α) - ψ(α + β) = mean(ln x)` and
+    /// `ψ(β) - ψ(α + β) = mean(ln(1 - x))`, which are found by the
+    /// fixed-point iteration of Minka (2000)[^1] from the estimates of the
+    /// method of moments.
+    ///
+    /// Fails if `data` is empty, its values are all equal or any is not in
+    /// the open interval `(0, 1)`, or if the iteration does not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Beta;
+    ///
+    /// let fitted = Beta::fit(&[0.12, 0.35, 0.41, 0.58, 0.77]).unwrap();
+    /// println!("{:?} after {} iterations", fitted.distribution, fitted.iterations);
+    /// ```
+    ///
+    /// [^1]: Thomas P. Minka (2000). *Estimating a Dirichlet distribution*.
+    pub fn fit_with(data: &[F], options: FitOptions<F>) -> Result<Fitted<Beta<F>, F>, FitError> {
+        let mut moments = [Welford::new(), Welford::new()];
+        let mut mean_logs = [F::zero(), F::zero()];
+        for &x in data {
+            if !(x > F::zero() && x < F::one()) {
+                return Err(FitError::OutOfSupport);
+            }
+            moments[0].push(x);
+            moments[1].push(F::one() - x);
+            let n = F::from(moments[0].count).unwrap();
+            mean_logs[0] = mean_logs[0] + (x.ln() - mean_logs[0]) / n;
+            mean_logs[1] = mean_logs[1] + ((-x).ln_1p() - mean_logs[1]) / n;
+        }
+        if moments[0].count == 0 {
+            return Err(FitError::Empty);
+        }
+        if moments[0].m2 == F::zero() {
+            return Err(FitError::Constant);
+        }
+
+        let mut alpha = [F::zero(); 2];
+        dirichlet_moments(&moments, &mut alpha);
+        let iterations = fit_dirichlet(&mean_logs, &mut alpha, options)?;
+
+        let [a, b] = alpha;
+        let n = F::from(moments[0].count).unwrap();
+        let log_likelihood =
+            n * ((a - F::one()) * mean_logs[0] + (b - F::one()) * mean_logs[1] - ln_beta(a, b));
+        Ok(Fitted {
+            distribution: Beta::new(a, b).map_err(|_| FitError::NoConvergence)?,
+            iterations,
+            log_likelihood,
+        })
+    }
 }
 
 impl<F> Distribution<F> for Beta<F>
@@ -263,6 +444,168 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for Beta<F>
+where
+    F: Float,
+    Open01: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        let (a, b) = self.params();
+        let (zero, one, two) = (F::zero(), F::one(), F::from(2.0).unwrap());
+        if !(x >= zero && x <= one) {
+            return F::neg_infinity();
+        }
+        // At the end points the density is zero, finite or infinite,
+        // depending on the corresponding shape parameter.
+        let boundary = |shape: F, other: F| {
+            if shape < one {
+                F::infinity()
+            } else if shape == one {
+                other.ln()
+            } else {
+                F::neg_infinity()
+            }
+        };
+        if x == zero {
+            return boundary(a, b);
+        }
+        if x == one {
+            return boundary(b, a);
+        }
+        if a <= two || b <= two {
+            (a - one) * x.ln() + (b - one) * (-x).ln_1p() - ln_beta(a, b)
+        } else {
+            // The density is a multiple of a binomial probability in `a - 1`,
+            // which avoids cancellation for large shape parameters.
+            (a + b - one).ln() + ln_binomial_raw(a - one, a + b - two, x, one - x)
+        }
+    }
+}
+
+impl<F> Cdf<F> for Beta<F>
+where
+    F: Float,
+    Open01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        let (a, b) = self.params();
+        beta_pq(a, b, x, F::one() - x).0
+    }
+
+    fn sf(&self, x: F) -> F {
+        let (a, b) = self.params();
+        beta_pq(a, b, x, F::one() - x).1
+    }
+}
+
+impl<F> Quantile<F> for Beta<F>
+where
+    F: Float,
+    Open01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        let (a, b) = self.params();
+        invert_cdf(self, p, F::zero(), F::one(), a / (a + b), F::one())
+    }
+}
+
+impl<F> Moments<F> for Beta<F>
+where
+    F: Float,
+    Open01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        let (a, b) = self.params();
+        Some(a / (a + b))
+    }
+
+    fn variance(&self) -> Option<F> {
+        let (a, b) = self.params();
+        let s = a + b;
+        Some(a * b / (s * s * (s + F::one())))
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let (a, b) = self.params();
+        let s = a + b;
+        let two = F::from(2.0).unwrap();
+        Some(two * (b - a) * (s + F::one()).sqrt() / ((s + two) * (a * b).sqrt()))
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let (a, b) = self.params();
+        let s = a + b;
+        let c = |x: f64| F::from(x).unwrap();
+        let num = (a - b) * (a - b) * (s + F::one()) - a * b * (s + c(2.0));
+        Some(c(6.0) * num / (a * b * (s + c(2.0)) * (s + c(3.0))))
+    }
+}
+
+impl<F> Entropy<F> for Beta<F>
+where
+    F: Float,
+    Open01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        let (a, b) = self.params();
+        dirichlet_entropy(&[a, b])
+    }
+}
+
+impl<F> KlDivergence<F> for Beta<F>
+where
+    F: Float,
+    Open01: Distribution<F>,
+{
+    fn kl_divergence(&self, other: &Self) -> F {
+        let (a1, b1) = self.params();
+        let (a2, b2) = other.params();
+        dirichlet_kl(&[a1, b1], &[a2, b2])
+    }
+}
+
+impl<F> Summary<F> for Beta<F>
+where
+    F: Float,
+    Open01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        let (a, b) = self.params();
+        let (zero, one) = (F::zero(), F::one());
+        Support::Real {
+            lower: if a >= one {
+                Bound::Included(zero)
+            } else {
+                Bound::Excluded(zero)
+            },
+            upper: if b >= one {
+                Bound::Included(one)
+            } else {
+                Bound::Excluded(one)
+            },
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        let (a, b) = self.params();
+        let one = F::one();
+        if a > one && b > one {
+            Some((a - one) / (a + b - F::from(2.0).unwrap()))
+        } else if (a < one && b < one) || (a == one && b == one) {
+            // Bimodal or uniform
+            None
+        } else if a < b {
+            Some(F::zero())
+        } else {
+            Some(one)
+        }
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -291,8 +634,186 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_beta_pdf() {
+        let beta = Beta::new(2.0, 5.0).unwrap();
+        assert_almost_eq!(beta.pdf(0.3), 2.1609, 1e-14);
+        assert_eq!(beta.pdf(0.0), 0.0);
+        assert_eq!(beta.pdf(1.5), 0.0);
+        // The parameters are not symmetric
+        let beta = Beta::new(5.0, 2.0).unwrap();
+        assert_almost_eq!(beta.pdf(0.7), 2.1609, 1e-14);
+        let arcsine = Beta::new(0.5, 0.5).unwrap();
+        assert_almost_eq!(arcsine.pdf(0.5), core::f64::consts::FRAC_2_PI, 1e-15);
+        assert_eq!(arcsine.pdf(1.0), f64::INFINITY);
+        let uniform = Beta::new(1.0, 1.0).unwrap();
+        assert_almost_eq!(uniform.pdf(0.0), 1.0, 1e-15);
+        let beta = Beta::new(500.0, 1500.0).unwrap();
+        assert_almost_eq!(beta.ln_pdf(0.25), 3.7183203578193466763, 1e-12);
+    }
+
+    #[test]
+    fn test_beta_cdf() {
+        let beta = Beta::new(2.0, 5.0).unwrap();
+        assert_almost_eq!(beta.cdf(0.3), 0.57982499999999997601, 1e-15);
+        assert_almost_eq!(beta.sf(0.3), 0.42017500000000002399, 1e-15);
+        assert_eq!(beta.cdf(-0.5), 0.0);
+        assert_eq!(beta.sf(1.5), 0.0);
+        let arcsine = Beta::new(0.5, 0.5).unwrap();
+        assert_almost_eq!(arcsine.cdf(0.1), 0.20483276469913345754, 1e-15);
+        let beta = Beta::new(1e5, 2e5).unwrap();
+        assert_almost_eq!(beta.cdf(0.335), 0.97352417002546875920, 1e-12);
+    }
+
+    #[test]
+    fn test_beta_quantile() {
+        let beta = Beta::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(beta.quantile(0.3), 0.27238394207510534103, 1e-15);
+        assert_almost_eq!(beta.quantile(1e-10), 4.0824940158083329196e-6, 1e-20);
+        assert_almost_eq!(beta.quantile(0.999), 0.93596186089716660917, 1e-15);
+        assert_eq!(beta.quantile(0.0), 0.0);
+        assert_eq!(beta.quantile(1.0), 1.0);
+        assert!(beta.quantile(1.5).is_nan());
+        let beta = Beta::new(0.5, 0.5).unwrap();
+        assert_almost_eq!(beta.quantile(0.1), 0.024471741852423216636, 1e-16);
+    }
+
+    #[test]
+    fn test_beta_moments() {
+        let d = Beta::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 0.4, 1e-15);
+        assert_almost_eq!(d.variance().unwrap(), 0.04, 1e-15);
+        assert_almost_eq!(d.skewness().unwrap(), 0.28571428571428571429, 1e-15);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), -0.64285714285714285714, 1e-15);
+    }
+
+    #[test]
+    fn test_beta_entropy() {
+        let d = Beta::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(d.entropy(), -0.23490664978800031023, 1e-15);
+        let d = Beta::new(1.0, 1.0).unwrap();
+        assert_almost_eq!(d.entropy(), 0.0, 1e-15);
+        let d = Beta::new(0.5, 0.5).unwrap();
+        assert_almost_eq!(d.entropy(), -0.24156447527049044469, 1e-15);
+        let d = Beta::new(0.01, 0.01).unwrap();
+        assert_almost_eq!(d.entropy(), -93.733715131891072050, 1e-12);
+        let d = Beta::new(1e8, 2e8).unwrap();
+        assert_almost_eq!(d.entropy(), -9.0927466837159241075, 1e-14);
+    }
+
+    #[test]
+    fn test_beta_kl_divergence() {
+        let p = Beta::new(0.5, 3.0).unwrap();
+        let q = Beta::new(2.0, 2.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 2.5633297040875977797, 1e-14);
+        assert_eq!(p.kl_divergence(&p), 0.0);
+        let p = Beta::new(1e5, 2e5).unwrap();
+        let q = Beta::new(1e5 + 1.0, 2e5).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 3.3333407407407406584e-6, 1e-18);
+    }
+
+    #[test]
+    fn test_beta_summary() {
+        let d = Beta::new(2.0, 5.0).unwrap();
+        assert_almost_eq!(d.mode().unwrap(), 0.2, 1e-16);
+        assert_almost_eq!(d.median(), 0.26444998329565996232, 1e-15);
+        assert!(d.support().contains(0.0) && d.support().contains(1.0));
+        assert_eq!(Beta::new(0.5, 0.5).unwrap().mode(), None);
+        assert_eq!(Beta::new(1.0, 1.0).unwrap().mode(), None);
+        assert_eq!(Beta::new(1.0, 3.0).unwrap().mode(), Some(0.0));
+        let d = Beta::new(3.0, 0.5).unwrap();
+        assert_eq!(d.mode(), Some(1.0));
+        assert!(d.support().contains(0.0) && !d.support().contains(1.0));
+    }
+
     #[test]
     fn beta_distributions_can_be_compared() {
         assert_eq!(Beta::new(1.0, 2.0), Beta::new(1.0, 2.0));
     }
+
+    #[test]
+    fn test_beta_fit() {
+        let fitted = Beta::fit(&[0.12, 0.35, 0.41, 0.58, 0.77]).unwrap();
+        let (a, b) = fitted.distribution.params();
+        assert_almost_eq!(a, 1.9553638207453153638, 1e-10);
+        assert_almost_eq!(b, 2.4582127056280109659, 1e-10);
+        assert_almost_eq!(fitted.log_likelihood, 0.84308613825697061615, 1e-12);
+
+        let mut rng = crate::test::rng(224);
+        for &(alpha, beta) in &[(0.3, 0.6), (2.0, 5.0), (40.0, 20.0)] {
+            let dist = Beta::new(alpha, beta).unwrap();
+            let data: [f64; 5000] = core::array::from_fn(|_| dist.sample(&mut rng));
+            let (a, b) = Beta::fit(&data).unwrap().distribution.params();
+            assert_almost_eq!(a / alpha, 1.0, 0.05);
+            assert_almost_eq!(b / beta, 1.0, 0.05);
+        }
+
+        assert_eq!(Beta::<f64>::fit(&[]), Err(FitError::Empty));
+        assert_eq!(Beta::fit(&[0.5, 0.5]), Err(FitError::Constant));
+        assert_eq!(Beta::fit(&[0.5, 1.0]), Err(FitError::OutOfSupport));
+        assert_eq!(Beta::fit(&[0.5, f64::NAN]), Err(FitError::OutOfSupport));
+        let options = FitOptions::new().with_max_iterations(1);
+        assert_eq!(
+            Beta::fit_with(&[0.12, 0.35, 0.41, 0.58, 0.77], options),
+            Err(FitError::NoConvergence)
+        );
+    }
+
+    #[test]
+    fn test_from_mean_variance() {
+        let beta = Beta::from_mean_variance(0.25, 0.0375).unwrap();
+        assert_almost_eq!(beta.mean().unwrap(), 0.25, 1e-15);
+        assert_almost_eq!(beta.variance().unwrap(), 0.0375, 1e-15);
+        let fitted = Beta::new(1.0, 3.0).unwrap();
+        assert_almost_eq!(beta.mean().unwrap(), fitted.mean().unwrap(), 1e-15);
+
+        assert_eq!(
+            Beta::from_mean_variance(0.0, 0.01),
+            Err(Error::MeanOutOfRange)
+        );
+        assert_eq!(
+            Beta::from_mean_variance(1.0, 0.01),
+            Err(Error::MeanOutOfRange)
+        );
+        assert_eq!(
+            Beta::from_mean_variance(0.5, 0.0),
+            Err(Error::VarianceOutOfRange)
+        );
+        // The variance of a distribution on [0, 1] with mean 0.5 is below 0.25
+        assert_eq!(
+            Beta::from_mean_variance(0.5, 0.25),
+            Err(Error::VarianceOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_from_quantiles() {
+        for &(alpha, beta, p1, p2) in &[
+            (1.0, 1.0, 0.1, 0.9),
+            (2.0, 5.0, 0.5, 0.9),
+            (0.5, 0.5, 0.05, 0.5),
+            (0.2, 3.0, 0.5, 0.99),
+            (50.0, 20.0, 0.01, 0.6),
+        ] {
+            let expected = Beta::new(alpha, beta).unwrap();
+            let (x1, x2) = (expected.quantile(p1), expected.quantile(p2));
+            let dist = Beta::from_quantiles((p1, x1), (p2, x2)).unwrap();
+            let (a, b) = dist.params();
+            assert_almost_eq!(a, alpha, 1e-8 * alpha);
+            assert_almost_eq!(b, beta, 1e-8 * beta);
+        }
+
+        assert_eq!(
+            Beta::from_quantiles((0.1, 0.0), (0.9, 0.5)),
+            Err(Error::QuantilesOutOfRange)
+        );
+        assert_eq!(
+            Beta::from_quantiles((0.1, 0.5), (0.9, 1.0)),
+            Err(Error::QuantilesOutOfRange)
+        );
+        assert_eq!(
+            Beta::from_quantiles((0.1, 0.6), (0.9, 0.5)),
+            Err(Error::QuantilesOutOfRange)
+        );
+    }
 }
@@ -0,0 +1,579 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The beta-binomial distribution `BetaBinomial(n, α, β)`.
+
+use crate::moments::excess_kurtosis_from_raw;
+use crate::quantile::{discrete_quantile, solve_increasing};
+use crate::special::{beta_pq, ln_beta, ln_binomial_raw};
+use crate::{Beta, Binomial, Cdf, DiscretePmf, Distribution, Moments, Quantile};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::Rng;
+
+/// The [beta-binomial distribution](https://en.wikipedia.org/wiki/Beta-binomial_distribution)
+/// `BetaBinomial(n, α, β)`.
+///
+/// The number of successes in `n` trials whose common success probability
+/// follows the [`Beta`] distribution `Beta(α, β)`: the [`Binomial`]
+/// distribution with an uncertain probability. It is also the number of
+/// white balls drawn in `n` draws from a Pólya urn that initially holds `α`
+/// white and `β` black balls, where each drawn ball is returned together with
+/// another of the same colour. Its variance exceeds that of the binomial
+/// distribution with the same mean, which makes it a model of overdispersed
+/// proportions such as conversion counts across heterogeneous visitors.
+///
+/// # Density function
+///
+/// `f(k) = C(n, k) B(k + α, n - k + β) / B(α, β)` for `0 <= k <= n`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{BetaBinomial, Distribution};
+///
+/// let bb = BetaBinomial::new(20, 2.0, 5.0).unwrap();
+/// let v = bb.sample(&mut rand::rng());
+/// println!("{} is from a beta-binomial distribution", v);
+/// ```
+///
+/// # Implementation details
+///
+/// For small `n`, samples simulate the Pólya urn with one uniform sample
+/// per trial. Otherwise, when the mean is small, or close to `n` by symmetry,
+/// samples are generated by inversion, summing the probabilities from zero
+/// with their recurrence. In the remaining cases a probability is sampled from
+/// the [`Beta`] distribution and then the number of successes from the
+/// [`Binomial`] distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BetaBinomial {
+    n: u64,
+    alpha: f64,
+    beta: f64,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Method {
+    Urn,
+    /// The probability of zero successes, with `α` and `β` swapped if
+    /// flipped, and the number of successes at which the search stops
+    Inversion(f64, bool, u64),
+    TwoStage(Beta<f64>),
+}
+
+/// The largest `n` for which the Pólya urn is simulated.
+pub(crate) const URN_MAX_N: u64 = 16;
+
+/// The largest mean, after flipping, for which samples are generated by
+/// inversion.
+const INVERSION_MAX_MEAN: f64 = 32.0;
+
+/// The largest number of steps of the search for samples by inversion.
+const INVERSION_MAX_STEPS: u64 = 1024;
+
+/// The probability beyond the end of the search for samples by inversion,
+/// which is at the level of rounding errors.
+const INVERSION_TAIL: f64 = 64.0 * f64::EPSILON;
+
+/// The largest number of probabilities summed for the distribution function
+/// before it is integrated instead.
+const TAIL_MAX_STEPS: u64 = 1024;
+
+/// Error type returned from [`BetaBinomial::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `alpha <= 0`, infinite or `nan`.
+    AlphaOutOfRange,
+    /// `beta <= 0`, infinite or `nan`.
+    BetaOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::AlphaOutOfRange => {
+                "alpha is not positive and finite in beta-binomial distribution"
+            }
+            Error::BetaOutOfRange => {
+                "beta is not positive and finite in beta-binomial distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl BetaBinomial {
+    /// Construct a new `BetaBinomial` with `n` trials and the shape
+    /// parameters `alpha` and `beta` of the distribution of the success
+    /// probability.
+    ///
+    /// Requires finite `alpha > 0` and `beta > 0`.
+    pub fn new(n: u64, alpha: f64, beta: f64) -> Result<BetaBinomial, Error> {
+        if !(alpha > 0.0 && alpha.is_finite()) {
+            return Err(Error::AlphaOutOfRange);
+        }
+        if !(beta > 0.0 && beta.is_finite()) {
+            return Err(Error::BetaOutOfRange);
+        }
+        let method = if n <= URN_MAX_N {
+            Method::Urn
+        } else {
+            let flipped = alpha > beta;
+            let (a, b) = if flipped {
+                (beta, alpha)
+            } else {
+                (alpha, beta)
+            };
+            let mean = n as f64 * a / (a + b);
+            // f(0) = B(α, n + β) / B(α, β)
+            let f0 = (ln_beta(a, n as f64 + b) - ln_beta(a, b)).exp();
+            let end = if mean <= INVERSION_MAX_MEAN && f0.is_normal() {
+                inversion_end(n, a, b, f0)
+            } else {
+                None
+            };
+            match end {
+                Some(end) => Method::Inversion(f0, flipped, end),
+                None => Method::TwoStage(Beta::new(alpha, beta).unwrap()),
+            }
+        };
+        Ok(BetaBinomial {
+            n,
+            alpha,
+            beta,
+            method,
+        })
+    }
+}
+
+/// `f(k + 1) / f(k) = (n - k)(k + α) / ((k + 1)(n - k - 1 + β))`.
+fn ratio(n: f64, a: f64, b: f64, k: f64) -> f64 {
+    (n - k) * (k + a) / ((k + 1.0) * (n - k - 1.0 + b))
+}
+
+/// The number of successes up to which the probabilities, from `f(0) = f0`,
+/// sum to within `INVERSION_TAIL` of one, if it is within
+/// `INVERSION_MAX_STEPS`.
+fn inversion_end(n: u64, a: f64, b: f64, f0: f64) -> Option<u64> {
+    let mut f = f0;
+    let mut sum = f0;
+    for k in 0..INVERSION_MAX_STEPS.min(n) {
+        if sum >= 1.0 - INVERSION_TAIL {
+            return Some(k);
+        }
+        f *= ratio(n as f64, a, b, k as f64);
+        sum += f;
+    }
+    if sum >= 1.0 - INVERSION_TAIL {
+        Some(INVERSION_MAX_STEPS.min(n))
+    } else {
+        None
+    }
+}
+
+impl Distribution<u64> for BetaBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        match self.method {
+            Method::Urn => sample_urn(self.n, self.alpha, self.beta, rng),
+            Method::Inversion(f0, flipped, end) => {
+                let (a, b) = if flipped {
+                    (self.beta, self.alpha)
+                } else {
+                    (self.alpha, self.beta)
+                };
+                let n = self.n as f64;
+                let mut u = rng.random::<f64>();
+                let mut f = f0;
+                let mut k = 0;
+                while u > f {
+                    if k == end {
+                        // Only rounding errors lead here
+                        let beta = Beta::new(self.alpha, self.beta).unwrap();
+                        return sample_two_stage(self.n, &beta, rng);
+                    }
+                    u -= f;
+                    f *= ratio(n, a, b, k as f64);
+                    k += 1;
+                }
+                if flipped {
+                    self.n - k
+                } else {
+                    k
+                }
+            }
+            Method::TwoStage(beta) => sample_two_stage(self.n, &beta, rng),
+        }
+    }
+}
+
+/// Sample `BetaBinomial(n, a, b)` by simulating the Pólya urn, with one
+/// uniform sample per trial.
+pub(crate) fn sample_urn<R: Rng + ?Sized>(n: u64, a: f64, b: f64, rng: &mut R) -> u64 {
+    let mut successes = 0;
+    for i in 0..n {
+        let p = (a + successes as f64) / (a + b + i as f64);
+        if rng.random::<f64>() < p {
+            successes += 1;
+        }
+    }
+    successes
+}
+
+/// Sample `BetaBinomial(n, a, b)` by sampling the success probability from
+/// `beta`, which is `Beta(a, b)`, and then the number of successes.
+pub(crate) fn sample_two_stage<R: Rng + ?Sized>(n: u64, beta: &Beta<f64>, rng: &mut R) -> u64 {
+    let p = beta.sample(rng);
+    Binomial::new(n, p).unwrap().sample(rng)
+}
+
+impl DiscretePmf<f64> for BetaBinomial {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        ln_pmf(self.n, self.alpha, self.beta, k)
+    }
+}
+
+impl Cdf<f64> for BetaBinomial {
+    fn cdf(&self, x: f64) -> f64 {
+        self.pq(x).0
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.pq(x).1
+    }
+}
+
+impl Quantile<f64> for BetaBinomial {
+    fn quantile(&self, p: f64) -> f64 {
+        discrete_quantile(self, p, 0.0, self.n as f64, self.mean().unwrap())
+    }
+}
+
+impl Moments<f64> for BetaBinomial {
+    fn mean(&self) -> Option<f64> {
+        Some(self.n as f64 * self.alpha / (self.alpha + self.beta))
+    }
+
+    fn variance(&self) -> Option<f64> {
+        let (n, a, b) = (self.n as f64, self.alpha, self.beta);
+        let s = a + b;
+        Some(n * a * b * (s + n) / (s * s * (s + 1.0)))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        if self.n == 0 {
+            return None;
+        }
+        let (n, a, b) = (self.n as f64, self.alpha, self.beta);
+        let s = a + b;
+        Some((s + 2.0 * n) * (b - a) / (s + 2.0) * ((1.0 + s) / (n * a * b * (n + s))).sqrt())
+    }
+
+    fn excess_kurtosis(&self) -> Option<f64> {
+        if self.n == 0 {
+            return None;
+        }
+        // From the factorial moments E[X(X - 1)⋯(X - j + 1)], which are
+        // n(n - 1)⋯(n - j + 1) α(α + 1)⋯(α + j - 1) / (s(s + 1)⋯(s + j - 1))
+        let (n, a, s) = (self.n as f64, self.alpha, self.alpha + self.beta);
+        let f1 = n * a / s;
+        let f2 = f1 * (n - 1.0) * (a + 1.0) / (s + 1.0);
+        let f3 = f2 * (n - 2.0) * (a + 2.0) / (s + 2.0);
+        let f4 = f3 * (n - 3.0) * (a + 3.0) / (s + 3.0);
+        let m2 = f2 + f1;
+        let m3 = f3 + 3.0 * f2 + f1;
+        let m4 = f4 + 6.0 * f3 + 7.0 * f2 + f1;
+        Some(excess_kurtosis_from_raw(f1, m2, m3, m4))
+    }
+}
+
+impl BetaBinomial {
+    /// `(P(X <= x), P(X > x))`, summing the probabilities on each side.
+    fn pq(&self, x: f64) -> (f64, f64) {
+        if !(x >= 0.0) {
+            return (0.0, 1.0);
+        }
+        if x >= self.n as f64 {
+            return (1.0, 0.0);
+        }
+        // Sum the tail on the side of `x` away from the mean, and take the
+        // other as its complement
+        let k = x as u64;
+        if x < self.mean().unwrap() {
+            let lower = lower_tail(self.n, self.alpha, self.beta, k);
+            (lower, 1.0 - lower)
+        } else {
+            let upper = lower_tail(self.n, self.beta, self.alpha, self.n - k - 1);
+            (1.0 - upper, upper)
+        }
+    }
+}
+
+/// `ln f(k)` for `BetaBinomial(n, a, b)`.
+fn ln_pmf(n: u64, a: f64, b: f64, k: u64) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
+    }
+    // C(n, k) = 1 / ((n + 1) B(n - k + 1, k + 1))
+    let (n, k) = (n as f64, k as f64);
+    ln_beta(k + a, n - k + b) - ln_beta(a, b) - (n + 1.0).ln() - ln_beta(n - k + 1.0, k + 1.0)
+}
+
+/// `P(X <= k)` for `BetaBinomial(n, a, b)` and `k <= n`, by summing the
+/// probabilities from `f(k)` downwards with their recurrence.
+///
+/// The sum stops early once the remaining probabilities are bounded by a
+/// negligible geometric series: for `a >= 1`, the ratios
+/// `f(j - 1) / f(j) = s t` with `s = j / (j - 1 + a)` and
+/// `t = (n - j + b) / (n - j + 1)` only decrease with `j`, apart from `t`
+/// approaching one from below when `b < 1`, so that `s max(t, 1)` bounds all
+/// later ratios. If it does not stop within `TAIL_MAX_STEPS`, as for `a < 1`
+/// or in the bulk of a wide distribution, the sum is replaced by
+/// `lower_tail_integral`.
+fn lower_tail(n: u64, a: f64, b: f64, k: u64) -> f64 {
+    let mut f = ln_pmf(n, a, b, k).exp();
+    let n_f = n as f64;
+    let mut sum = f;
+    let mut j = k as f64;
+    for _ in 0..TAIL_MAX_STEPS {
+        if j == 0.0 {
+            return sum;
+        }
+        let s = j / (j - 1.0 + a);
+        let t = (n_f - j + b) / (n_f - j + 1.0);
+        f *= s * t;
+        sum += f;
+        j -= 1.0;
+        let r = s * t.max(1.0);
+        if r < 1.0 && f * r <= (1.0 - r) * f64::EPSILON * sum {
+            return sum;
+        }
+    }
+    if j == 0.0 {
+        sum
+    } else {
+        lower_tail_integral(n, a, b, k)
+    }
+}
+
+/// `P(X <= k)` for `BetaBinomial(n, a, b)` and `k < n`, by integrating the
+/// mixture of binomial distributions.
+///
+/// With `P ~ Beta(a, b)`, `P(X <= k)` is the probability that `P` is below
+/// the `(k + 1)`-th smallest of `n` uniform samples, which follows
+/// `Beta(k + 1, n - k)`; it is the expectation of `I_x(a, b)` for
+/// `x ~ Beta(k + 1, n - k)`. The integrand is smooth and log-concave on the
+/// logit scale `y = ln(x / (1 - x))`, where the trapezoidal rule converges
+/// exponentially fast: it is summed from its mode with a step of a quarter of
+/// the width of either factor, until the terms are negligible.
+fn lower_tail_integral(n: u64, a: f64, b: f64, k: u64) -> f64 {
+    let (c, d) = (k as f64 + 1.0, (n - k) as f64);
+    // `(x, 1 - x)` without cancellation
+    let logistic = |y: f64| (1.0 / (1.0 + (-y).exp()), 1.0 / (1.0 + y.exp()));
+    let ln_logistic = |y: f64| -(y.max(0.0) - y + (-y.abs()).exp().ln_1p());
+    // The density of `y`, `x^c (1 - x)^d / B(c, d)`, times `I_x(a, b)`
+    let term = |y: f64| {
+        let (x, x_c) = logistic(y);
+        let density = (c * d / (c + d)) * ln_binomial_raw(c, c + d, x, x_c).exp();
+        density * beta_pq(a, b, x, x_c).0
+    };
+    // The mode of the integrand, where the derivative of its logarithm,
+    // `c - (c + d) x + x^a (1 - x)^b / (B(a, b) I_x(a, b))`, vanishes
+    let ln_b = ln_beta(a, b);
+    let slope = |y: f64| {
+        let (x, x_c) = logistic(y);
+        let ln_f = a * ln_logistic(y) + b * ln_logistic(-y) - ln_b;
+        (c + d) * x - c - (ln_f - beta_pq(a, b, x, x_c).0.ln()).exp()
+    };
+    let y0 = (c / d).ln();
+    let mode = match solve_increasing(slope, y0) {
+        Some(mode) if mode.is_finite() => mode,
+        _ => y0,
+    };
+    let (x, x_c) = logistic(mode);
+    let width = (1.0 / ((c + d) * x * x_c))
+        .sqrt()
+        .min((1.0 / a + 1.0 / b).sqrt());
+    let step = 0.25 * width;
+    let peak = term(mode);
+    let mut sum = peak;
+    for &dir in &[-1.0, 1.0] {
+        let mut y = mode;
+        loop {
+            y += dir * step;
+            let t = term(y);
+            sum += t;
+            if !(t > 1e-20 * peak) {
+                break;
+            }
+        }
+    }
+    (step * sum).min(1.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(BetaBinomial::new(10, 1.0, 1.0).unwrap().method, Method::Urn);
+        assert!(matches!(
+            BetaBinomial::new(100, 1.0, 9.0).unwrap().method,
+            Method::Inversion(_, false, _)
+        ));
+        assert!(matches!(
+            BetaBinomial::new(100, 9.0, 1.0).unwrap().method,
+            Method::Inversion(_, true, _)
+        ));
+        // The tail beyond the search is too heavy for inversion
+        assert!(matches!(
+            BetaBinomial::new(1_000_000_000, 1e-8, 0.5).unwrap().method,
+            Method::TwoStage(_)
+        ));
+        assert!(matches!(
+            BetaBinomial::new(100, 1.0, 1.0).unwrap().method,
+            Method::TwoStage(_)
+        ));
+
+        assert_eq!(BetaBinomial::new(10, 0.0, 1.0), Err(Error::AlphaOutOfRange));
+        assert_eq!(
+            BetaBinomial::new(10, 1.0, f64::INFINITY),
+            Err(Error::BetaOutOfRange)
+        );
+        assert_eq!(
+            BetaBinomial::new(10, 1.0, f64::NAN),
+            Err(Error::BetaOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        const SAMPLES: usize = 100_000;
+        let mut rng = crate::test::rng(250);
+        // Each sampling method
+        for &(n, alpha, beta) in &[
+            (10, 2.5, 1.5),
+            (200, 0.5, 4.0),
+            (200, 6.0, 0.3),
+            (200, 2.0, 3.0),
+        ] {
+            let bb = BetaBinomial::new(n, alpha, beta).unwrap();
+            let (mean, var) = (bb.mean().unwrap(), bb.variance().unwrap());
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..SAMPLES {
+                let x = bb.sample(&mut rng);
+                assert!(x <= n);
+                let x = x as f64;
+                sum += x;
+                sum_sq += (x - mean) * (x - mean);
+            }
+            assert_almost_eq!(sum / SAMPLES as f64, mean, 0.01 * mean);
+            assert_almost_eq!(sum_sq / SAMPLES as f64, var, 0.03 * var);
+        }
+
+        let bb = BetaBinomial::new(0, 1.0, 1.0).unwrap();
+        assert_eq!(bb.sample(&mut rng), 0);
+    }
+
+    #[test]
+    fn test_pmf_cdf() {
+        let bb = BetaBinomial::new(10, 2.5, 1.5).unwrap();
+        assert_almost_eq!(bb.pmf(3), 0.0720977783203125, 1e-15);
+        assert_almost_eq!(bb.ln_pmf(0), -4.347621669692881, 1e-14);
+        assert_eq!(bb.pmf(11), 0.0);
+        let sum: f64 = (0..=10).map(|k| bb.pmf(k)).sum();
+        assert_almost_eq!(sum, 1.0, 1e-14);
+        assert_almost_eq!(bb.cdf(3.5), 0.16690826416015625, 1e-15);
+        assert_almost_eq!(bb.sf(7.0), 0.36717987060546875, 1e-15);
+        assert_eq!(bb.cdf(-0.5), 0.0);
+        assert_eq!(bb.cdf(10.0), 1.0);
+        assert_eq!(bb.quantile(0.1), 3.0);
+        assert_eq!(bb.quantile(0.5), 7.0);
+
+        // With α = β = 1 the distribution is uniform
+        let bb = BetaBinomial::new(4, 1.0, 1.0).unwrap();
+        for k in 0..=4 {
+            assert_almost_eq!(bb.pmf(k), 0.2, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_cdf_quantile() {
+        for &(alpha, beta) in &[(2.5, 1.5), (0.5, 0.3), (0.4, 3.0), (7.0, 0.8), (30.0, 50.0)] {
+            let bb = BetaBinomial::new(200, alpha, beta).unwrap();
+            let mut lower = 0.0;
+            for k in 0..200 {
+                lower += bb.pmf(k);
+                let x = k as f64;
+                assert_almost_eq!(bb.cdf(x), lower, 1e-13);
+                assert_almost_eq!(bb.sf(x), 1.0 - lower, 1e-13);
+                if bb.pmf(k) > 1e-10 {
+                    assert_eq!(bb.quantile(lower - 1e-12), x);
+                    assert_eq!(bb.quantile(lower + 1e-12), x + 1.0);
+                }
+            }
+        }
+
+        // Close to the beta distribution for large `n`
+        let bb = BetaBinomial::new(10_000_000, 2.0, 3.0).unwrap();
+        let beta = Beta::new(2.0, 3.0).unwrap();
+        for &x in &[1e3, 1e6, 4e6, 9e6, 9.999e6] {
+            assert_almost_eq!(bb.cdf(x), beta.cdf(x / 1e7), 1e-6);
+            assert_almost_eq!(bb.sf(x), beta.sf(x / 1e7), 1e-6);
+        }
+        for &p in &[1e-6, 0.1, 0.5, 0.99] {
+            let x = bb.quantile(p);
+            assert!(bb.cdf(x) >= p && bb.cdf(x - 1.0) < p);
+        }
+        let bb = BetaBinomial::new(100_000_000, 50.0, 1e8).unwrap();
+        assert_almost_eq!(bb.sf(100.0), 1.31342714e-5, 1e-12);
+
+        // The integral agrees with the sum of the probabilities
+        for &(n, alpha, beta) in &[(5000, 0.5, 0.5), (5000, 2.0, 3.0), (20000, 40.0, 0.7)] {
+            let mut lower = 0.0;
+            for k in 0..n - 1 {
+                lower += ln_pmf(n, alpha, beta, k).exp();
+                if k % 97 == 1 {
+                    let integral = lower_tail_integral(n, alpha, beta, k);
+                    assert_almost_eq!(integral, lower, 1e-11 * lower);
+                }
+            }
+        }
+
+        // The bulk of a wide distribution with a U-shaped density
+        let bb = BetaBinomial::new(1_000_000_000, 0.5, 0.5).unwrap();
+        let beta = Beta::new(0.5, 0.5).unwrap();
+        for &x in &[1e6, 4e8, 9e8, 1e9 - 1e6] {
+            assert_almost_eq!(bb.cdf(x), beta.cdf(x / 1e9), 1e-8);
+            assert_almost_eq!(bb.sf(x), beta.sf(x / 1e9), 1e-8);
+        }
+        for &p in &[1e-6, 0.3, 0.5, 0.99] {
+            let x = bb.quantile(p);
+            assert!(bb.cdf(x) >= p && bb.cdf(x - 1.0) < p);
+        }
+    }
+
+    #[test]
+    fn test_moments() {
+        let bb = BetaBinomial::new(10, 2.5, 1.5).unwrap();
+        assert_almost_eq!(bb.mean().unwrap(), 6.25, 1e-15);
+        assert_almost_eq!(bb.variance().unwrap(), 6.5625, 1e-14);
+        assert_almost_eq!(bb.skewness().unwrap(), -0.3903600291794133, 1e-14);
+        assert_almost_eq!(bb.excess_kurtosis().unwrap(), -0.7047619047619048, 1e-12);
+        let bb = BetaBinomial::new(7, 0.5, 3.0).unwrap();
+        assert_almost_eq!(bb.skewness().unwrap(), 1.607060866333062, 1e-14);
+        assert_almost_eq!(bb.excess_kurtosis().unwrap(), 2.22027972027972, 1e-12);
+    }
+}
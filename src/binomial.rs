@@ -9,9 +9,18 @@
 
 //! The binomial distribution `Binomial(n, p)`.
 
-use crate::{Distribution, Uniform};
+use crate::divergence::bernoulli_kl;
+use crate::entropy::lattice_entropy;
+use crate::fit::Counts;
+use crate::quantile::discrete_quantile;
+use crate::special::{beta_pq, ln_binomial_raw, std_normal_quantile};
+use crate::{
+    Cdf, CharacteristicFunction, DiscretePmf, Distribution, Entropy, FitError, KlDivergence, Mgf,
+    Moments, Quantile, Summary, Support, Uniform,
+};
 use core::cmp::Ordering;
 use core::fmt;
+use num_complex::Complex;
 #[allow(unused_imports)]
 use num_traits::Float;
 use rand::Rng;
@@ -46,6 +55,11 @@ use rand::Rng;
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Binomial {
+    // The parameters are stored as the sampling constants in `method` do not
+    // determine them: `n` is not kept for `p = 0`, and `p` only up to rounding
+    // as `p / (1 - p)`, `n p` or `1 - p`
+    n: u64,
+    p: f64,
     method: Method,
 }
 
@@ -85,6 +99,11 @@ pub enum Error {
     ProbabilityTooSmall,
     /// `p > 1`.
     ProbabilityTooLarge,
+    /// `mean <= 0` or `nan`.
+    MeanOutOfRange,
+    /// `variance < 0`, `variance >= mean` or `nan`, or `mean² / (mean - variance)`
+    /// is not an integral number of trials.
+    VarianceOutOfRange,
 }
 
 impl fmt::Display for Error {
@@ -92,6 +111,10 @@ impl fmt::Display for Error {
         f.write_str(match self {
             Error::ProbabilityTooSmall => "p < 0 or is NaN in binomial distribution",
             Error::ProbabilityTooLarge => "p > 1 in binomial distribution",
+            Error::MeanOutOfRange => "mean is not positive in binomial distribution",
+            Error::VarianceOutOfRange => {
+                "variance does not match an integral number of trials in binomial distribution"
+            }
         })
     }
 }
@@ -112,16 +135,22 @@ impl Binomial {
 
         if p == 0.0 {
             return Ok(Binomial {
+                n,
+                p,
                 method: Method::Constant(0),
             });
         }
 
         if p == 1.0 {
             return Ok(Binomial {
+                n,
+                p,
                 method: Method::Constant(n),
             });
         }
 
+        let (n_trials, p_success) = (n, p);
+
         // The binomial distribution is symmetrical with respect to p -> 1-p
         let flipped = p > 0.5;
         let p = if flipped { 1.0 - p } else { p };
@@ -165,7 +194,82 @@ impl Binomial {
             let m = f64_to_i64(f_m);
             Method::Btpe(Btpe { n, p, m, p1 }, flipped)
         };
-        Ok(Binomial { method })
+        Ok(Binomial {
+            n: n_trials,
+            p: p_success,
+            method,
+        })
+    }
+
+    /// Construct a binomial distribution with the given `mean` and
+    /// `variance`.
+    ///
+    /// The parameters are `n = mean² / (mean - variance)`, which must be an
+    /// integer up to rounding errors, and `p = mean / n`. This requires
+    /// `mean > 0` and `0 <= variance < mean`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Binomial;
+    ///
+    /// let bin = Binomial::from_mean_variance(3.0, 2.1).unwrap();
+    /// assert_eq!(bin, Binomial::new(10, 0.3).unwrap());
+    /// // There is no binomial distribution with 11.25 trials
+    /// assert!(Binomial::from_mean_variance(3.0, 2.2).is_err());
+    /// ```
+    pub fn from_mean_variance(mean: f64, variance: f64) -> Result<Binomial, Error> {
+        if !(mean > 0.0) {
+            return Err(Error::MeanOutOfRange);
+        }
+        if !(variance >= 0.0 && variance < mean) {
+            return Err(Error::VarianceOutOfRange);
+        }
+        let n_real = mean / (mean - variance) * mean;
+        let n = n_real.round();
+        if !(n >= 1.0 && n <= u64::MAX as f64) || (n_real - n).abs() > 1e-8 * n {
+            return Err(Error::VarianceOutOfRange);
+        }
+        Binomial::new(n as u64, (mean / n).min(1.0))
+    }
+
+    /// Fit to observed numbers of successes in `n` trials each by maximum
+    /// likelihood.
+    ///
+    /// The estimate of `p` is the sample mean divided by `n`.
+    ///
+    /// Fails if `data` is empty, `n` is zero or any value is greater
+    /// than `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Binomial;
+    ///
+    /// let bin = Binomial::fit(10, &[2, 5, 3, 2]).unwrap();
+    /// assert_eq!(bin, Binomial::new(10, 0.3).unwrap());
+    /// ```
+    pub fn fit(n: u64, data: &[u64]) -> Result<Binomial, FitError> {
+        Self::fit_counts(n, Counts::new(data.iter().map(|&k| (k, 1)))?)
+    }
+
+    /// Fit to observed numbers of successes in `n` trials each, given as a
+    /// frequency table of `(value, frequency)` pairs, by maximum likelihood.
+    ///
+    /// See [`Binomial::fit`].
+    pub fn fit_frequencies(n: u64, table: &[(u64, u64)]) -> Result<Binomial, FitError> {
+        Self::fit_counts(n, Counts::new(table.iter().copied())?)
+    }
+
+    fn fit_counts(n: u64, counts: Counts<f64>) -> Result<Binomial, FitError> {
+        if counts.max > n {
+            return Err(FitError::OutOfSupport);
+        }
+        if n == 0 {
+            return Err(FitError::Constant);
+        }
+        let p = (counts.mean / n as f64).min(1.0);
+        Binomial::new(n, p).map_err(|_| FitError::OutOfSupport)
     }
 }
 
@@ -389,10 +493,154 @@ impl Distribution<u64> for Binomial {
     }
 }
 
+impl DiscretePmf<f64> for Binomial {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        if k > self.n {
+            return f64::NEG_INFINITY;
+        }
+        ln_binomial_raw(k as f64, self.n as f64, self.p, 1.0 - self.p)
+    }
+}
+
+impl Cdf<f64> for Binomial {
+    fn cdf(&self, x: f64) -> f64 {
+        self.pq(x).0
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.pq(x).1
+    }
+}
+
+impl Quantile<f64> for Binomial {
+    fn quantile(&self, p: f64) -> f64 {
+        // Start from the normal approximation
+        let n = self.n as f64;
+        let (mean, var) = (n * self.p, n * self.p * (1.0 - self.p));
+        let guess = mean + std_normal_quantile(p) * var.sqrt();
+        discrete_quantile(self, p, 0.0, n, guess)
+    }
+}
+
+impl Moments<f64> for Binomial {
+    fn mean(&self) -> Option<f64> {
+        Some(self.n as f64 * self.p)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some(self.n as f64 * self.p * (1.0 - self.p))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let var = self.n as f64 * self.p * (1.0 - self.p);
+        if var == 0.0 {
+            return None;
+        }
+        Some((1.0 - 2.0 * self.p) / var.sqrt())
+    }
+
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let pq = self.p * (1.0 - self.p);
+        if pq == 0.0 || self.n == 0 {
+            return None;
+        }
+        Some((1.0 - 6.0 * pq) / (self.n as f64 * pq))
+    }
+}
+
+impl Entropy<f64> for Binomial {
+    fn entropy(&self) -> f64 {
+        lattice_entropy(self)
+    }
+}
+
+/// The divergence has a closed form only for equal numbers of trials `n`.
+/// It is infinite if `self` has more trials than `other`, and NaN is
+/// returned if it has fewer.
+impl KlDivergence<f64> for Binomial {
+    fn kl_divergence(&self, other: &Self) -> f64 {
+        match self.n.cmp(&other.n) {
+            Ordering::Equal => self.n as f64 * bernoulli_kl(self.p, other.p),
+            Ordering::Greater => f64::INFINITY,
+            Ordering::Less => f64::NAN,
+        }
+    }
+}
+
+impl Mgf<f64> for Binomial {
+    fn cgf(&self, t: f64) -> Option<f64> {
+        let (n, p) = (self.n as f64, self.p);
+        // n ln(1 - p + p e^t), factoring out e^t for t > 0 to avoid overflow
+        if t <= 0.0 {
+            Some(n * (p * t.exp_m1()).ln_1p())
+        } else if t > 0.0 {
+            Some(n * (t + ((1.0 - p) * (-t).exp_m1()).ln_1p()))
+        } else {
+            None
+        }
+    }
+}
+
+impl CharacteristicFunction<f64> for Binomial {
+    fn characteristic(&self, t: f64) -> Complex<f64> {
+        // (1 - p + p e^it)^n in polar form
+        let (n, p) = (self.n as f64, self.p);
+        let q = 1.0 - p;
+        let s = (0.5 * t).sin();
+        let ln_r = 0.5 * (-4.0 * p * q * s * s).ln_1p();
+        let theta = (p * t.sin()).atan2(q + p * t.cos());
+        Complex::from_polar((n * ln_r).exp(), n * theta)
+    }
+}
+
+impl Summary<f64> for Binomial {
+    fn support(&self) -> Support<f64> {
+        let lower = if self.p == 1.0 { self.n } else { 0 };
+        let upper = if self.p == 0.0 { 0 } else { self.n };
+        Support::Integer {
+            lower,
+            upper: Some(upper),
+        }
+    }
+
+    fn mode(&self) -> Option<f64> {
+        // For integer (n + 1) p both (n + 1) p - 1 and (n + 1) p are modes
+        let x = (self.n as f64 + 1.0) * self.p;
+        let m = x.floor();
+        if m == x && m > 0.0 {
+            Some(m - 1.0)
+        } else {
+            Some(m.min(self.n as f64))
+        }
+    }
+
+    fn median(&self) -> f64 {
+        self.quantile(0.5)
+    }
+}
+
+impl Binomial {
+    /// `(P(X <= x), P(X > x))`, using `P(X <= k) = I_{1-p}(n - k, k + 1)`.
+    fn pq(&self, x: f64) -> (f64, f64) {
+        if !(x >= 0.0) {
+            return (0.0, 1.0);
+        }
+        let k = x.floor();
+        let n = self.n as f64;
+        if k >= n {
+            return (1.0, 0.0);
+        }
+        beta_pq(n - k, k + 1.0, 1.0 - self.p, self.p)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Binomial;
-    use crate::Distribution;
+    use crate::{
+        Cdf, CharacteristicFunction, DiscretePmf, Distribution, Entropy, FitError, KlDivergence,
+        Mgf, Moments, Quantile, Summary, Support,
+    };
     use rand::Rng;
 
     fn test_binomial_mean_and_variance<R: Rng>(n: u64, p: f64, rng: &mut R) {
@@ -439,6 +687,137 @@ mod test {
         Binomial::new(20, -10.0).unwrap();
     }
 
+    #[test]
+    fn test_binomial_pmf() {
+        let binomial = Binomial::new(20, 0.3).unwrap();
+        assert_almost_eq!(binomial.pmf(5), 0.17886305056987974096, 1e-15);
+        assert_almost_eq!(binomial.pmf(0), 0.00079792266297612001, 1e-17);
+        assert_almost_eq!(binomial.pmf(20), 3.486784401e-11, 1e-24);
+        assert_eq!(binomial.pmf(21), 0.0);
+        let total: f64 = (0..=20).map(|k| binomial.pmf(k)).sum();
+        assert_almost_eq!(total, 1.0, 1e-14);
+
+        let binomial = Binomial::new(1_000_000_000, 0.6).unwrap();
+        assert_almost_eq!(binomial.ln_pmf(600_010_000), -10.775343019283250336, 1e-8);
+
+        assert_eq!(Binomial::new(10, 0.0).unwrap().pmf(0), 1.0);
+        assert_eq!(Binomial::new(10, 1.0).unwrap().pmf(10), 1.0);
+        assert_eq!(Binomial::new(10, 1.0).unwrap().pmf(9), 0.0);
+    }
+
+    #[test]
+    fn test_binomial_cdf() {
+        let binomial = Binomial::new(20, 0.3).unwrap();
+        assert_almost_eq!(binomial.cdf(5.0), 0.41637082944748090018, 1e-15);
+        assert_almost_eq!(binomial.sf(5.5), 0.58362917055251909982, 1e-15);
+        assert_almost_eq!(binomial.sf(19.0), 3.486784401e-11, 1e-24);
+        assert_almost_eq!(binomial.cdf(0.0), 0.00079792266297612001, 1e-17);
+        assert_eq!(binomial.cdf(-1.0), 0.0);
+        assert_eq!(binomial.cdf(20.0), 1.0);
+        assert_eq!(binomial.sf(20.0), 0.0);
+        assert_eq!(Binomial::new(10, 0.0).unwrap().cdf(0.0), 1.0);
+        assert_eq!(Binomial::new(10, 1.0).unwrap().sf(9.0), 1.0);
+    }
+
+    #[test]
+    fn test_binomial_quantile() {
+        let binomial = Binomial::new(20, 0.3).unwrap();
+        assert_eq!(binomial.quantile(0.5), 6.0);
+        assert_eq!(binomial.quantile(0.05), 3.0);
+        assert_eq!(binomial.quantile(0.95), 9.0);
+        assert_eq!(binomial.quantile(1e-9), 0.0);
+        assert_eq!(binomial.quantile(1.0), 20.0);
+        for i in 1..100 {
+            let p = i as f64 / 100.0;
+            let k = binomial.quantile(p);
+            assert!(binomial.cdf(k) >= p && binomial.cdf(k - 1.0) < p);
+        }
+
+        let mut rng = crate::test::rng(353);
+        for _ in 0..100 {
+            let k = binomial.sample_by_inversion(&mut rng);
+            assert!((0.0..=20.0).contains(&k) && k.fract() == 0.0);
+        }
+    }
+
+    #[test]
+    fn test_binomial_moments() {
+        let d = Binomial::new(20, 0.3).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 6.0, 1e-14);
+        assert_almost_eq!(d.variance().unwrap(), 4.2, 1e-14);
+        assert_almost_eq!(d.skewness().unwrap(), 0.19518001458970664877, 1e-14);
+        assert_almost_eq!(
+            d.excess_kurtosis().unwrap(),
+            -0.061904761904761904762,
+            1e-14
+        );
+        let d = Binomial::new(20, 1.0).unwrap();
+        assert_eq!(d.variance(), Some(0.0));
+        assert_eq!(d.skewness(), None);
+    }
+
+    #[test]
+    fn test_binomial_entropy() {
+        let d = Binomial::new(20, 0.3).unwrap();
+        assert_almost_eq!(d.entropy(), 2.1325386416614898514, 1e-14);
+        let d = Binomial::new(1000, 0.5).unwrap();
+        assert_almost_eq!(d.entropy(), 4.1796689086352685778, 1e-13);
+        let d = Binomial::new(1_000_000, 0.3).unwrap();
+        assert_almost_eq!(d.entropy(), 7.5463698745621776102, 1e-13);
+        assert_eq!(Binomial::new(20, 1.0).unwrap().entropy(), 0.0);
+        assert_eq!(Binomial::new(0, 0.3).unwrap().entropy(), 0.0);
+    }
+
+    #[test]
+    fn test_binomial_kl_divergence() {
+        let p = Binomial::new(20, 0.3).unwrap();
+        let q = Binomial::new(20, 0.4).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 0.43201708287093097861, 1e-15);
+        assert_eq!(p.kl_divergence(&p), 0.0);
+        let p = Binomial::new(20, 0.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 10.216512475319814404, 1e-14);
+        assert_eq!(q.kl_divergence(&p), f64::INFINITY);
+        let p = Binomial::new(1000, 0.5).unwrap();
+        let q = Binomial::new(1000, 0.5 + 1e-9).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 1.9999998868722757500e-15, 1e-29);
+        // Different numbers of trials
+        let p = Binomial::new(21, 0.3).unwrap();
+        let q = Binomial::new(20, 0.3).unwrap();
+        assert_eq!(p.kl_divergence(&q), f64::INFINITY);
+        assert!(q.kl_divergence(&p).is_nan());
+    }
+
+    #[test]
+    fn test_binomial_mgf() {
+        let d = Binomial::new(20, 0.3).unwrap();
+        assert_almost_eq!(d.cgf(0.4).unwrap(), 2.7525395477317652725, 1e-14);
+        assert_almost_eq!(d.cgf(50.0).unwrap(), 975.92054391348128015, 1e-12);
+        assert_eq!(d.mgf(0.0), Some(1.0));
+        assert_eq!(d.cgf(f64::NAN), None);
+        let phi = d.characteristic(1.2);
+        assert_almost_eq!(phi.re, 0.041213793933825856326, 1e-16);
+        assert_almost_eq!(phi.im, 0.016199664627130242769, 1e-16);
+    }
+
+    #[test]
+    fn test_binomial_summary() {
+        let d = Binomial::new(20, 0.3).unwrap();
+        let support = Support::Integer {
+            lower: 0,
+            upper: Some(20),
+        };
+        assert_eq!(d.support(), support);
+        assert_eq!((d.mode(), d.median()), (Some(6.0), 6.0));
+        // Both 4 and 5 are modes
+        assert_eq!(Binomial::new(9, 0.5).unwrap().mode(), Some(4.0));
+        let d = Binomial::new(5, 1.0).unwrap();
+        assert_eq!(d.mode(), Some(5.0));
+        assert_eq!(d.support().lower(), 5.0);
+        let d = Binomial::new(5, 0.0).unwrap();
+        assert_eq!(d.mode(), Some(0.0));
+        assert_eq!(d.support().upper(), 0.0);
+    }
+
     #[test]
     fn binomial_distributions_can_be_compared() {
         assert_eq!(Binomial::new(1, 1.0), Binomial::new(1, 1.0));
@@ -454,4 +833,59 @@ mod test {
         }
         assert_ne!(sum, 0);
     }
+
+    #[test]
+    fn test_binomial_fit() {
+        let d = Binomial::fit(10, &[2, 5, 3, 2]).unwrap();
+        assert_eq!((d.n, d.p), (10, 0.3));
+        let d = Binomial::fit_frequencies(4, &[(0, 2), (4, 1), (2, 5)]).unwrap();
+        assert_eq!((d.n, d.p), (4, 0.4375));
+        assert_eq!(Binomial::fit(4, &[4, 4]).unwrap().p, 1.0);
+
+        let mut rng = crate::test::rng(229);
+        let dist = Binomial::new(60, 0.15).unwrap();
+        let data: [u64; 5000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        let d = Binomial::fit(60, &data).unwrap();
+        assert_almost_eq!(d.p, 0.15, 0.005);
+
+        assert_eq!(Binomial::fit(10, &[]), Err(FitError::Empty));
+        assert_eq!(Binomial::fit(0, &[0, 0]), Err(FitError::Constant));
+        assert_eq!(Binomial::fit(4, &[2, 5]), Err(FitError::OutOfSupport));
+    }
+
+    #[test]
+    fn test_from_mean_variance() {
+        use super::Error;
+
+        assert_eq!(
+            Binomial::from_mean_variance(3.0, 2.1),
+            Binomial::new(10, 0.3)
+        );
+        assert_eq!(
+            Binomial::from_mean_variance(40.0, 0.0),
+            Binomial::new(40, 1.0)
+        );
+        let expected = Binomial::new(1000, 0.01).unwrap();
+        let binomial =
+            Binomial::from_mean_variance(expected.mean().unwrap(), expected.variance().unwrap())
+                .unwrap();
+        assert_eq!(binomial, expected);
+
+        assert_eq!(
+            Binomial::from_mean_variance(0.0, 0.0),
+            Err(Error::MeanOutOfRange)
+        );
+        assert_eq!(
+            Binomial::from_mean_variance(1.0, 1.0),
+            Err(Error::VarianceOutOfRange)
+        );
+        assert_eq!(
+            Binomial::from_mean_variance(1.0, -0.1),
+            Err(Error::VarianceOutOfRange)
+        );
+        assert_eq!(
+            Binomial::from_mean_variance(3.0, 2.2),
+            Err(Error::VarianceOutOfRange)
+        );
+    }
 }
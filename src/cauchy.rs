@@ -9,8 +9,12 @@
 
 //! The Cauchy distribution `Cauchy(x₀, γ)`.
 
-use crate::{Distribution, StandardUniform};
+use crate::{
+    Cdf, CharacteristicFunction, ContinuousPdf, Distribution, Entropy, Moments, Quantile,
+    StandardUniform, Summary, Support,
+};
 use core::fmt;
+use num_complex::Complex;
 use num_traits::{Float, FloatConst};
 use rand::Rng;
 
@@ -113,6 +117,129 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for Cauchy<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        let z = ((x - self.median) / self.scale).abs();
+        // ln(1 + z²), without overflow for large z
+        let ln_1p_z2 = if z > F::one() {
+            F::from(2.0).unwrap() * z.ln() + (z * z).recip().ln_1p()
+        } else {
+            (z * z).ln_1p()
+        };
+        -(F::PI() * self.scale).ln() - ln_1p_z2
+    }
+}
+
+impl<F> Cdf<F> for Cauchy<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        standard_cauchy_cdf((x - self.median) / self.scale)
+    }
+
+    fn sf(&self, x: F) -> F {
+        standard_cauchy_cdf((self.median - x) / self.scale)
+    }
+}
+
+impl<F> Quantile<F> for Cauchy<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        if !(p >= F::zero() && p <= F::one()) {
+            return F::nan();
+        }
+        let half = F::from(0.5).unwrap();
+        // `tan(π(p - 1/2))`, evaluated on the smaller tail probability
+        let z = if p < half {
+            -(F::PI() * p).tan().recip()
+        } else if p > half {
+            (F::PI() * (F::one() - p)).tan().recip()
+        } else {
+            F::zero()
+        };
+        self.median + self.scale * z
+    }
+}
+
+/// The Cauchy distribution has no moments.
+impl<F> Moments<F> for Cauchy<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        None
+    }
+
+    fn variance(&self) -> Option<F> {
+        None
+    }
+
+    fn skewness(&self) -> Option<F> {
+        None
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        None
+    }
+}
+
+impl<F> Entropy<F> for Cauchy<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        (F::from(4.0).unwrap() * F::PI() * self.scale).ln()
+    }
+}
+
+impl<F> CharacteristicFunction<F> for Cauchy<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn characteristic(&self, t: F) -> Complex<F> {
+        Complex::from_polar((-self.scale * t.abs()).exp(), self.median * t)
+    }
+}
+
+impl<F> Summary<F> for Cauchy<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::real_line()
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some(self.median)
+    }
+
+    fn median(&self) -> F {
+        self.median
+    }
+}
+
+/// `1/2 + atan(z) / π`, accurate also in the lower tail.
+fn standard_cauchy_cdf<F: Float + FloatConst>(z: F) -> F {
+    if z < F::zero() {
+        (-z.recip()).atan() / F::PI()
+    } else {
+        F::from(0.5).unwrap() + z.atan() / F::PI()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -197,6 +324,66 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_cauchy_pdf() {
+        let cauchy = Cauchy::new(10.0, 5.0).unwrap();
+        assert_almost_eq!(cauchy.pdf(3.0), 0.021507424742148018347, 1e-16);
+        assert_almost_eq!(cauchy.pdf(10.0), 1.0 / (5.0 * core::f64::consts::PI), 1e-16);
+        assert_almost_eq!(cauchy.ln_pdf(1e200), -920.56932917103357335, 1e-12);
+    }
+
+    #[test]
+    fn test_cauchy_cdf() {
+        let cauchy = Cauchy::new(10.0, 5.0).unwrap();
+        assert_almost_eq!(cauchy.cdf(3.0), 0.19743154328874657005, 1e-16);
+        assert_almost_eq!(cauchy.sf(3.0), 0.80256845671125342995, 1e-15);
+        assert_eq!(cauchy.cdf(10.0), 0.5);
+        assert_almost_eq!(cauchy.sf(1e20) / 1.5915494309189533577e-20, 1.0, 1e-15);
+    }
+
+    #[test]
+    fn test_cauchy_quantile() {
+        let cauchy = Cauchy::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(cauchy.quantile(0.3), -0.45308505601072187837, 1e-15);
+        assert_almost_eq!(cauchy.quantile(1e-12) / -636619772366.58135588, 1.0, 1e-14);
+        assert_almost_eq!(cauchy.quantile(0.999), 637.61767797110032641, 1e-10);
+        assert_eq!(cauchy.quantile(0.5), 1.0);
+        assert_eq!(cauchy.quantile(0.0), f64::NEG_INFINITY);
+        assert_eq!(cauchy.quantile(1.0), f64::INFINITY);
+        assert!(cauchy.quantile(2.0).is_nan());
+    }
+
+    #[test]
+    fn test_cauchy_moments() {
+        let cauchy = Cauchy::new(1.0, 2.0).unwrap();
+        assert_eq!(cauchy.mean(), None);
+        assert_eq!(cauchy.variance(), None);
+        assert_eq!(cauchy.skewness(), None);
+        assert_eq!(cauchy.excess_kurtosis(), None);
+    }
+
+    #[test]
+    fn test_cauchy_entropy() {
+        let cauchy = Cauchy::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(cauchy.entropy(), 3.2241714275292361, 1e-15);
+    }
+
+    #[test]
+    fn test_cauchy_characteristic() {
+        let d = Cauchy::new(1.5, 2.0).unwrap();
+        let phi = d.characteristic(-0.5);
+        assert_almost_eq!(phi.re, (-1.0f64).exp() * 0.75f64.cos(), 1e-16);
+        assert_almost_eq!(phi.im, -(-1.0f64).exp() * 0.75f64.sin(), 1e-16);
+        assert_eq!(d.characteristic(0.0).re, 1.0);
+    }
+
+    #[test]
+    fn test_cauchy_summary() {
+        let d = Cauchy::new(1.5, 2.0).unwrap();
+        assert_eq!(d.support(), Support::real_line());
+        assert_eq!((d.mode(), d.median()), (Some(1.5), 1.5));
+    }
+
     #[test]
     fn cauchy_distributions_can_be_compared() {
         assert_eq!(Cauchy::new(1.0, 2.0), Cauchy::new(1.0, 2.0));
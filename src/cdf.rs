@@ -0,0 +1,41 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Cumulative distribution functions.
+
+use num_traits::Float;
+
+/// The cumulative distribution function (CDF) of a univariate distribution,
+/// together with its complement, the survival function.
+///
+/// For discrete distributions `x` may be any real number: `cdf(x)` is
+/// `P(X <= floor(x))`, so `cdf(2.5) == cdf(2.0)`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Cdf, Normal};
+///
+/// let normal = Normal::new(0.0f64, 1.0).unwrap();
+/// assert_eq!(normal.cdf(0.0), 0.5);
+///
+/// // Far in the upper tail `1 - cdf(x)` rounds to zero, but `sf(x)` does not:
+/// assert_eq!(1.0 - normal.cdf(10.0), 0.0);
+/// assert!((normal.sf(10.0) / 7.619853024160527e-24 - 1.0).abs() < 1e-12);
+/// ```
+pub trait Cdf<F: Float> {
+    /// Evaluate the cumulative distribution function at `x`, i.e.
+    /// `P(X <= x)`.
+    fn cdf(&self, x: F) -> F;
+
+    /// Evaluate the survival function at `x`, i.e. `P(X > x)`.
+    ///
+    /// This is computed directly rather than as `1 - cdf(x)`, so it keeps
+    /// full relative precision in the upper tail.
+    fn sf(&self, x: F) -> F;
+}
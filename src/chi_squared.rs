@@ -11,8 +11,15 @@
 
 use self::ChiSquaredRepr::*;
 
-use crate::{Distribution, Exp1, Gamma, Open01, StandardNormal};
+use crate::gamma::{gamma_entropy, gamma_ln_pdf};
+use crate::quantile::invert_cdf;
+use crate::special::gamma_pq;
+use crate::{
+    Cdf, ContinuousPdf, Distribution, Entropy, Exp1, Gamma, Moments, Open01, Quantile,
+    StandardNormal, Summary, Support,
+};
 use core::fmt;
+use core::ops::Bound;
 use num_traits::Float;
 use rand::Rng;
 #[cfg(feature = "serde")]
@@ -113,6 +120,14 @@ where
         };
         Ok(ChiSquared { repr })
     }
+
+    /// Degrees of freedom `k`
+    pub(crate) fn dof(&self) -> F {
+        match self.repr {
+            DoFExactlyOne => F::one(),
+            DoFAnythingElse(ref g) => F::from(2.0).unwrap() * g.params().0,
+        }
+    }
 }
 impl<F> Distribution<F> for ChiSquared<F>
 where
@@ -133,6 +148,120 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for ChiSquared<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        gamma_ln_pdf(F::from(0.5).unwrap() * self.dof(), F::from(2.0).unwrap(), x)
+    }
+}
+
+impl<F> Cdf<F> for ChiSquared<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::zero();
+        }
+        let half = F::from(0.5).unwrap();
+        gamma_pq(half * self.dof(), half * x).0
+    }
+
+    fn sf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::one();
+        }
+        let half = F::from(0.5).unwrap();
+        gamma_pq(half * self.dof(), half * x).1
+    }
+}
+
+impl<F> Quantile<F> for ChiSquared<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        invert_cdf(self, p, F::zero(), F::infinity(), self.dof(), F::one())
+    }
+}
+
+impl<F> Moments<F> for ChiSquared<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some(self.dof())
+    }
+
+    fn variance(&self) -> Option<F> {
+        Some(F::from(2.0).unwrap() * self.dof())
+    }
+
+    fn skewness(&self) -> Option<F> {
+        Some((F::from(8.0).unwrap() / self.dof()).sqrt())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        Some(F::from(12.0).unwrap() / self.dof())
+    }
+}
+
+impl<F> Entropy<F> for ChiSquared<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        gamma_entropy(F::from(0.5).unwrap() * self.dof(), F::from(2.0).unwrap())
+    }
+}
+
+impl<F> Summary<F> for ChiSquared<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        // The density is unbounded at zero for one degree of freedom or less
+        let two = F::from(2.0).unwrap();
+        let lower = if self.dof() >= two {
+            Bound::Included(F::zero())
+        } else {
+            Bound::Excluded(F::zero())
+        };
+        Support::Real {
+            lower,
+            upper: Bound::Unbounded,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some((self.dof() - F::from(2.0).unwrap()).max(F::zero()))
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -167,6 +296,63 @@ mod test {
         ChiSquared::new(-1.0).unwrap();
     }
 
+    #[test]
+    fn test_chi_squared_pdf() {
+        let chi = ChiSquared::new(3.0).unwrap();
+        assert_almost_eq!(chi.pdf(2.0), 0.20755374871029733748, 1e-16);
+        let chi = ChiSquared::new(1.0).unwrap();
+        assert_almost_eq!(chi.pdf(2.0), 0.10377687435514866874, 1e-16);
+        assert_eq!(chi.pdf(-2.0), 0.0);
+    }
+
+    #[test]
+    fn test_chi_squared_cdf() {
+        let chi = ChiSquared::new(3.0).unwrap();
+        assert_almost_eq!(chi.cdf(2.0), 0.42759329552912016600, 1e-15);
+        assert_almost_eq!(chi.sf(2.0), 0.57240670447087983400, 1e-15);
+        let chi = ChiSquared::new(1.0).unwrap();
+        assert_almost_eq!(chi.sf(100.0) / 1.5239706048321052132e-23, 1.0, 1e-13);
+        assert_eq!(chi.cdf(-2.0), 0.0);
+    }
+
+    #[test]
+    fn test_chi_squared_quantile() {
+        let chi = ChiSquared::new(3.0).unwrap();
+        assert_almost_eq!(chi.quantile(0.95), 7.8147279032511779735, 1e-14);
+        let chi = ChiSquared::new(1.0).unwrap();
+        assert_almost_eq!(chi.quantile(0.5), 0.45493642311957275194, 1e-15);
+        assert_eq!(chi.quantile(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_chi_squared_moments() {
+        let chi = ChiSquared::new(3.0).unwrap();
+        assert_eq!(chi.mean(), Some(3.0));
+        assert_eq!(chi.variance(), Some(6.0));
+        assert_almost_eq!(chi.skewness().unwrap(), 1.6329931618554520655, 1e-15);
+        assert_eq!(chi.excess_kurtosis(), Some(4.0));
+        assert_eq!(ChiSquared::new(1.0).unwrap().variance(), Some(2.0));
+    }
+
+    #[test]
+    fn test_chi_squared_entropy() {
+        let chi = ChiSquared::new(1.0).unwrap();
+        assert_almost_eq!(chi.entropy(), 0.78375711047393365677, 1e-15);
+        let chi = ChiSquared::new(7.0).unwrap();
+        assert_almost_eq!(chi.entropy(), 2.6362291812939115662, 1e-15);
+    }
+
+    #[test]
+    fn test_chi_squared_summary() {
+        let d = ChiSquared::new(3.0).unwrap();
+        assert_eq!(d.mode(), Some(1.0));
+        assert_almost_eq!(d.median(), 2.3659738843753382661, 1e-14);
+        assert!(d.support().contains(0.0));
+        let d = ChiSquared::new(1.0).unwrap();
+        assert_eq!(d.mode(), Some(0.0));
+        assert!(!d.support().contains(0.0));
+    }
+
     #[test]
     fn gamma_distributions_can_be_compared() {
         assert_eq!(Gamma::new(1.0, 2.0), Gamma::new(1.0, 2.0));
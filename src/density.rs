@@ -0,0 +1,78 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Probability density and mass functions.
+
+use num_traits::Float;
+
+/// The probability density function (PDF) of a continuous distribution.
+///
+/// Implementations use the same parameterization as the distribution's
+/// constructor. Outside of the support of the distribution, `pdf` returns
+/// zero and `ln_pdf` returns negative infinity.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{ContinuousPdf, Normal};
+///
+/// let normal = Normal::new(2.0, 3.0).unwrap();
+/// let density = normal.pdf(2.0);
+/// assert!((density - 1.0 / (3.0 * (2.0 * std::f64::consts::PI).sqrt())).abs() < 1e-15);
+///
+/// // Far in the tail the density underflows, but its logarithm does not:
+/// assert_eq!(normal.pdf(200.0), 0.0);
+/// assert!(normal.ln_pdf(200.0).is_finite());
+/// ```
+pub trait ContinuousPdf<F: Float> {
+    /// Evaluate the probability density function at `x`.
+    fn pdf(&self, x: F) -> F {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Evaluate the natural logarithm of the probability density function
+    /// at `x`.
+    ///
+    /// This is computed directly rather than as `pdf(x).ln()`, so it stays
+    /// finite and accurate where the density underflows.
+    fn ln_pdf(&self, x: F) -> F;
+}
+
+/// The probability mass function (PMF) of a discrete distribution.
+///
+/// Outcomes are passed as `u64`, whatever type the distribution samples;
+/// probabilities are returned in the distribution's floating-point type.
+/// Outside of the support of the distribution, `pmf` returns zero and
+/// `ln_pmf` returns negative infinity.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Binomial, DiscretePmf};
+///
+/// let binomial = Binomial::new(4, 0.5).unwrap();
+/// assert_eq!(binomial.pmf(2), 0.375);
+///
+/// // Log-probabilities remain finite where the probability underflows:
+/// let binomial = Binomial::new(1_000_000, 0.5).unwrap();
+/// assert_eq!(binomial.pmf(0), 0.0);
+/// assert!((binomial.ln_pmf(0) - 1_000_000.0 * 0.5f64.ln()).abs() < 1e-6);
+/// ```
+pub trait DiscretePmf<F: Float> {
+    /// Evaluate the probability mass function at `k`, i.e. `P(X = k)`.
+    fn pmf(&self, k: u64) -> F {
+        self.ln_pmf(k).exp()
+    }
+
+    /// Evaluate the natural logarithm of the probability mass function at
+    /// `k`.
+    ///
+    /// This is computed directly rather than as `pmf(k).ln()`, so it stays
+    /// finite and accurate where the probability underflows.
+    fn ln_pmf(&self, k: u64) -> F;
+}
@@ -10,7 +10,14 @@
 //! The dirichlet distribution `Dirichlet(α₁, α₂, ..., αₙ)`.
 
 #![cfg(feature = "alloc")]
-use crate::{Beta, Distribution, Exp1, Gamma, Open01, StandardNormal};
+use crate::divergence::dirichlet_kl;
+use crate::entropy::dirichlet_entropy;
+use crate::fit::{dirichlet_moments, fit_dirichlet, Welford};
+use crate::special::ln_gamma;
+use crate::{
+    Beta, Distribution, Entropy, Exp1, FitError, FitOptions, Fitted, Gamma, KlDivergence, Open01,
+    StandardNormal,
+};
 use core::fmt;
 use num_traits::{Float, NumCast};
 use rand::Rng;
@@ -223,6 +230,7 @@ where
     Exp1: Distribution<F>,
     Open01: Distribution<F>,
 {
+    alpha: [F; N],
     repr: DirichletRepr<F, N>,
 }
 
@@ -304,15 +312,94 @@ where
             // the probability of generating nans is negligibly small.
             let dist = DirichletFromBeta::new(alpha).map_err(|_| Error::FailedToCreateBeta)?;
             Ok(Dirichlet {
+                alpha,
                 repr: DirichletRepr::FromBeta(dist),
             })
         } else {
             let dist = DirichletFromGamma::new(alpha).map_err(|_| Error::FailedToCreateGamma)?;
             Ok(Dirichlet {
+                alpha,
                 repr: DirichletRepr::FromGamma(dist),
             })
         }
     }
+
+    /// Fit to observations by maximum likelihood, with the default
+    /// [`FitOptions`].
+    ///
+    /// See [`Dirichlet::fit_with`].
+    pub fn fit(data: &[[F; N]]) -> Result<Fitted<Dirichlet<F, N>, F>, FitError> {
+        Self::fit_with(data, FitOptions::new())
+    }
+
+    /// Fit to observations by maximum likelihood.
+    ///
+    /// The estimates solve `ψ(αₖ) - ψ(Σⱼ αⱼ) = mean(ln xₖ)` for each
+    /// component, which are found by the fixed-point iteration of
+    /// Minka (2000)[^1] from the estimates of the method of moments.
+    ///
+    /// Fails if `data` is empty, its observations are all equal or any is
+    /// not on the simplex (with positive components that sum to one, to
+    /// within rounding), or if the iteration does not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Dirichlet;
+    ///
+    /// let data = [[0.2, 0.3, 0.5], [0.1, 0.6, 0.3], [0.3, 0.3, 0.4], [0.25, 0.45, 0.3]];
+    /// let fitted = Dirichlet::fit(&data).unwrap();
+    /// println!("{:?} after {} iterations", fitted.distribution, fitted.iterations);
+    /// ```
+    ///
+    /// [^1]: Thomas P. Minka (2000). *Estimating a Dirichlet distribution*.
+    pub fn fit_with(
+        data: &[[F; N]],
+        options: FitOptions<F>,
+    ) -> Result<Fitted<Dirichlet<F, N>, F>, FitError> {
+        let tolerance = F::from(N).unwrap() * F::epsilon().sqrt();
+        let mut moments = [Welford::new(); N];
+        let mut mean_logs = [F::zero(); N];
+        for (i, x) in data.iter().enumerate() {
+            let n = F::from(i + 1).unwrap();
+            let mut sum = F::zero();
+            for ((&xk, w), mean_log) in x.iter().zip(&mut moments).zip(&mut mean_logs) {
+                if !(xk > F::zero()) {
+                    return Err(FitError::OutOfSupport);
+                }
+                sum = sum + xk;
+                w.push(xk);
+                *mean_log = *mean_log + (xk.ln() - *mean_log) / n;
+            }
+            if !((sum - F::one()).abs() <= tolerance) {
+                return Err(FitError::OutOfSupport);
+            }
+        }
+        if data.is_empty() {
+            return Err(FitError::Empty);
+        }
+        if moments.iter().all(|w| w.m2 == F::zero()) {
+            return Err(FitError::Constant);
+        }
+
+        let mut alpha = [F::zero(); N];
+        dirichlet_moments(&moments, &mut alpha);
+        let iterations = fit_dirichlet(&mean_logs, &mut alpha, options)?;
+
+        let n = F::from(data.len()).unwrap();
+        let alpha_sum = alpha.iter().fold(F::zero(), |sum, &a| sum + a);
+        let log_likelihood = n * alpha
+            .iter()
+            .zip(&mean_logs)
+            .fold(ln_gamma(alpha_sum), |ll, (&a, &mean_log)| {
+                ll - ln_gamma(a) + (a - F::one()) * mean_log
+            });
+        Ok(Fitted {
+            distribution: Dirichlet::new(alpha).map_err(|_| FitError::NoConvergence)?,
+            iterations,
+            log_likelihood,
+        })
+    }
 }
 
 impl<F, const N: usize> Distribution<[F; N]> for Dirichlet<F, N>
@@ -330,6 +417,30 @@ where
     }
 }
 
+impl<F, const N: usize> Entropy<F> for Dirichlet<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        dirichlet_entropy(&self.alpha)
+    }
+}
+
+impl<F, const N: usize> KlDivergence<F> for Dirichlet<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn kl_divergence(&self, other: &Self) -> F {
+        dirichlet_kl(&self.alpha, &other.alpha)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -443,4 +554,83 @@ mod test {
         let seed = 1317624576693539401;
         check_dirichlet_means(alpha, n, rtol, seed);
     }
+
+    #[test]
+    fn test_dirichlet_entropy() {
+        let d = Dirichlet::new([1.0, 2.0, 3.0]).unwrap();
+        assert_almost_eq!(d.entropy(), -1.2443445622221006848, 1e-15);
+        let d = Dirichlet::new([0.05; 3]).unwrap();
+        assert_almost_eq!(d.entropy(), -31.330203366031876577, 1e-13);
+        let d = Dirichlet::new([1e6, 2e6, 3e6]).unwrap();
+        assert_almost_eq!(d.entropy(), -14.561152818900033993, 1e-13);
+        // The Dirichlet distribution with two parameters is a beta distribution
+        let beta = Beta::new(0.5, 3.0).unwrap();
+        let d = Dirichlet::new([0.5, 3.0]).unwrap();
+        assert_almost_eq!(d.entropy(), beta.entropy(), 1e-15);
+    }
+
+    #[test]
+    fn test_dirichlet_kl_divergence() {
+        let p = Dirichlet::new([1.0, 2.0, 3.0]).unwrap();
+        let q = Dirichlet::new([3.0, 2.0, 1.0]).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 3.0, 1e-14);
+        assert_eq!(p.kl_divergence(&p), 0.0);
+        let p = Dirichlet::new([0.05; 3]).unwrap();
+        let q = Dirichlet::new([0.1; 3]).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 0.60504388044272296574, 1e-14);
+    }
+
+    #[test]
+    fn test_dirichlet_fit() {
+        let mut rng = crate::test::rng(226);
+        let alpha = [0.5, 2.0, 6.0];
+        let dist = Dirichlet::new(alpha).unwrap();
+        let data: [[f64; 3]; 5000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        let fitted = Dirichlet::fit(&data).unwrap();
+        for (a, alpha) in fitted.distribution.alpha.iter().zip(alpha) {
+            assert_almost_eq!(a / alpha, 1.0, 0.05);
+        }
+        // The log-likelihood is at a maximum
+        let ll = |alpha: [f64; 3]| {
+            let d = Dirichlet::new(alpha).unwrap();
+            let a0: f64 = alpha.iter().sum();
+            data.iter()
+                .map(|x| {
+                    crate::special::ln_gamma(a0)
+                        + x.iter()
+                            .zip(&d.alpha)
+                            .map(|(&x, &a)| (a - 1.0) * x.ln() - crate::special::ln_gamma(a))
+                            .sum::<f64>()
+                })
+                .sum::<f64>()
+        };
+        let best = fitted.distribution.alpha;
+        assert_almost_eq!(fitted.log_likelihood, ll(best), 1e-8);
+        for k in 0..3 {
+            for &h in &[0.99, 1.01] {
+                let mut alpha = best;
+                alpha[k] *= h;
+                assert!(ll(alpha) < fitted.log_likelihood);
+            }
+        }
+
+        assert_eq!(Dirichlet::<f64, 2>::fit(&[]), Err(FitError::Empty));
+        assert_eq!(
+            Dirichlet::fit(&[[0.25, 0.75], [0.25, 0.75]]),
+            Err(FitError::Constant)
+        );
+        assert_eq!(
+            Dirichlet::fit(&[[0.25, 0.75], [0.5, 0.6]]),
+            Err(FitError::OutOfSupport)
+        );
+        assert_eq!(
+            Dirichlet::fit(&[[0.25, 0.75], [0.0, 1.0]]),
+            Err(FitError::OutOfSupport)
+        );
+        let options = FitOptions::new().with_max_iterations(2);
+        assert_eq!(
+            Dirichlet::fit_with(&data, options),
+            Err(FitError::NoConvergence)
+        );
+    }
 }
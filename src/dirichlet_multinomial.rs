@@ -0,0 +1,241 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Dirichlet-multinomial distribution `DirichletMultinomial(n, α)`.
+
+use crate::beta_binomial::{sample_two_stage, sample_urn, URN_MAX_N};
+use crate::special::ln_beta;
+use crate::{Beta, Distribution};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::Rng;
+
+/// The [Dirichlet-multinomial distribution](https://en.wikipedia.org/wiki/Dirichlet-multinomial_distribution)
+/// `DirichletMultinomial(n, α)` with `N` categories.
+///
+/// The counts of each category in `n` trials whose category probabilities
+/// follow the [`Dirichlet`](crate::Dirichlet) distribution `Dirichlet(α)`:
+/// the [`Multinomial`](crate::Multinomial) distribution with uncertain
+/// probabilities, as for a Pólya urn with `αᵢ` balls of each colour `i`. Each
+/// count `xᵢ` follows the [`BetaBinomial`](crate::BetaBinomial) distribution
+/// `BetaBinomial(n, αᵢ, ∑ α - αᵢ)`, and the counts sum to `n`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{DirichletMultinomial, Distribution};
+///
+/// let dm = DirichletMultinomial::new(10, [1.0, 2.0, 3.0]).unwrap();
+/// let counts = dm.sample(&mut rand::rng());
+/// assert_eq!(counts.iter().sum::<u64>(), 10);
+/// ```
+///
+/// # Implementation details
+///
+/// The counts are sampled in turn from the conditional distribution of each
+/// count given the previous ones, which is the
+/// [`BetaBinomial`](crate::BetaBinomial) distribution with the remaining
+/// trials, the parameter of the category and the sum of the parameters of the
+/// later categories. These are fixed, so the [`Beta`] distributions of the
+/// conditional probabilities are constructed with the distribution. A count
+/// is then sampled by simulating the Pólya urn when few trials remain, and
+/// otherwise from the [`Binomial`](crate::Binomial) distribution with a
+/// probability sampled from the conditional [`Beta`] distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DirichletMultinomial<const N: usize> {
+    n: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    alpha: [f64; N],
+    /// The sums of the parameters of the categories after each one
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    rest: [f64; N],
+    /// The distributions of the probability of each category given the
+    /// previous ones, `Beta(αᵢ, rest[i])`, except for the last category
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    conditional: [Option<Beta<f64>>; N],
+}
+
+/// Error type returned from [`DirichletMultinomial::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `N == 0`.
+    BadDimension,
+    /// A parameter `alpha[i]` is not positive, infinite or `nan`.
+    AlphaOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::BadDimension => "zero categories in Dirichlet-multinomial distribution",
+            Error::AlphaOutOfRange => {
+                "alpha is not positive and finite in Dirichlet-multinomial distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<const N: usize> DirichletMultinomial<N> {
+    /// Construct a new `DirichletMultinomial` with `n` trials and the
+    /// parameters `alpha` of the distribution of the category probabilities.
+    ///
+    /// Requires `N >= 1` and finite `alpha[i] > 0`.
+    pub fn new(n: u64, alpha: [f64; N]) -> Result<DirichletMultinomial<N>, Error> {
+        if N == 0 {
+            return Err(Error::BadDimension);
+        }
+        if !alpha.iter().all(|&a| a > 0.0 && a.is_finite()) {
+            return Err(Error::AlphaOutOfRange);
+        }
+        let mut rest = [0.0; N];
+        for i in (0..N - 1).rev() {
+            rest[i] = rest[i + 1] + alpha[i + 1];
+        }
+        let conditional = core::array::from_fn(|i| {
+            if i + 1 < N {
+                Some(Beta::new(alpha[i], rest[i]).unwrap())
+            } else {
+                None
+            }
+        });
+        Ok(DirichletMultinomial {
+            n,
+            alpha,
+            rest,
+            conditional,
+        })
+    }
+
+    /// Evaluate the probability mass function at the counts `x`.
+    pub fn pmf(&self, x: &[u64; N]) -> f64 {
+        self.ln_pmf(x).exp()
+    }
+
+    /// Evaluate the natural logarithm of the probability mass function at
+    /// the counts `x`.
+    pub fn ln_pmf(&self, x: &[u64; N]) -> f64 {
+        if x.iter().try_fold(0u64, |sum, &x| sum.checked_add(x)) != Some(self.n) {
+            return f64::NEG_INFINITY;
+        }
+        if self.n == 0 {
+            return 0.0;
+        }
+        // f(x) = n B(∑ α, n) / ∏ xᵢ B(αᵢ, xᵢ), over the nonzero counts
+        let n = self.n as f64;
+        let sum = self.alpha.iter().sum();
+        x.iter()
+            .zip(self.alpha.iter())
+            .filter(|(&x, _)| x > 0)
+            .fold(n.ln() + ln_beta(sum, n), |ln_f, (&x, &a)| {
+                let x = x as f64;
+                ln_f - x.ln() - ln_beta(a, x)
+            })
+    }
+}
+
+impl<const N: usize> Distribution<[u64; N]> for DirichletMultinomial<N> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [u64; N] {
+        let mut counts = [0; N];
+        let mut trials = self.n;
+        let categories = counts
+            .iter_mut()
+            .zip(self.alpha.iter().zip(&self.rest))
+            .zip(&self.conditional)
+            .take(N - 1);
+        for ((x, (&a, &rest)), conditional) in categories {
+            if trials == 0 {
+                break;
+            }
+            *x = if trials <= URN_MAX_N {
+                sample_urn(trials, a, rest, rng)
+            } else {
+                sample_two_stage(trials, conditional.as_ref().unwrap(), rng)
+            };
+            trials -= *x;
+        }
+        counts[N - 1] += trials;
+        counts
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{BetaBinomial, DiscretePmf};
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            DirichletMultinomial::<0>::new(5, []),
+            Err(Error::BadDimension)
+        );
+        assert_eq!(
+            DirichletMultinomial::new(5, [1.0, 0.0]),
+            Err(Error::AlphaOutOfRange)
+        );
+        assert_eq!(
+            DirichletMultinomial::new(5, [1.0, f64::NAN]),
+            Err(Error::AlphaOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_pmf() {
+        let dm = DirichletMultinomial::new(4, [1.0, 2.0, 3.0]).unwrap();
+        assert_almost_eq!(dm.pmf(&[1, 1, 2]), 2.0 / 21.0, 1e-15);
+        assert_eq!(dm.pmf(&[1, 1, 1]), 0.0);
+        assert_eq!(dm.pmf(&[u64::MAX, 5, 0]), 0.0);
+        let dm = DirichletMultinomial::new(4, [0.5, 2.0, 3.0]).unwrap();
+        assert_almost_eq!(dm.pmf(&[0, 0, 4]), 0.15795968737145208, 1e-15);
+        let dm = DirichletMultinomial::new(0, [0.5, 2.0]).unwrap();
+        assert_eq!(dm.pmf(&[0, 0]), 1.0);
+
+        // With two categories, the beta-binomial distribution
+        let dm = DirichletMultinomial::new(10, [2.5, 1.5]).unwrap();
+        let bb = BetaBinomial::new(10, 2.5, 1.5).unwrap();
+        for k in 0..=10 {
+            assert_almost_eq!(dm.pmf(&[k, 10 - k]), bb.pmf(k), 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let alpha = [0.5, 2.0, 1.5];
+        let dm = DirichletMultinomial::new(40, alpha).unwrap();
+        let mut rng = crate::test::rng(251);
+        const SAMPLES: usize = 100_000;
+        let mut sum = [0u64; 3];
+        for _ in 0..SAMPLES {
+            let counts = dm.sample(&mut rng);
+            assert_eq!(counts.iter().sum::<u64>(), 40);
+            for (s, x) in sum.iter_mut().zip(counts.iter()) {
+                *s += x;
+            }
+        }
+        for (&s, &a) in sum.iter().zip(alpha.iter()) {
+            assert_almost_eq!(s as f64 / SAMPLES as f64, 40.0 * a / 4.0, 0.1);
+        }
+
+        let dm = DirichletMultinomial::new(7, [1.0]).unwrap();
+        assert_eq!(dm.sample(&mut rng), [7]);
+    }
+}
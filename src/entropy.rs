@@ -0,0 +1,169 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Differential and Shannon entropy.
+
+use crate::special::{ln_minus_digamma, stirlerr, LN_2PI};
+use crate::utils::integrate;
+use crate::{DiscretePmf, Moments};
+use num_traits::Float;
+
+/// The entropy of a distribution, in nats.
+///
+/// For continuous distributions this is the differential entropy
+/// `-∫ f(x) ln f(x) dx`, which may be negative; for discrete distributions it
+/// is the Shannon entropy `-Σ p(k) ln p(k)`. Divide by `ln 2` to convert to
+/// bits.
+///
+/// # Accuracy
+///
+/// Closed forms are evaluated to within a few units in the last place of the
+/// result, except where the entropy is close to zero as the result of
+/// cancellation between terms of similar size; the digamma and log-gamma
+/// functions involved are combined so that they do not cancel for large
+/// parameters. The entropy of the [`InverseGaussian`](crate::InverseGaussian),
+/// [`NormalInverseGaussian`](crate::NormalInverseGaussian) and
+/// [`SkewNormal`](crate::SkewNormal) distributions is computed by numerical
+/// integration and that of the [`Poisson`](crate::Poisson),
+/// [`Binomial`](crate::Binomial) and [`Hypergeometric`](crate::Hypergeometric)
+/// distributions by summation, or for large variances by an asymptotic
+/// expansion. These are accurate to a relative error of about `1e-13` for
+/// `f64`, or to the accuracy of the density itself for extreme parameters.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Entropy, Exp, Normal};
+///
+/// let exp = Exp::new(1.0f64).unwrap();
+/// assert_eq!(exp.entropy(), 1.0);
+///
+/// // The entropy of the normal distribution is ln(σ √(2πe))
+/// let normal = Normal::new(0.0f64, 2.0).unwrap();
+/// let expected = (2.0 * (2.0 * core::f64::consts::PI).sqrt()).ln() + 0.5;
+/// assert!((normal.entropy() - expected).abs() < 1e-15);
+/// ```
+pub trait Entropy<F: Float> {
+    /// The entropy in nats.
+    fn entropy(&self) -> F;
+}
+
+/// The entropy of the Dirichlet distribution with parameters `alpha` (of
+/// which the beta distribution is the case with two parameters).
+///
+/// This is `ln B(α) + (α₀ - K) ψ(α₀) - Σ (αⱼ - 1) ψ(αⱼ)` for `K` parameters
+/// with sum `α₀`, rearranged using Stirling's series so that the large terms
+/// of the log-gamma and digamma functions cancel analytically.
+pub(crate) fn dirichlet_entropy<F: Float>(alpha: &[F]) -> F {
+    let half = F::from(0.5).unwrap();
+    let k = F::from(alpha.len()).unwrap();
+    let a0 = alpha.iter().fold(F::zero(), |sum, &a| sum + a);
+    let mut h = (k - F::one()) * half * F::from(LN_2PI).unwrap() + (half - k) * a0.ln()
+        - stirlerr(a0)
+        - (a0 - k) * ln_minus_digamma(a0);
+    for &a in alpha {
+        h = h + half * a.ln() + stirlerr(a) + (a - F::one()) * ln_minus_digamma(a);
+    }
+    h
+}
+
+/// The differential entropy of a continuous distribution with log-density
+/// `ln_pdf` and support `(lo, +inf)`, evaluated by quadrature.
+///
+/// For `lo = -inf` the real line is mapped to `(-1, 1)` by
+/// `x = loc + scale t / (1 - t²)`; otherwise the half-line is mapped by
+/// `x = lo + (loc - lo) exp(scale t / (1 - t²))`, so `scale` is then a width
+/// on the logarithmic scale. The integral is split at `loc`, which should be
+/// close to the mode or to any sharp feature of the density.
+pub(crate) fn numeric_entropy<F, G>(ln_pdf: G, lo: F, loc: F, scale: F) -> F
+where
+    F: Float,
+    G: Fn(F) -> F,
+{
+    let one = F::one();
+    let integrand = |t: F| {
+        let u = one - t * t;
+        let w = t / u;
+        let (x, dx) = if lo == F::neg_infinity() {
+            (loc + scale * w, one)
+        } else {
+            let x = lo + (loc - lo) * (scale * w).exp();
+            (x, x - lo)
+        };
+        let l = ln_pdf(x);
+        if !x.is_finite() || !l.is_finite() {
+            return F::zero();
+        }
+        // Evaluate the Jacobian last: it overflows where the density has
+        // long since underflowed.
+        let h = -l.exp() * l;
+        if h == F::zero() {
+            return h;
+        }
+        h * dx * scale * (one + t * t) / (u * u)
+    };
+    integrate(integrand, -one, F::zero()) + integrate(integrand, F::zero(), one)
+}
+
+/// The Shannon entropy of a unimodal distribution on the non-negative
+/// integers.
+///
+/// The terms are summed outwards from the mean while they are significant.
+/// For variances `σ² > 1e5` the Edgeworth expansion
+/// `½ ln(2πeσ²) - γ₁²/12 - γ₂²/48 + γ₁²γ₂/8 - 7γ₁⁴/48 + O(σ⁻⁶)` in terms of
+/// the skewness `γ₁` and excess kurtosis `γ₂` is used instead. This applies
+/// to lattice distributions whose probabilities extend to a smooth function,
+/// such as the Poisson, binomial and hypergeometric distributions, since by
+/// the Poisson summation formula the sum then differs from the corresponding
+/// integral only by terms of order `exp(-2π²σ²)`.
+pub(crate) fn lattice_entropy<F, D>(dist: &D) -> F
+where
+    F: Float,
+    D: DiscretePmf<F> + Moments<F> + ?Sized,
+{
+    let (mean, var) = (dist.mean().unwrap(), dist.variance().unwrap());
+    let c = |x: f64| F::from(x).unwrap();
+    if var > c(1e5) {
+        let (g1, g2) = (dist.skewness().unwrap(), dist.excess_kurtosis().unwrap());
+        let g1_2 = g1 * g1;
+        let normal = c(0.5) * (c(LN_2PI + 1.0) + var.ln());
+        return normal - g1_2 / c(12.0) - g2 * g2 / c(48.0) + g1_2 * g2 / c(8.0)
+            - c(7.0 / 48.0) * g1_2 * g1_2;
+    }
+
+    let term = |k: u64| {
+        let l = dist.ln_pmf(k);
+        if l == F::neg_infinity() {
+            F::zero()
+        } else {
+            -l.exp() * l
+        }
+    };
+    let tol = c(0.1) * F::epsilon();
+    let start = mean.round().to_u64().unwrap_or(0);
+    let mut sum = F::zero();
+    let mut k = start;
+    loop {
+        let t = term(k);
+        sum = sum + t;
+        if t <= tol * sum || k == u64::MAX {
+            break;
+        }
+        k += 1;
+    }
+    k = start;
+    while k > 0 {
+        k -= 1;
+        let t = term(k);
+        sum = sum + t;
+        if t <= tol * sum {
+            break;
+        }
+    }
+    sum
+}
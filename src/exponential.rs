@@ -9,9 +9,15 @@
 
 //! The exponential distribution `Exp(λ)`.
 
+use crate::special::log1pmx;
 use crate::utils::ziggurat;
-use crate::{ziggurat_tables, Distribution};
+use crate::{
+    ziggurat_tables, Cdf, CharacteristicFunction, ContinuousPdf, Distribution, Entropy, FitError,
+    KlDivergence, Mgf, Moments, Quantile, Summary, Support,
+};
 use core::fmt;
+use core::ops::Bound;
+use num_complex::Complex;
 use num_traits::Float;
 use rand::Rng;
 
@@ -85,6 +91,96 @@ impl Distribution<f64> for Exp1 {
     }
 }
 
+impl<F: Float> ContinuousPdf<F> for Exp1 {
+    fn ln_pdf(&self, x: F) -> F {
+        if x < F::zero() {
+            return F::neg_infinity();
+        }
+        -x
+    }
+}
+
+impl<F: Float> Cdf<F> for Exp1 {
+    fn cdf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::zero();
+        }
+        -(-x).exp_m1()
+    }
+
+    fn sf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::one();
+        }
+        (-x).exp()
+    }
+}
+
+impl<F: Float> Quantile<F> for Exp1 {
+    fn quantile(&self, p: F) -> F {
+        if !(p >= F::zero() && p <= F::one()) {
+            return F::nan();
+        }
+        -(-p).ln_1p()
+    }
+}
+
+impl<F: Float> Moments<F> for Exp1 {
+    fn mean(&self) -> Option<F> {
+        Some(F::one())
+    }
+
+    fn variance(&self) -> Option<F> {
+        Some(F::one())
+    }
+
+    fn skewness(&self) -> Option<F> {
+        Some(F::from(2.0).unwrap())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        Some(F::from(6.0).unwrap())
+    }
+}
+
+impl<F: Float> Entropy<F> for Exp1 {
+    fn entropy(&self) -> F {
+        F::one()
+    }
+}
+
+impl<F: Float> Mgf<F> for Exp1 {
+    fn cgf(&self, t: F) -> Option<F> {
+        if !(t < F::one()) {
+            return None;
+        }
+        Some(-(-t).ln_1p())
+    }
+}
+
+impl<F: Float> CharacteristicFunction<F> for Exp1 {
+    fn characteristic(&self, t: F) -> Complex<F> {
+        Complex::new(F::one(), -t).inv()
+    }
+}
+
+impl<F: Float> Summary<F> for Exp1 {
+    fn support(&self) -> Support<F> {
+        Support::Real {
+            lower: Bound::Included(F::zero()),
+            upper: Bound::Unbounded,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some(F::zero())
+    }
+
+    fn median(&self) -> F {
+        F::from(core::f64::consts::LN_2).unwrap()
+    }
+}
+
 /// The [exponential distribution](https://en.wikipedia.org/wiki/Exponential_distribution) `Exp(λ)`.
 ///
 /// The exponential distribution is a continuous probability distribution
@@ -171,6 +267,43 @@ where
             lambda_inverse: F::one() / lambda,
         })
     }
+
+    /// Fit to observations by maximum likelihood.
+    ///
+    /// The estimate of `λ` is the reciprocal of the sample mean, which is
+    /// accumulated in a single pass.
+    ///
+    /// Fails if `data` is empty, its values are all zero or any is negative
+    /// or not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Exp;
+    ///
+    /// let exp = Exp::fit(&[0.25, 0.5, 0.75]).unwrap();
+    /// assert_eq!(exp, Exp::new(2.0).unwrap());
+    /// ```
+    pub fn fit(data: &[F]) -> Result<Exp<F>, FitError> {
+        let mut count = 0usize;
+        let mut mean = F::zero();
+        for &x in data {
+            if !(x >= F::zero() && x.is_finite()) {
+                return Err(FitError::OutOfSupport);
+            }
+            count += 1;
+            mean = mean + (x - mean) / F::from(count).unwrap();
+        }
+        if count == 0 {
+            return Err(FitError::Empty);
+        }
+        if mean == F::zero() {
+            return Err(FitError::Constant);
+        }
+        Ok(Exp {
+            lambda_inverse: mean,
+        })
+    }
 }
 
 impl<F> Distribution<F> for Exp<F>
@@ -183,6 +316,125 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for Exp<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        if x < F::zero() {
+            return F::neg_infinity();
+        }
+        -self.lambda_inverse.ln() - x / self.lambda_inverse
+    }
+}
+
+impl<F> Cdf<F> for Exp<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        Exp1.cdf(x / self.lambda_inverse)
+    }
+
+    fn sf(&self, x: F) -> F {
+        Exp1.sf(x / self.lambda_inverse)
+    }
+}
+
+impl<F> Quantile<F> for Exp<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        Exp1.quantile(p) * self.lambda_inverse
+    }
+}
+
+impl<F> Moments<F> for Exp<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some(self.lambda_inverse)
+    }
+
+    fn variance(&self) -> Option<F> {
+        Some(self.lambda_inverse * self.lambda_inverse)
+    }
+
+    fn skewness(&self) -> Option<F> {
+        Moments::<F>::skewness(&Exp1)
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        Moments::<F>::excess_kurtosis(&Exp1)
+    }
+}
+
+impl<F> Entropy<F> for Exp<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        F::one() + self.lambda_inverse.ln()
+    }
+}
+
+impl<F> KlDivergence<F> for Exp<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn kl_divergence(&self, other: &Self) -> F {
+        // With x = λ₂ / λ₁ - 1 this is x - ln(1 + x)
+        let x = (self.lambda_inverse - other.lambda_inverse) / other.lambda_inverse;
+        -log1pmx(x)
+    }
+}
+
+impl<F> Mgf<F> for Exp<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn cgf(&self, t: F) -> Option<F> {
+        Exp1.cgf(t * self.lambda_inverse)
+    }
+}
+
+impl<F> CharacteristicFunction<F> for Exp<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn characteristic(&self, t: F) -> Complex<F> {
+        Exp1.characteristic(t * self.lambda_inverse)
+    }
+}
+
+impl<F> Summary<F> for Exp<F>
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Exp1.support()
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some(F::zero())
+    }
+
+    fn median(&self) -> F {
+        F::from(core::f64::consts::LN_2).unwrap() * self.lambda_inverse
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -212,6 +464,111 @@ mod test {
         Exp::new(f64::nan()).unwrap();
     }
 
+    #[test]
+    fn test_exp_pdf() {
+        let exp = Exp::new(10.0).unwrap();
+        assert_almost_eq!(exp.pdf(0.3), 0.49787068367863948507, 1e-15);
+        assert_almost_eq!(exp.pdf(0.0), 10.0, 1e-14);
+        assert_eq!(exp.pdf(-0.3), 0.0);
+        assert_eq!(Exp::new(0.0).unwrap().pdf(1.0), 0.0);
+        assert_eq!(ContinuousPdf::<f64>::ln_pdf(&Exp1, 2.5), -2.5);
+    }
+
+    #[test]
+    fn test_exp_cdf() {
+        let exp = Exp::new(10.0).unwrap();
+        assert_almost_eq!(exp.cdf(0.3), 0.95021293163213605702, 1e-15);
+        assert_almost_eq!(exp.sf(0.3), 0.049787068367863942979, 1e-16);
+        assert_almost_eq!(exp.cdf(1e-20), 1e-19, 1e-34);
+        assert_eq!(exp.cdf(-0.3), 0.0);
+        assert_eq!(exp.sf(-0.3), 1.0);
+        assert_eq!(Exp::new(0.0).unwrap().sf(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_exp_quantile() {
+        let exp = Exp::new(2.0).unwrap();
+        assert_almost_eq!(exp.quantile(0.3), 0.17833747196936618153, 1e-16);
+        assert_almost_eq!(exp.quantile(1e-12), 5.0000000000024998994e-13, 1e-27);
+        assert_eq!(exp.quantile(0.0), 0.0);
+        assert_eq!(exp.quantile(1.0), f64::INFINITY);
+        assert!(exp.quantile(-0.1).is_nan());
+        assert!(exp.quantile(f64::NAN).is_nan());
+
+        let mut rng = crate::test::rng(222);
+        for _ in 0..100 {
+            let x = exp.sample_by_inversion(&mut rng);
+            assert_almost_eq!(exp.cdf(exp.quantile(exp.cdf(x))), exp.cdf(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_exp_moments() {
+        let exp = Exp::new(2.0).unwrap();
+        assert_eq!(exp.mean(), Some(0.5));
+        assert_eq!(exp.variance(), Some(0.25));
+        assert_eq!(exp.skewness(), Some(2.0));
+        assert_eq!(exp.excess_kurtosis(), Some(6.0));
+    }
+
+    #[test]
+    fn test_exp_entropy() {
+        let exp = Exp::new(2.0).unwrap();
+        assert_almost_eq!(exp.entropy(), 1.0 - core::f64::consts::LN_2, 1e-16);
+        assert_eq!(Entropy::<f64>::entropy(&Exp1), 1.0);
+        let exp = Exp::new(f64::INFINITY).unwrap();
+        assert_eq!(exp.entropy(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_exp_kl_divergence() {
+        let p = Exp::new(2.0).unwrap();
+        let q = Exp::new(3.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 0.094534891891835645778, 1e-16);
+        assert_eq!(p.kl_divergence(&p), 0.0);
+        let p = Exp::new(1.0).unwrap();
+        let q = Exp::new(1.0 + 1e-6).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 4.9999966660577168850e-13, 1e-27);
+    }
+
+    #[test]
+    fn test_exp_mgf() {
+        let d = Exp::new(2.0).unwrap();
+        assert_eq!(d.mgf(1.0), Some(2.0));
+        assert_almost_eq!(d.cgf(-1.0).unwrap(), -(1.5f64.ln()), 1e-16);
+        assert_eq!(d.cgf(2.0), None);
+        assert_eq!(d.cgf(f64::INFINITY), None);
+        let phi = d.characteristic(3.0);
+        assert_almost_eq!(phi.re, 1.0 / 3.25, 1e-16);
+        assert_almost_eq!(phi.im, 1.5 / 3.25, 1e-16);
+    }
+
+    #[test]
+    fn test_exp_summary() {
+        let d = Exp::new(2.0).unwrap();
+        assert!(d.support().contains(0.0) && !d.support().contains(-1e-300));
+        assert_eq!(d.support().upper(), f64::INFINITY);
+        assert_eq!(d.mode(), Some(0.0));
+        assert_eq!(d.median(), core::f64::consts::LN_2 / 2.0);
+    }
+
+    #[test]
+    fn test_exp_fit() {
+        let mut rng = crate::test::rng(214);
+        let dist = Exp::new(4.0).unwrap();
+        let data: [f64; 10_000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        let d = Exp::fit(&data).unwrap();
+        assert_almost_eq!(d.lambda_inverse, 0.25, 0.01);
+        // A single or constant positive observation determines the rate
+        assert_eq!(Exp::fit(&[0.5, 0.5]), Ok(Exp::new(2.0).unwrap()));
+        assert_eq!(Exp::fit(&[0.0, 1.0]), Ok(Exp::new(2.0).unwrap()));
+
+        assert_eq!(Exp::<f64>::fit(&[]), Err(FitError::Empty));
+        assert_eq!(Exp::fit(&[0.0, 0.0]), Err(FitError::Constant));
+        assert_eq!(Exp::fit(&[1.0, -1.0]), Err(FitError::OutOfSupport));
+        assert_eq!(Exp::fit(&[1.0, f64::NAN]), Err(FitError::OutOfSupport));
+    }
+
     #[test]
     fn exponential_distributions_can_be_compared() {
         assert_eq!(Exp::new(1.0), Exp::new(1.0));
@@ -9,8 +9,15 @@
 
 //! The Fisher F-distribution.
 
-use crate::{ChiSquared, Distribution, Exp1, Open01, StandardNormal};
+use crate::entropy::dirichlet_entropy;
+use crate::quantile::invert_cdf;
+use crate::special::{beta_pq, ln_beta, ln_minus_digamma};
+use crate::{
+    Cdf, ChiSquared, ContinuousPdf, Distribution, Entropy, Exp1, Moments, Open01, Quantile,
+    StandardNormal, Summary, Support,
+};
 use core::fmt;
+use core::ops::Bound;
 use num_traits::Float;
 use rand::Rng;
 #[cfg(feature = "serde")]
@@ -111,6 +118,181 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for FisherF<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        let (m, n) = (self.numer.dof(), self.denom.dof());
+        let half = F::from(0.5).unwrap();
+        let two = F::from(2.).unwrap();
+        if x < F::zero() {
+            return F::neg_infinity();
+        }
+        if x == F::zero() {
+            return if m < two {
+                F::infinity()
+            } else if m == two {
+                F::zero()
+            } else {
+                F::neg_infinity()
+            };
+        }
+        let mx = m * x;
+        -half * (m * (n / mx).ln_1p() + n * (mx / n).ln_1p()) - x.ln() - ln_beta(half * m, half * n)
+    }
+}
+
+impl<F> Cdf<F> for FisherF<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        f_pq(self.numer.dof(), self.denom.dof(), x).0
+    }
+
+    fn sf(&self, x: F) -> F {
+        f_pq(self.numer.dof(), self.denom.dof(), x).1
+    }
+}
+
+impl<F> Quantile<F> for FisherF<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        let one = F::one();
+        invert_cdf(self, p, F::zero(), F::infinity(), one, one)
+    }
+}
+
+impl<F> Moments<F> for FisherF<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        let n = self.denom.dof();
+        let two = F::from(2.0).unwrap();
+        if !(n > two) {
+            return None;
+        }
+        Some(n / (n - two))
+    }
+
+    fn variance(&self) -> Option<F> {
+        let (m, n) = (self.numer.dof(), self.denom.dof());
+        let c = |x: f64| F::from(x).unwrap();
+        if !(n > c(4.0)) {
+            return None;
+        }
+        let n2 = n - c(2.0);
+        Some(c(2.0) * n * n * (m + n2) / (m * n2 * n2 * (n - c(4.0))))
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let (m, n) = (self.numer.dof(), self.denom.dof());
+        let c = |x: f64| F::from(x).unwrap();
+        if !(n > c(6.0)) {
+            return None;
+        }
+        let num = (c(2.0) * m + n - c(2.0)) * (c(8.0) * (n - c(4.0))).sqrt();
+        Some(num / ((n - c(6.0)) * (m * (m + n - c(2.0))).sqrt()))
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let (m, n) = (self.numer.dof(), self.denom.dof());
+        let c = |x: f64| F::from(x).unwrap();
+        if !(n > c(8.0)) {
+            return None;
+        }
+        let n2 = n - c(2.0);
+        let num = m * (c(5.0) * n - c(22.0)) * (m + n2) + (n - c(4.0)) * n2 * n2;
+        Some(c(12.0) * num / (m * (n - c(6.0)) * (n - c(8.0)) * (m + n2)))
+    }
+}
+
+impl<F> Entropy<F> for FisherF<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // `X = (n/m) B/(1 - B)` with `B ~ Beta(m/2, n/2)`, so the entropy is
+        // that of the beta distribution plus `2 (ψ(a + b) - ψ(b)) + ln(n/m)`.
+        let (m, n) = (self.numer.dof(), self.denom.dof());
+        let half = F::from(0.5).unwrap();
+        let (a, b) = (half * m, half * n);
+        let two = F::from(2.0).unwrap();
+        dirichlet_entropy(&[a, b])
+            + two * ((a / b).ln_1p() + ln_minus_digamma(b) - ln_minus_digamma(a + b))
+            + (n / m).ln()
+    }
+}
+
+impl<F> Summary<F> for FisherF<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        let two = F::from(2.0).unwrap();
+        let lower = if self.numer.dof() >= two {
+            Bound::Included(F::zero())
+        } else {
+            Bound::Excluded(F::zero())
+        };
+        Support::Real {
+            lower,
+            upper: Bound::Unbounded,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        let two = F::from(2.0).unwrap();
+        let (m, n) = (self.numer.dof(), self.denom.dof());
+        if m > two {
+            Some((m - two) / m * n / (n + two))
+        } else {
+            Some(F::zero())
+        }
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
+/// `(P(X <= x), P(X > x))` for `m` and `n` degrees of freedom, via the
+/// incomplete beta function `I_{mx / (mx + n)}(m / 2, n / 2)`.
+fn f_pq<F: Float>(m: F, n: F, x: F) -> (F, F) {
+    let half = F::from(0.5).unwrap();
+    if !(x > F::zero()) {
+        return (F::zero(), F::one());
+    }
+    let z = m * x / n;
+    if z.is_infinite() {
+        return (F::one(), F::zero());
+    }
+    let (p, q) = (z / (F::one() + z), (F::one() + z).recip());
+    beta_pq(half * m, half * n, p, q)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -124,6 +306,65 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_f_pdf() {
+        let f = FisherF::new(2.0, 32.0).unwrap();
+        assert_almost_eq!(f.pdf(1.2), 0.29245301816960365625, 1e-14);
+        assert_eq!(f.pdf(0.0), 1.0);
+        let f = FisherF::new(5.0, 3.0).unwrap();
+        assert_almost_eq!(f.pdf(0.7), 0.48536743689404924462, 1e-14);
+        assert_eq!(f.pdf(0.0), 0.0);
+        assert_eq!(f.pdf(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_f_cdf() {
+        let f = FisherF::new(2.0, 32.0).unwrap();
+        assert_almost_eq!(f.cdf(1.2), 0.68561300546767607034, 1e-15);
+        assert_almost_eq!(f.sf(1.2), 0.31438699453232392966, 1e-15);
+        let f = FisherF::new(5.0, 3.0).unwrap();
+        assert_almost_eq!(f.cdf(0.7), 0.33859652481698468337, 1e-15);
+        assert_eq!(f.cdf(0.0), 0.0);
+        assert_eq!(f.sf(-1.0), 1.0);
+    }
+
+    #[test]
+    fn test_f_quantile() {
+        let f = FisherF::new(4.0, 7.0).unwrap();
+        assert_almost_eq!(f.quantile(0.95), 4.1203117268976330630, 1e-14);
+        assert_eq!(f.quantile(0.0), 0.0);
+        assert_eq!(f.quantile(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_f_moments() {
+        let d = FisherF::new(4.0, 10.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 1.25, 1e-13);
+        assert_almost_eq!(d.variance().unwrap(), 1.5625, 1e-13);
+        assert_almost_eq!(d.skewness().unwrap(), 4.0, 1e-13);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 54.0, 1e-13);
+        let d = FisherF::new(4.0, 5.0).unwrap();
+        assert!(d.variance().is_some());
+        assert_eq!(d.skewness(), None);
+    }
+
+    #[test]
+    fn test_f_entropy() {
+        let d = FisherF::new(4.0, 7.0).unwrap();
+        assert_almost_eq!(d.entropy(), 1.2767985218613503158, 1e-14);
+    }
+
+    #[test]
+    fn test_f_summary() {
+        let d = FisherF::new(5.0, 10.0).unwrap();
+        assert_almost_eq!(d.mode().unwrap(), 0.5, 1e-15);
+        assert_almost_eq!(d.median(), 0.93193316085104794520, 1e-14);
+        assert!(d.support().contains(0.0));
+        let d = FisherF::new(1.0, 10.0).unwrap();
+        assert_eq!(d.mode(), Some(0.0));
+        assert!(!d.support().contains(0.0));
+    }
+
     #[test]
     fn fisher_f_distributions_can_be_compared() {
         assert_eq!(FisherF::new(1.0, 2.0), FisherF::new(1.0, 2.0));
@@ -0,0 +1,498 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fitting distributions to observed data.
+
+use crate::special::{digamma, inv_digamma, trigamma};
+use core::fmt;
+use num_traits::Float;
+
+/// Error type returned when fitting a distribution to data, as by
+/// [`Normal::fit`](crate::Normal::fit).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FitError {
+    /// There are no observations.
+    Empty,
+    /// The observations do not determine the distribution, because they are
+    /// all equal (or, for the exponential distribution, all zero).
+    Constant,
+    /// An observation is outside the support of the distribution, or NaN.
+    OutOfSupport,
+    /// The iteration for the estimates did not converge within the maximum
+    /// number of iterations.
+    NoConvergence,
+    /// The number of order statistics or the size of the blocks is zero, or
+    /// too large for the number of observations.
+    InvalidSize,
+}
+
+impl fmt::Display for FitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FitError::Empty => "no observations to fit distribution to",
+            FitError::Constant => "observations are degenerate in distribution fit",
+            FitError::OutOfSupport => "observation outside the support in distribution fit",
+            FitError::NoConvergence => "iteration did not converge in distribution fit",
+            FitError::InvalidSize => "invalid number of order statistics or block size in fit",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FitError {}
+
+/// Options for the iterative maximum-likelihood fits, such as
+/// [`Gamma::fit_with`](crate::Gamma::fit_with).
+///
+/// The iteration stops when the relative change of every parameter in one
+/// step is at most the tolerance, which defaults to `1e-12` (or `16 ε` if
+/// that is larger), and fails with [`FitError::NoConvergence`] after the
+/// maximum number of iterations, which defaults to 1000.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{FitOptions, Gamma};
+///
+/// let options = FitOptions::new().with_tolerance(1e-6).with_max_iterations(20);
+/// let fitted = Gamma::fit_with(&[1.0, 2.0, 4.0], options).unwrap();
+/// assert!(fitted.iterations <= 20);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FitOptions<F> {
+    tolerance: F,
+    max_iterations: usize,
+}
+
+impl<F: Float> FitOptions<F> {
+    /// The default options.
+    pub fn new() -> Self {
+        FitOptions {
+            tolerance: Self::default_tolerance(),
+            max_iterations: 1000,
+        }
+    }
+
+    /// Set the relative tolerance for the change of the parameters.
+    ///
+    /// A tolerance that is not positive and finite, with which the iteration
+    /// could never converge, is replaced by the default.
+    pub fn with_tolerance(mut self, tolerance: F) -> Self {
+        self.tolerance = if tolerance > F::zero() && tolerance.is_finite() {
+            tolerance
+        } else {
+            Self::default_tolerance()
+        };
+        self
+    }
+
+    /// `1e-12`, or `16 ε` if that is larger.
+    fn default_tolerance() -> F {
+        F::from(1e-12)
+            .unwrap()
+            .max(F::from(16.0).unwrap() * F::epsilon())
+    }
+
+    /// Set the maximum number of iterations.
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Whether a parameter changing from `old` to `new` is within tolerance.
+    pub(crate) fn converged(&self, old: F, new: F) -> bool {
+        (new - old).abs() <= self.tolerance * new.abs()
+    }
+
+    /// The maximum number of iterations.
+    pub(crate) fn max_iterations(&self) -> usize {
+        self.max_iterations
+    }
+}
+
+impl<F: Float> Default for FitOptions<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A distribution fitted by an iterative maximum-likelihood method, with
+/// diagnostics of the iteration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fitted<D, F> {
+    /// The fitted distribution.
+    pub distribution: D,
+    /// The number of iterations until convergence.
+    pub iterations: usize,
+    /// The log-likelihood of the observations under the fitted distribution.
+    pub log_likelihood: F,
+}
+
+/// Running mean and sum of squared deviations, updated with Welford's
+/// algorithm so that they keep their accuracy in a single pass over the
+/// data.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Welford<F> {
+    pub(crate) count: usize,
+    pub(crate) mean: F,
+    pub(crate) m2: F,
+}
+
+impl<F: Float> Welford<F> {
+    pub(crate) fn new() -> Self {
+        Welford {
+            count: 0,
+            mean: F::zero(),
+            m2: F::zero(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, x: F) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean = self.mean + delta / F::from(self.count).unwrap();
+        self.m2 = self.m2 + delta * (x - self.mean);
+    }
+
+    /// The variance of the observations, normalized by their number as for
+    /// the maximum-likelihood estimate.
+    pub(crate) fn variance(&self) -> F {
+        self.m2 / F::from(self.count).unwrap()
+    }
+}
+
+/// Fit the parameters `alpha` of a Dirichlet distribution (or, with two
+/// parameters, of a beta distribution) to observations whose logarithms
+/// have means `mean_logs`, starting from the values in `alpha`.
+///
+/// The likelihood equations `ψ(αₖ) = ψ(Σⱼ αⱼ) + mean_logs[k]` are solved by
+/// the methods of Minka (2000)[^1]: Newton's method, whose Hessian is a
+/// diagonal plus a constant matrix and so is solved in linear time, and
+/// where a Newton step would leave the domain, the fixed-point iteration
+/// `αₖ ← ψ⁻¹(ψ(Σⱼ αⱼ) + mean_logs[k])`, which always increases the
+/// likelihood but converges only linearly. Returns the number of
+/// iterations.
+///
+/// [^1]: Thomas P. Minka (2000). *Estimating a Dirichlet distribution*.
+pub(crate) fn fit_dirichlet<F: Float>(
+    mean_logs: &[F],
+    alpha: &mut [F],
+    options: FitOptions<F>,
+) -> Result<usize, FitError> {
+    for iteration in 1..=options.max_iterations() {
+        let sum = alpha.iter().fold(F::zero(), |sum, &a| sum + a);
+        let psi_sum = digamma(sum);
+        // The gradient `gₖ` and the diagonal `qₖ` of the Hessian, less the
+        // constant `trigamma(sum)`
+        let gradient = |a: F, mean_log: F| (psi_sum - digamma(a) + mean_log, -trigamma(a));
+        let (mut num, mut den) = (F::zero(), trigamma(sum).recip());
+        for (&a, &mean_log) in alpha.iter().zip(mean_logs) {
+            let (g, q) = gradient(a, mean_log);
+            num = num + g / q;
+            den = den + q.recip();
+        }
+        let b = num / den;
+        let newton = |a: F, mean_log: F| {
+            let (g, q) = gradient(a, mean_log);
+            a - (g - b) / q
+        };
+        let use_newton = alpha
+            .iter()
+            .zip(mean_logs)
+            .all(|(&a, &mean_log)| newton(a, mean_log) > F::zero());
+
+        let mut converged = true;
+        for (a, &mean_log) in alpha.iter_mut().zip(mean_logs) {
+            let next = if use_newton {
+                newton(*a, mean_log)
+            } else {
+                inv_digamma(psi_sum + mean_log)
+            };
+            if !next.is_finite() {
+                return Err(FitError::NoConvergence);
+            }
+            converged &= options.converged(*a, next);
+            *a = next;
+        }
+        if converged {
+            return Ok(iteration);
+        }
+    }
+    Err(FitError::NoConvergence)
+}
+
+/// A starting point for [`fit_dirichlet`] by the method of moments: the
+/// parameters `α₀ mₖ` for the means `mₖ` of the components, with the
+/// precision `α₀` estimated from `mₖ (1 - mₖ) / vₖ - 1` for their variances
+/// `vₖ`, averaged over the components.
+pub(crate) fn dirichlet_moments<F: Float>(moments: &[Welford<F>], alpha: &mut [F]) {
+    let (mut sum, mut count) = (F::zero(), 0usize);
+    for w in moments {
+        let v = w.variance();
+        if v > F::zero() {
+            sum = sum + w.mean * (F::one() - w.mean) / v - F::one();
+            count += 1;
+        }
+    }
+    let mut precision = sum / F::from(count).unwrap();
+    if !(precision > F::zero() && precision.is_finite()) {
+        precision = F::one();
+    }
+    for (a, w) in alpha.iter_mut().zip(moments) {
+        *a = precision * w.mean;
+    }
+}
+
+/// Summary statistics of integer observations given as a frequency table
+/// of `(value, count)` pairs.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Counts<F> {
+    /// The number of observations.
+    pub(crate) total: F,
+    pub(crate) mean: F,
+    /// The mean of `ln(value)`, which is `-inf` if any value is zero.
+    pub(crate) mean_ln: F,
+    pub(crate) min: u64,
+    pub(crate) max: u64,
+}
+
+impl<F: Float> Counts<F> {
+    /// Fails with [`FitError::Empty`] if there are no observations.
+    pub(crate) fn new<I>(table: I) -> Result<Self, FitError>
+    where
+        I: IntoIterator<Item = (u64, u64)>,
+    {
+        let mut counts = Counts {
+            total: F::zero(),
+            mean: F::zero(),
+            mean_ln: F::zero(),
+            min: u64::MAX,
+            max: 0,
+        };
+        for (value, count) in table {
+            if count == 0 {
+                continue;
+            }
+            let (x, c) = (F::from(value).unwrap(), F::from(count).unwrap());
+            counts.total = counts.total + c;
+            let weight = c / counts.total;
+            counts.mean = counts.mean + (x - counts.mean) * weight;
+            counts.mean_ln = counts.mean_ln + (x.ln() - counts.mean_ln) * weight;
+            counts.min = counts.min.min(value);
+            counts.max = counts.max.max(value);
+        }
+        if counts.total == F::zero() {
+            return Err(FitError::Empty);
+        }
+        // The running mean is `nan` once a positive value follows a zero
+        if counts.min == 0 {
+            counts.mean_ln = F::neg_infinity();
+        }
+        Ok(counts)
+    }
+}
+
+/// Find the root of `f` between `a` and `b`, where `f(a)` and `f(b)` have
+/// opposite signs, with the Illinois variant of the method of false
+/// position. Returns the root and the number of iterations.
+pub(crate) fn bracketed_root<F, G>(
+    mut f: G,
+    mut a: F,
+    mut b: F,
+    options: FitOptions<F>,
+) -> Result<(F, usize), FitError>
+where
+    F: Float,
+    G: FnMut(F) -> F,
+{
+    let (mut fa, mut fb) = (f(a), f(b));
+    let half = F::from(0.5).unwrap();
+    // The end of the bracket that was replaced last: `Some(true)` for `a`
+    let mut last = None;
+    for iteration in 1..=options.max_iterations() {
+        let c = (a * fb - b * fa) / (fb - fa);
+        let fc = f(c);
+        if !fc.is_finite() {
+            return Err(FitError::NoConvergence);
+        }
+        if fc == F::zero() {
+            return Ok((c, iteration));
+        }
+        if (fc < F::zero()) == (fa < F::zero()) {
+            a = c;
+            fa = fc;
+            if last == Some(true) {
+                fb = fb * half;
+            }
+            last = Some(true);
+        } else {
+            b = c;
+            fb = fc;
+            if last == Some(false) {
+                fa = fa * half;
+            }
+            last = Some(false);
+        }
+        if options.converged(a, b) {
+            return Ok((c, iteration));
+        }
+    }
+    Err(FitError::NoConvergence)
+}
+
+/// Fit the location `μ` and scale `β` of a Gumbel distribution to the
+/// observations produced by `data`, which is called once per pass.
+///
+/// The estimate of `β` solves `β = mean(x) - Σ x e^(-x/β) / Σ e^(-x/β)`,
+/// which is found by Newton's method from the estimate `√6 σ / π` of the
+/// method of moments; then `μ = -β ln(mean(e^(-x/β)))`.
+pub(crate) fn fit_gumbel<F, G, I>(
+    data: G,
+    options: FitOptions<F>,
+) -> Result<Fitted<(F, F), F>, FitError>
+where
+    F: Float,
+    G: Fn() -> I,
+    I: Iterator<Item = F>,
+{
+    let mut moments = Welford::new();
+    let mut min = F::infinity();
+    for x in data() {
+        if !x.is_finite() {
+            return Err(FitError::OutOfSupport);
+        }
+        moments.push(x);
+        min = min.min(x);
+    }
+    if moments.count == 0 {
+        return Err(FitError::Empty);
+    }
+    if moments.m2 == F::zero() {
+        return Err(FitError::Constant);
+    }
+    let n = F::from(moments.count).unwrap();
+    // Work with `y = x - min`, so that `e^(-y/β)` cannot overflow
+    let mean_y = moments.mean - min;
+
+    let pi = F::from(core::f64::consts::PI).unwrap();
+    let mut scale = (F::from(6.0).unwrap() * moments.variance()).sqrt() / pi;
+    let mut iterations = 0;
+    loop {
+        if iterations == options.max_iterations() {
+            return Err(FitError::NoConvergence);
+        }
+        iterations += 1;
+        let (mut s0, mut s1, mut s2) = (F::zero(), F::zero(), F::zero());
+        for x in data() {
+            let y = x - min;
+            let w = (-y / scale).exp();
+            s0 = s0 + w;
+            s1 = s1 + w * y;
+            s2 = s2 + w * y * y;
+        }
+        let (r1, r2) = (s1 / s0, s2 / s0);
+        let h = scale - mean_y + r1;
+        let dh = F::one() + (r2 - r1 * r1) / (scale * scale);
+        let mut next = scale - h / dh;
+        if !(next > F::zero()) {
+            next = scale * F::from(0.5).unwrap();
+        }
+        if !next.is_finite() {
+            return Err(FitError::NoConvergence);
+        }
+        let converged = options.converged(scale, next);
+        scale = next;
+        if converged {
+            break;
+        }
+    }
+
+    let s0 = data().fold(F::zero(), |sum, x| sum + (-(x - min) / scale).exp());
+    let location = min - scale * (s0 / n).ln();
+    let log_likelihood = -n * (scale.ln() + (moments.mean - location) / scale + F::one());
+    Ok(Fitted {
+        distribution: (location, scale),
+        iterations,
+        log_likelihood,
+    })
+}
+
+/// The maxima of the complete blocks of `block_size` consecutive
+/// observations, or NaN for a block containing NaN.
+pub(crate) fn block_maxima<F: Float>(
+    data: &[F],
+    block_size: usize,
+) -> impl Iterator<Item = F> + '_ {
+    data.chunks_exact(block_size).map(|block| {
+        block.iter().fold(F::neg_infinity(), |max, &x| {
+            if x > max || x.is_nan() {
+                x
+            } else {
+                max
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_options() {
+        let default = FitOptions::<f64>::new();
+        assert_eq!(default.tolerance, 1e-12);
+        assert_eq!(FitOptions::new().with_tolerance(1e-6).tolerance, 1e-6);
+        for &tolerance in &[0.0, -1e-6, f64::NAN, f64::INFINITY] {
+            assert_eq!(FitOptions::new().with_tolerance(tolerance), default);
+        }
+        assert_eq!(FitOptions::<f32>::new().tolerance, 16.0 * f32::EPSILON);
+    }
+
+    #[test]
+    fn test_welford() {
+        // Shifted far from zero, where the two-pass formula cancels
+        let mut w = Welford::new();
+        for &x in &[4.0, 7.0, 13.0, 16.0] {
+            w.push(1e9 + x);
+        }
+        assert_eq!(w.count, 4);
+        assert_eq!(w.mean, 1e9 + 10.0);
+        assert_eq!(w.variance(), 22.5);
+    }
+
+    #[test]
+    fn test_counts() {
+        let counts = Counts::<f64>::new([(1, 3), (4, 1), (7, 0), (2, 4)]).unwrap();
+        assert_eq!(counts.total, 8.0);
+        assert_almost_eq!(counts.mean, 15.0 / 8.0, 1e-15);
+        assert_almost_eq!(counts.mean_ln, 0.75 * core::f64::consts::LN_2, 1e-15);
+        assert_eq!((counts.min, counts.max), (1, 4));
+        assert!(Counts::<f64>::new([(0, 1)]).unwrap().mean_ln.is_infinite());
+        assert_eq!(
+            Counts::<f64>::new([(0, 2), (3, 1)]).unwrap().mean_ln,
+            f64::NEG_INFINITY
+        );
+        assert!(matches!(Counts::<f64>::new([(3, 0)]), Err(FitError::Empty)));
+    }
+
+    #[test]
+    fn test_bracketed_root() {
+        let options = FitOptions::new();
+        let (x, iterations) = bracketed_root(|x: f64| x * x - 2.0, 0.0, 10.0, options).unwrap();
+        assert_almost_eq!(x, core::f64::consts::SQRT_2, 1e-12);
+        assert!(iterations < 30);
+        let (x, _) = bracketed_root(|x: f64| (-x).exp() - 0.5, 20.0, 0.0, options).unwrap();
+        assert_almost_eq!(x, core::f64::consts::LN_2, 1e-12);
+        assert_eq!(
+            bracketed_root(|x: f64| x - 1.0, 0.0, 10.0, options.with_max_iterations(0)),
+            Err(FitError::NoConvergence)
+        );
+    }
+}
@@ -8,8 +8,15 @@
 
 //! The Fréchet distribution `Fréchet(μ, σ, α)`.
 
-use crate::{Distribution, OpenClosed01};
+use crate::fit::{block_maxima, fit_gumbel};
+use crate::moments::{excess_kurtosis_from_raw, skewness_from_raw, variance_from_raw};
+use crate::special::ln_gamma;
+use crate::{
+    Cdf, ContinuousPdf, Distribution, Entropy, FitError, FitOptions, Fitted, Moments, OpenClosed01,
+    Quantile, Summary, Support,
+};
 use core::fmt;
+use core::ops::Bound;
 use num_traits::Float;
 use rand::Rng;
 
@@ -101,6 +108,78 @@ where
             shape,
         })
     }
+
+    /// Fit to the maxima of blocks of observations by maximum likelihood,
+    /// with the default [`FitOptions`].
+    ///
+    /// See [`Frechet::fit_block_maxima_with`].
+    pub fn fit_block_maxima(
+        data: &[F],
+        block_size: usize,
+    ) -> Result<Fitted<Frechet<F>, F>, FitError> {
+        Self::fit_block_maxima_with(data, block_size, FitOptions::new())
+    }
+
+    /// Fit to the maxima of blocks of observations by maximum likelihood,
+    /// with the location fixed at zero.
+    ///
+    /// `data` is split into consecutive blocks of `block_size` observations,
+    /// discarding an incomplete last block, and the distribution is fitted
+    /// to the maximum of each block. With a `block_size` of 1 it is fitted
+    /// to the observations themselves.
+    ///
+    /// The logarithm of a Fréchet variable with location zero, scale `s`
+    /// and shape `α` follows the Gumbel distribution with location `ln s` and
+    /// scale `1 / α`, so the estimates are those of
+    /// [`Gumbel::fit_block_maxima_with`](crate::Gumbel::fit_block_maxima_with)
+    /// for the logarithms of the maxima.
+    ///
+    /// Fails if `block_size` is zero, there is no complete block, the
+    /// maxima are all equal or any is not positive and finite, or if the
+    /// iteration does not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Distribution, Frechet, Pareto};
+    ///
+    /// // Fifty years of daily observations with a heavy tail
+    /// let pareto = Pareto::new(1.0, 3.0).unwrap();
+    /// let daily: Vec<f64> = pareto.sample_iter(rand::rng()).take(50 * 365).collect();
+    /// // Annual maxima of Pareto observations are close to Fréchet(0, 365^(1/3), 3)
+    /// let fitted = Frechet::fit_block_maxima(&daily, 365).unwrap();
+    /// println!("{:?}", fitted.distribution);
+    /// ```
+    pub fn fit_block_maxima_with(
+        data: &[F],
+        block_size: usize,
+        options: FitOptions<F>,
+    ) -> Result<Fitted<Frechet<F>, F>, FitError> {
+        if block_size == 0 {
+            return Err(FitError::InvalidSize);
+        }
+        let ln_maxima = || block_maxima(data, block_size).map(|m| m.ln());
+        let fitted = fit_gumbel(ln_maxima, options)?;
+        let (location, scale) = fitted.distribution;
+        // The Jacobian of the transformation to logarithms
+        let sum_ln = ln_maxima().fold(F::zero(), |sum, y| sum + y);
+        Ok(Fitted {
+            distribution: Frechet::new(F::zero(), location.exp(), scale.recip())
+                .map_err(|_| FitError::NoConvergence)?,
+            iterations: fitted.iterations,
+            log_likelihood: fitted.log_likelihood - sum_ln,
+        })
+    }
+
+    /// The raw moment `E[((X - location) / scale)^k] = Γ(1 - k / shape)`,
+    /// if `k < shape`.
+    fn raw_moment(&self, k: f64) -> Option<F> {
+        let k = F::from(k).unwrap();
+        if !(k < self.shape) {
+            return None;
+        }
+        Some(ln_gamma(F::one() - k / self.shape).exp())
+    }
 }
 
 impl<F> Distribution<F> for Frechet<F>
@@ -114,6 +193,118 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for Frechet<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        if !(x > self.location) {
+            return F::neg_infinity();
+        }
+        let z = (x - self.location) / self.scale;
+        (self.shape / self.scale).ln() - (F::one() + self.shape) * z.ln() - z.powf(-self.shape)
+    }
+}
+
+impl<F> Cdf<F> for Frechet<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        if !(x > self.location) {
+            return F::zero();
+        }
+        let z = (x - self.location) / self.scale;
+        (-z.powf(-self.shape)).exp()
+    }
+
+    fn sf(&self, x: F) -> F {
+        if !(x > self.location) {
+            return F::one();
+        }
+        let z = (x - self.location) / self.scale;
+        -(-z.powf(-self.shape)).exp_m1()
+    }
+}
+
+impl<F> Quantile<F> for Frechet<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        if !(p >= F::zero() && p <= F::one()) {
+            return F::nan();
+        }
+        self.location + self.scale * (-p.ln()).powf(-self.shape.recip())
+    }
+}
+
+impl<F> Moments<F> for Frechet<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some(self.location + self.scale * self.raw_moment(1.0)?)
+    }
+
+    fn variance(&self) -> Option<F> {
+        let (m1, m2) = (self.raw_moment(1.0)?, self.raw_moment(2.0)?);
+        Some(self.scale * self.scale * variance_from_raw(m1, m2))
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let (m1, m2) = (self.raw_moment(1.0)?, self.raw_moment(2.0)?);
+        Some(skewness_from_raw(m1, m2, self.raw_moment(3.0)?))
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let (m1, m2) = (self.raw_moment(1.0)?, self.raw_moment(2.0)?);
+        let (m3, m4) = (self.raw_moment(3.0)?, self.raw_moment(4.0)?);
+        Some(excess_kurtosis_from_raw(m1, m2, m3, m4))
+    }
+}
+
+impl<F> Entropy<F> for Frechet<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // The Euler–Mascheroni constant γ
+        let euler = F::from(0.57721566490153286061).unwrap();
+        F::one() + euler / self.shape + euler + (self.scale / self.shape).ln()
+    }
+}
+
+impl<F> Summary<F> for Frechet<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::Real {
+            lower: Bound::Excluded(self.location),
+            upper: Bound::Unbounded,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        let x = (self.shape / (F::one() + self.shape)).powf(self.shape.recip());
+        Some(self.location + self.scale * x)
+    }
+
+    fn median(&self) -> F {
+        let x = F::from(core::f64::consts::LN_2)
+            .unwrap()
+            .powf(-self.shape.recip());
+        self.location + self.scale * x
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,8 +389,90 @@ mod tests {
             .all(|(p_hat, p)| (p_hat - p).abs() < 0.003))
     }
 
+    #[test]
+    fn test_pdf() {
+        let d = Frechet::new(1.0, 2.0, 3.0).unwrap();
+        assert_almost_eq!(d.pdf(2.0), 0.0080511030696602841317, 1e-17);
+        assert_eq!(d.pdf(1.0), 0.0);
+        assert_eq!(d.pdf(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let d = Frechet::new(1.0, 2.0, 3.0).unwrap();
+        assert_almost_eq!(d.cdf(2.0), 0.00033546262790251184, 1e-18);
+        assert_almost_eq!(d.sf(2.0), 0.99966453737209748815, 1e-15);
+        assert_almost_eq!(d.sf(1e6), 8.0000240000480000480e-18, 1e-31);
+        assert_eq!(d.cdf(1.0), 0.0);
+        assert_eq!(d.sf(0.0), 1.0);
+    }
+
+    #[test]
+    fn test_quantile() {
+        let d = Frechet::new(1.0, 2.0, 3.0).unwrap();
+        assert_almost_eq!(d.quantile(0.3), 2.8799996567696199560, 1e-15);
+        assert_almost_eq!(d.quantile(0.999), 20.996665554937852826, 1e-12);
+        assert_eq!(d.quantile(0.0), 1.0);
+        assert_eq!(d.quantile(1.0), f64::INFINITY);
+        assert!(d.quantile(1.1).is_nan());
+    }
+
+    #[test]
+    fn test_moments() {
+        let d = Frechet::new(1.0, 2.0, 5.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 3.3284594274506067473, 1e-12);
+        assert_almost_eq!(d.variance().unwrap(), 0.53504568996766102326, 1e-12);
+        assert_almost_eq!(d.skewness().unwrap(), 3.5350716046213945905, 1e-12);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 45.091512125815759643, 1e-12);
+        let d = Frechet::new(1.0, 2.0, 2.5).unwrap();
+        assert!(d.variance().is_some());
+        assert_eq!(d.skewness(), None);
+        assert_eq!(d.excess_kurtosis(), None);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let d = Frechet::new(1.0, 2.0, 3.0).unwrap();
+        assert_almost_eq!(d.entropy(), 1.3641557784272127655, 1e-15);
+    }
+
+    #[test]
+    fn test_summary() {
+        let d = Frechet::new(1.0, 2.0, 3.0).unwrap();
+        assert!(!d.support().contains(1.0) && d.support().contains(1.0 + 1e-15));
+        assert_almost_eq!(d.mode().unwrap(), 2.8171205928321396589, 1e-15);
+        assert_almost_eq!(d.median(), 3.2598945526747801588, 1e-15);
+    }
+
     #[test]
     fn frechet_distributions_can_be_compared() {
         assert_eq!(Frechet::new(1.0, 2.0, 3.0), Frechet::new(1.0, 2.0, 3.0));
     }
+
+    #[test]
+    fn test_fit_block_maxima() {
+        let data = [1.2, 3.4, 2.2, 5.1, 2.9, 4.0, 0.5];
+        let fitted = Frechet::fit_block_maxima(&data, 2).unwrap();
+        let d = fitted.distribution;
+        assert_eq!(d.location, 0.0);
+        assert_almost_eq!(d.scale, 3.7905758515785202417, 1e-12);
+        assert_almost_eq!(d.shape, 7.2895436000080518032, 1e-11);
+        assert_almost_eq!(fitted.log_likelihood, -3.0422819981971294790, 1e-12);
+
+        let mut rng = crate::test::rng(236);
+        let dist = Frechet::new(0.0, 3.0, 1.5).unwrap();
+        let data: [f64; 5000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        let d = Frechet::fit_block_maxima(&data, 1).unwrap().distribution;
+        assert_almost_eq!(d.scale, 3.0, 0.1);
+        assert_almost_eq!(d.shape, 1.5, 0.05);
+
+        assert_eq!(
+            Frechet::fit_block_maxima(&data, 0),
+            Err(FitError::InvalidSize)
+        );
+        assert_eq!(
+            Frechet::fit_block_maxima(&[1.0, 0.0], 1),
+            Err(FitError::OutOfSupport)
+        );
+    }
 }
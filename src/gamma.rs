@@ -11,8 +11,19 @@
 
 use self::GammaRepr::*;
 
-use crate::{Distribution, Exp, Exp1, Open01, StandardNormal};
+use crate::divergence::{deviance, ln_gamma_kl_remainder};
+use crate::quantile::{invert_cdf, order_quantiles, solve_increasing};
+use crate::special::{
+    gamma_pq, ln_gamma, ln_minus_digamma, ln_poisson_raw, stirlerr, trigamma, LN_2PI,
+};
+use crate::{
+    Cdf, CharacteristicFunction, ContinuousPdf, Distribution, Entropy, Exp, Exp1, FitError,
+    FitOptions, Fitted, KlDivergence, Mgf, Moments, Open01, Quantile, StandardNormal, Summary,
+    Support,
+};
 use core::fmt;
+use core::ops::Bound;
+use num_complex::Complex;
 use num_traits::Float;
 use rand::Rng;
 #[cfg(feature = "serde")]
@@ -74,8 +85,10 @@ where
     repr: GammaRepr<F>,
 }
 
-/// Error type returned from [`Gamma::new`].
+/// Error type returned from [`Gamma::new`], [`Gamma::from_mean_variance`] and
+/// [`Gamma::from_quantiles`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// `shape <= 0` or `nan`.
     ShapeTooSmall,
@@ -83,6 +96,12 @@ pub enum Error {
     ScaleTooSmall,
     /// `1 / scale == 0`.
     ScaleTooLarge,
+    /// `mean <= 0` or `nan`.
+    MeanOutOfRange,
+    /// `variance <= 0`, infinite or `nan`.
+    VarianceOutOfRange,
+    /// The quantiles are invalid or not achievable.
+    QuantilesOutOfRange,
 }
 
 impl fmt::Display for Error {
@@ -91,6 +110,11 @@ impl fmt::Display for Error {
             Error::ShapeTooSmall => "shape is not positive in gamma distribution",
             Error::ScaleTooSmall => "scale is not positive in gamma distribution",
             Error::ScaleTooLarge => "scale is infinity in gamma distribution",
+            Error::MeanOutOfRange => "mean is not positive in gamma distribution",
+            Error::VarianceOutOfRange => {
+                "variance is not positive and finite in gamma distribution"
+            }
+            Error::QuantilesOutOfRange => "quantiles are not achievable in gamma distribution",
         })
     }
 }
@@ -182,6 +206,180 @@ where
         };
         Ok(Gamma { repr })
     }
+
+    /// Construct a gamma distribution with the given `mean` and `variance`.
+    ///
+    /// The parameters are `shape = mean² / variance` and
+    /// `scale = variance / mean`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Gamma, Moments};
+    ///
+    /// let gamma = Gamma::from_mean_variance(6.0, 12.0).unwrap();
+    /// assert_eq!(gamma, Gamma::new(3.0, 2.0).unwrap());
+    /// assert_eq!(gamma.variance(), Some(12.0));
+    /// ```
+    pub fn from_mean_variance(mean: F, variance: F) -> Result<Gamma<F>, Error> {
+        if !(mean > F::zero()) {
+            return Err(Error::MeanOutOfRange);
+        }
+        if !(variance > F::zero() && variance.is_finite()) {
+            return Err(Error::VarianceOutOfRange);
+        }
+        Gamma::new(mean / variance * mean, variance / mean)
+    }
+
+    /// Construct a gamma distribution from two quantiles.
+    ///
+    /// Each of `a` and `b` is a pair `(p, x)` requiring the quantile at
+    /// probability `p` to be `x`, for example `(0.5, median)`. The
+    /// probabilities must be distinct and in `(0, 1)`, and the values positive,
+    /// finite and in the same order as the probabilities.
+    ///
+    /// The ratio of two quantiles only depends on the shape, and decreases
+    /// towards one as the shape grows; the shape is found numerically from the
+    /// ratio of `x`, and then the scale from either quantile.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Gamma, Quantile};
+    ///
+    /// let gamma: Gamma<f64> = Gamma::from_quantiles((0.5, 10.0), (0.95, 30.0)).unwrap();
+    /// assert!((gamma.quantile(0.5) - 10.0).abs() < 1e-9);
+    /// assert!((gamma.quantile(0.95) - 30.0).abs() < 1e-9);
+    /// ```
+    pub fn from_quantiles(a: (F, F), b: (F, F)) -> Result<Gamma<F>, Error> {
+        let ((p1, x1), (p2, x2)) = order_quantiles(a, b).ok_or(Error::QuantilesOutOfRange)?;
+        if !(x1 > F::zero()) {
+            return Err(Error::QuantilesOutOfRange);
+        }
+        let one = F::one();
+        let quantile = |shape: F, p: F| match Gamma::new(shape, one) {
+            Ok(gamma) => gamma.quantile(p),
+            Err(_) => F::nan(),
+        };
+        // In terms of `t = ln(shape)`, so that the search spans all scales
+        let ln_ratio = (x2 / x1).ln();
+        let t = solve_increasing(
+            |t: F| {
+                let shape = t.exp();
+                ln_ratio - (quantile(shape, p2) / quantile(shape, p1)).ln()
+            },
+            F::zero(),
+        )
+        .ok_or(Error::QuantilesOutOfRange)?;
+        let shape = t.exp();
+        Gamma::new(shape, x1 / quantile(shape, p1)).map_err(|_| Error::QuantilesOutOfRange)
+    }
+
+    /// Returns the `(shape, scale)` parameters.
+    ///
+    /// The shape is recovered from the constants of the sampler, which may
+    /// round it by an ulp.
+    pub(crate) fn params(&self) -> (F, F) {
+        match &self.repr {
+            Large(g) => (g.d + F::from(1. / 3.).unwrap(), g.scale),
+            One(exp) => (F::one(), exp.mean().unwrap()),
+            Small(g) => (g.inv_shape.recip(), g.large_shape.scale),
+        }
+    }
+
+    /// Fit to observations by maximum likelihood, with the default
+    /// [`FitOptions`].
+    ///
+    /// See [`Gamma::fit_with`].
+    pub fn fit(data: &[F]) -> Result<Fitted<Gamma<F>, F>, FitError> {
+        Self::fit_with(data, FitOptions::new())
+    }
+
+    /// Fit to observations by maximum likelihood.
+    ///
+    /// With `s = ln(x̄) - mean(ln x)` for the sample mean `x̄`, the estimate
+    /// of the shape `k` solves `ln(k) - ψ(k) = s`, which is found by Newton's
+    /// method from the approximation of Minka (2002)[^1]; the estimate of the
+    /// scale is then `x̄ / k`.
+    ///
+    /// Fails if `data` is empty, its values are all equal or any is not
+    /// positive and finite, or if the iteration does not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Gamma, Moments};
+    ///
+    /// let fitted = Gamma::<f64>::fit(&[0.8, 1.5, 2.2, 3.1, 4.7]).unwrap();
+    /// // the fitted mean is the sample mean
+    /// assert!((fitted.distribution.mean().unwrap() - 2.46).abs() < 1e-12);
+    /// println!("{} iterations, log-likelihood {}", fitted.iterations, fitted.log_likelihood);
+    /// ```
+    ///
+    /// [^1]: Thomas P. Minka (2002). *Estimating a Gamma distribution*.
+    pub fn fit_with(data: &[F], options: FitOptions<F>) -> Result<Fitted<Gamma<F>, F>, FitError> {
+        let mut count = 0usize;
+        let mut mean = F::zero();
+        for &x in data {
+            if !(x > F::zero() && x.is_finite()) {
+                return Err(FitError::OutOfSupport);
+            }
+            count += 1;
+            mean = mean + (x - mean) / F::from(count).unwrap();
+        }
+        if count == 0 {
+            return Err(FitError::Empty);
+        }
+        let n = F::from(count).unwrap();
+        // s = -mean(ln(x / x̄)), in terms of relative differences near the
+        // mean so that it keeps its accuracy for clustered observations
+        let half = F::from(0.5).unwrap();
+        let ln_ratio = |x: F| {
+            let r = (x - mean) / mean;
+            if r.abs() < half {
+                r.ln_1p()
+            } else {
+                (x / mean).ln()
+            }
+        };
+        let s = -data.iter().fold(F::zero(), |sum, &x| sum + ln_ratio(x)) / n;
+        if !(s > F::zero()) {
+            return Err(FitError::Constant);
+        }
+
+        let c = |x: f64| F::from(x).unwrap();
+        let three = c(3.0);
+        let mut shape =
+            (three - s + ((s - three) * (s - three) + c(24.0) * s).sqrt()) / (c(12.0) * s);
+        let mut iterations = 0;
+        loop {
+            if iterations == options.max_iterations() {
+                return Err(FitError::NoConvergence);
+            }
+            iterations += 1;
+            let f = ln_minus_digamma(shape) - s;
+            let df = shape.recip() - trigamma(shape);
+            let mut next = shape - f / df;
+            if !(next > F::zero()) {
+                next = shape * c(0.5);
+            }
+            let converged = options.converged(shape, next);
+            shape = next;
+            if converged {
+                break;
+            }
+        }
+
+        let scale = mean / shape;
+        let mean_ln = mean.ln() - s;
+        let log_likelihood =
+            n * ((shape - F::one()) * mean_ln - shape - shape * scale.ln() - ln_gamma(shape));
+        Ok(Fitted {
+            distribution: Gamma::new(shape, scale).map_err(|_| FitError::NoConvergence)?,
+            iterations,
+            log_likelihood,
+        })
+    }
 }
 
 impl<F> GammaSmallShape<F>
@@ -270,12 +468,416 @@ where
     }
 }
 
+/// Logarithm of the density of `Gamma(shape, scale)` at `x`.
+pub(crate) fn gamma_ln_pdf<F: Float>(shape: F, scale: F, x: F) -> F {
+    if x < F::zero() {
+        return F::neg_infinity();
+    }
+    if x == F::zero() {
+        return if shape < F::one() {
+            F::infinity()
+        } else if shape == F::one() {
+            -scale.ln()
+        } else {
+            F::neg_infinity()
+        };
+    }
+    if shape < F::one() {
+        (shape - F::one()) * x.ln() - x / scale - ln_gamma(shape) - shape * scale.ln()
+    } else {
+        // x^(k-1) exp(-x/θ) / Γ(k) is a Poisson probability in `k - 1`, which
+        // is evaluated without cancellation for large shapes.
+        ln_poisson_raw(shape - F::one(), x / scale) - scale.ln()
+    }
+}
+
+/// Entropy of `Gamma(shape, scale)`.
+///
+/// This is `ln Γ(k) + (1 - k) ψ(k) + k + ln θ`, rearranged so that the large
+/// terms cancel analytically: `½ ln(2πk) + (k - 1) (ln k - ψ(k))`, plus the
+/// error of Stirling's approximation and `ln θ`.
+pub(crate) fn gamma_entropy<F: Float>(shape: F, scale: F) -> F {
+    let half = F::from(0.5).unwrap();
+    half * (F::from(LN_2PI).unwrap() + shape.ln())
+        + stirlerr(shape)
+        + (shape - F::one()) * ln_minus_digamma(shape)
+        + scale.ln()
+}
+
+impl<F> ContinuousPdf<F> for Gamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        let (shape, scale) = self.params();
+        gamma_ln_pdf(shape, scale, x)
+    }
+}
+
+impl<F> Cdf<F> for Gamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::zero();
+        }
+        let (shape, scale) = self.params();
+        gamma_pq(shape, x / scale).0
+    }
+
+    fn sf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::one();
+        }
+        let (shape, scale) = self.params();
+        gamma_pq(shape, x / scale).1
+    }
+}
+
+impl<F> Quantile<F> for Gamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        let (shape, scale) = self.params();
+        let mean = shape * scale;
+        invert_cdf(self, p, F::zero(), F::infinity(), mean, scale)
+    }
+}
+
+impl<F> Moments<F> for Gamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        let (shape, scale) = self.params();
+        Some(shape * scale)
+    }
+
+    fn variance(&self) -> Option<F> {
+        let (shape, scale) = self.params();
+        Some(shape * scale * scale)
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let shape = self.params().0;
+        Some(F::from(2.0).unwrap() / shape.sqrt())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let shape = self.params().0;
+        Some(F::from(6.0).unwrap() / shape)
+    }
+}
+
+impl<F> Entropy<F> for Gamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        let (shape, scale) = self.params();
+        gamma_entropy(shape, scale)
+    }
+}
+
+impl<F> KlDivergence<F> for Gamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn kl_divergence(&self, other: &Self) -> F {
+        // The terms depending on the scales combine with the deviance of the
+        // shapes into a deviance of the means
+        let ((k1, scale1), (k2, scale2)) = (self.params(), other.params());
+        deviance(k2, k1 * scale1 / scale2) + ln_gamma_kl_remainder(k1, k2)
+    }
+}
+
+impl<F> Mgf<F> for Gamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn cgf(&self, t: F) -> Option<F> {
+        let (shape, scale) = self.params();
+        let x = scale * t;
+        if !(x < F::one()) {
+            return None;
+        }
+        Some(-shape * (-x).ln_1p())
+    }
+}
+
+impl<F> CharacteristicFunction<F> for Gamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn characteristic(&self, t: F) -> Complex<F> {
+        let (shape, scale) = self.params();
+        // (1 - iθt)^-k in polar form
+        let x = scale * t;
+        let r = F::one().hypot(x).powf(-shape);
+        Complex::from_polar(r, shape * x.atan())
+    }
+}
+
+impl<F> Summary<F> for Gamma<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        let shape = self.params().0;
+        // The density is unbounded at zero for shapes less than one
+        let lower = if shape >= F::one() {
+            Bound::Included(F::zero())
+        } else {
+            Bound::Excluded(F::zero())
+        };
+        Support::Real {
+            lower,
+            upper: Bound::Unbounded,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        let (shape, scale) = self.params();
+        Some((shape - F::one()).max(F::zero()) * scale)
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_gamma_pdf() {
+        let gamma = Gamma::new(2.0, 5.0).unwrap();
+        assert_almost_eq!(gamma.pdf(3.0), 0.065857396331283171915, 1e-16);
+        assert_eq!(gamma.pdf(-1.0), 0.0);
+        assert_eq!(gamma.pdf(0.0), 0.0);
+        let gamma = Gamma::new(0.5, 2.0).unwrap();
+        assert_almost_eq!(gamma.pdf(0.5), 0.43939128946772236701, 1e-15);
+        assert_eq!(gamma.pdf(0.0), f64::INFINITY);
+        let gamma = Gamma::new(1000.0, 1.0).unwrap();
+        assert_almost_eq!(gamma.ln_pdf(1000.0), -4.3728995060262968242, 1e-13);
+        let gamma = Gamma::new(3.5, 1.0).unwrap();
+        assert_almost_eq!(gamma.ln_pdf(1e-3), -18.471361799802416803, 1e-12);
+        let exp = Gamma::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(exp.pdf(0.0), 0.5, 1e-16);
+    }
+
+    #[test]
+    fn test_gamma_cdf() {
+        let gamma = Gamma::new(2.0, 5.0).unwrap();
+        assert_almost_eq!(gamma.cdf(3.0), 0.12190138224955770048, 1e-15);
+        assert_almost_eq!(gamma.sf(3.0), 0.87809861775044229952, 1e-15);
+        assert_eq!(gamma.cdf(0.0), 0.0);
+        assert_eq!(gamma.sf(-1.0), 1.0);
+        let gamma = Gamma::new(0.5, 2.0).unwrap();
+        assert_almost_eq!(gamma.cdf(0.5), 0.52049987781304653768, 1e-15);
+        let gamma = Gamma::new(1000.0, 1.0).unwrap();
+        assert_almost_eq!(gamma.cdf(1000.0), 0.50420524418021550850, 1e-14);
+        // Large shape, where an asymptotic expansion is used
+        let gamma = Gamma::new(1e6, 1.0).unwrap();
+        assert_almost_eq!(gamma.sf(1e6 + 1000.0), 0.15865521363165970837, 1e-14);
+        assert_almost_eq!(gamma.cdf(1e6 - 3000.0), 0.0013381041673135996923, 1e-16);
+    }
+
+    #[test]
+    fn test_gamma_quantile() {
+        let gamma = Gamma::new(2.5, 2.0).unwrap();
+        assert_almost_eq!(gamma.quantile(0.3), 2.9999081327599062100, 1e-14);
+        assert_almost_eq!(gamma.quantile(1e-10), 3.2335571462496934607e-4, 1e-18);
+        assert_almost_eq!(gamma.quantile(0.999), 20.515005652432876384, 1e-13);
+        assert_eq!(gamma.quantile(0.0), 0.0);
+        assert_eq!(gamma.quantile(1.0), f64::INFINITY);
+        let gamma = Gamma::new(0.1, 1.0).unwrap();
+        assert_almost_eq!(gamma.quantile(0.01) / 6.0730483624078993461e-21, 1.0, 1e-13);
+
+        let gamma = Gamma::new(2.5, 2.0).unwrap();
+        let mut rng = crate::test::rng(314);
+        for _ in 0..20 {
+            let x = gamma.sample_by_inversion(&mut rng);
+            assert_almost_eq!(gamma.quantile(gamma.cdf(x)), x, 1e-12 * x);
+        }
+    }
+
+    #[test]
+    fn test_gamma_moments() {
+        let gamma = Gamma::new(2.5, 2.0).unwrap();
+        assert_eq!(gamma.mean(), Some(5.0));
+        assert_eq!(gamma.variance(), Some(10.0));
+        assert_almost_eq!(gamma.skewness().unwrap(), 1.2649110640673517328, 1e-15);
+        assert_eq!(gamma.excess_kurtosis(), Some(2.4));
+    }
+
+    #[test]
+    fn test_gamma_entropy() {
+        let gamma = Gamma::new(2.5, 1.5).unwrap();
+        assert_almost_eq!(gamma.entropy(), 2.1354130176132187608, 1e-15);
+        let gamma = Gamma::new(1.0, 1.0).unwrap();
+        assert_almost_eq!(gamma.entropy(), 1.0, 1e-15);
+        let gamma = Gamma::new(1e-3, 1.0).unwrap();
+        assert_almost_eq!(gamma.entropy(), -992.66681747449463649, 1e-11);
+        let gamma = Gamma::new(1e10, 1.0).unwrap();
+        assert_almost_eq!(gamma.entropy(), 12.931863998141567829, 1e-14);
+    }
+
+    #[test]
+    fn test_gamma_kl_divergence() {
+        let p = Gamma::new(2.0, 3.0).unwrap();
+        let q = Gamma::new(4.0, 0.5).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 3.7791529221189007188, 1e-14);
+        assert_eq!(p.kl_divergence(&p), 0.0);
+        let p = Gamma::new(0.1, 1.0).unwrap();
+        let q = Gamma::new(0.2, 2.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 0.40235610084967531203, 1e-15);
+        let p = Gamma::new(1e6, 1.0).unwrap();
+        let q = Gamma::new(1e6 + 1.0, 1.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 5.0000008333333333333e-7, 1e-20);
+    }
+
+    #[test]
+    fn test_gamma_mgf() {
+        let d = Gamma::new(2.5, 2.0).unwrap();
+        assert_almost_eq!(d.cgf(0.2).unwrap(), 1.2770640594149767080, 1e-15);
+        assert_eq!(d.cgf(0.5), None);
+        let phi = d.characteristic(0.7);
+        assert_almost_eq!(phi.re, -0.18576197413833930121, 1e-15);
+        assert_almost_eq!(phi.im, 0.17841458510642147042, 1e-15);
+    }
+
+    #[test]
+    fn test_gamma_summary() {
+        let d = Gamma::new(3.0, 2.0).unwrap();
+        assert_eq!(d.mode(), Some(4.0));
+        assert_almost_eq!(d.median(), 5.3481206274471206358, 1e-14);
+        assert!(d.support().contains(0.0));
+        // The density is unbounded at zero
+        let d = Gamma::new(0.5, 1.0).unwrap();
+        assert_eq!(d.mode(), Some(0.0));
+        assert!(!d.support().contains(0.0));
+    }
+
     #[test]
     fn gamma_distributions_can_be_compared() {
         assert_eq!(Gamma::new(1.0, 2.0), Gamma::new(1.0, 2.0));
     }
+
+    #[test]
+    fn test_gamma_fit() {
+        let fitted = Gamma::fit(&[0.8, 1.5, 2.2, 3.1, 4.7]).unwrap();
+        let (shape, scale) = fitted.distribution.params();
+        assert_almost_eq!(shape, 3.0941526228457005870, 1e-12);
+        assert_almost_eq!(scale, 0.79504804702798771562, 1e-12);
+        assert_almost_eq!(fitted.log_likelihood, -8.1881772546213869123, 1e-12);
+        assert!(fitted.iterations <= 10);
+
+        let mut rng = crate::test::rng(223);
+        for &(k, theta) in &[(0.2, 2.0), (2.5, 1.5), (80.0, 0.01)] {
+            let dist = Gamma::new(k, theta).unwrap();
+            let data: [f64; 5000] = core::array::from_fn(|_| dist.sample(&mut rng));
+            let (shape, scale) = Gamma::fit(&data).unwrap().distribution.params();
+            assert_almost_eq!(shape / k, 1.0, 0.05);
+            assert_almost_eq!(scale / theta, 1.0, 0.05);
+        }
+
+        // An observation that is negligible next to the mean
+        assert!(Gamma::fit(&[1e-300, 1.0, 2.0]).is_ok());
+
+        assert_eq!(Gamma::<f64>::fit(&[]), Err(FitError::Empty));
+        assert_eq!(Gamma::fit(&[1.5, 1.5]), Err(FitError::Constant));
+        assert_eq!(Gamma::fit(&[1.5, 0.0]), Err(FitError::OutOfSupport));
+        assert_eq!(
+            Gamma::fit(&[1.5, f64::INFINITY]),
+            Err(FitError::OutOfSupport)
+        );
+        let options = FitOptions::new().with_max_iterations(1);
+        assert_eq!(
+            Gamma::fit_with(&[0.8, 1.5, 2.2, 3.1, 4.7], options),
+            Err(FitError::NoConvergence)
+        );
+    }
+
+    #[test]
+    fn test_from_mean_variance() {
+        let gamma = Gamma::from_mean_variance(6.0, 12.0).unwrap();
+        let (shape, scale) = gamma.params();
+        assert_almost_eq!(shape, 3.0, 1e-15);
+        assert_almost_eq!(scale, 2.0, 1e-15);
+        assert_almost_eq!(gamma.mean().unwrap(), 6.0, 1e-14);
+        assert_almost_eq!(gamma.variance().unwrap(), 12.0, 1e-13);
+
+        assert_eq!(
+            Gamma::from_mean_variance(0.0, 1.0),
+            Err(Error::MeanOutOfRange)
+        );
+        assert_eq!(
+            Gamma::from_mean_variance(f64::NAN, 1.0),
+            Err(Error::MeanOutOfRange)
+        );
+        assert_eq!(
+            Gamma::from_mean_variance(1.0, 0.0),
+            Err(Error::VarianceOutOfRange)
+        );
+        assert_eq!(
+            Gamma::from_mean_variance(1.0, f64::INFINITY),
+            Err(Error::VarianceOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_from_quantiles() {
+        for &(shape, scale) in &[(1.0, 1.0), (0.1, 5.0), (2.5, 0.5), (1e4, 1e-3)] {
+            let expected = Gamma::new(shape, scale).unwrap();
+            let (x1, x2) = (expected.quantile(0.25), expected.quantile(0.99));
+            let gamma = Gamma::from_quantiles((0.99, x2), (0.25, x1)).unwrap();
+            let (s, t) = gamma.params();
+            assert_almost_eq!(s, shape, 1e-9 * shape);
+            assert_almost_eq!(t, scale, 1e-9 * scale);
+        }
+
+        assert_eq!(
+            Gamma::from_quantiles((0.1, 0.0), (0.9, 1.0)),
+            Err(Error::QuantilesOutOfRange)
+        );
+        assert_eq!(
+            Gamma::from_quantiles((0.1, 2.0), (0.9, 1.0)),
+            Err(Error::QuantilesOutOfRange)
+        );
+        assert_eq!(
+            Gamma::from_quantiles((0.1, 1.0), (1.0, 2.0)),
+            Err(Error::QuantilesOutOfRange)
+        );
+    }
 }
@@ -0,0 +1,68 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Moment-generating, cumulant-generating and characteristic functions.
+
+use num_complex::Complex;
+use num_traits::Float;
+
+/// The moment-generating function `M(t) = E[exp(tX)]` and the
+/// cumulant-generating function `K(t) = ln M(t)` of a univariate
+/// distribution.
+///
+/// Both return `None` if `t` is outside the domain of convergence of the
+/// expectation (or NaN). The domain always contains `t = 0`, where
+/// `M(0) = 1` and `K(0) = 0`; distributions without a finite
+/// moment-generating function near zero, such as the
+/// [`Cauchy`](crate::Cauchy) distribution, do not implement this trait.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Exp, Mgf};
+///
+/// let exp = Exp::new(2.0f64).unwrap();
+/// assert_eq!(exp.mgf(1.0), Some(2.0));
+/// assert_eq!(exp.mgf(2.0), None);
+/// assert_eq!(exp.cgf(0.0), Some(0.0));
+/// ```
+pub trait Mgf<F: Float> {
+    /// The moment-generating function `E[exp(tX)]`.
+    fn mgf(&self, t: F) -> Option<F> {
+        self.cgf(t).map(F::exp)
+    }
+
+    /// The cumulant-generating function `ln E[exp(tX)]`.
+    ///
+    /// This is evaluated directly rather than as the logarithm of
+    /// [`Mgf::mgf`], so it does not overflow where the moment-generating
+    /// function does and keeps its relative precision close to `t = 0`.
+    fn cgf(&self, t: F) -> Option<F>;
+}
+
+/// The characteristic function `φ(t) = E[exp(itX)]` of a univariate
+/// distribution.
+///
+/// Unlike the moment-generating function, this exists for all real `t` and
+/// every distribution.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Cauchy, CharacteristicFunction};
+///
+/// // φ(t) = exp(i x₀ t - γ |t|)
+/// let cauchy = Cauchy::new(0.0f64, 2.0).unwrap();
+/// let phi = cauchy.characteristic(1.0);
+/// assert_eq!(phi.re, (-2.0f64).exp());
+/// assert_eq!(phi.im, 0.0);
+/// ```
+pub trait CharacteristicFunction<F: Float> {
+    /// The characteristic function `E[exp(itX)]`.
+    fn characteristic(&self, t: F) -> Complex<F>;
+}
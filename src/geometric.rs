@@ -1,7 +1,14 @@
 //! The geometric distribution `Geometric(p)`.
 
-use crate::Distribution;
+use crate::divergence::bernoulli_kl;
+use crate::fit::Counts;
+use crate::quantile::discrete_quantile;
+use crate::{
+    Cdf, CharacteristicFunction, DiscretePmf, Distribution, Entropy, FitError, KlDivergence, Mgf,
+    Moments, Quantile, Summary, Support,
+};
 use core::fmt;
+use num_complex::Complex;
 #[allow(unused_imports)]
 use num_traits::Float;
 use rand::Rng;
@@ -89,6 +96,38 @@ impl Geometric {
             Ok(Geometric { p, pi, k })
         }
     }
+
+    /// Fit to observed numbers of failures before the first success by
+    /// maximum likelihood.
+    ///
+    /// The estimate of `p` is `1 / (1 + m)` for the sample mean `m`.
+    ///
+    /// Fails if `data` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Geometric;
+    ///
+    /// let geo = Geometric::fit(&[0, 4, 2, 1, 3]).unwrap();
+    /// assert_eq!(geo, Geometric::new(1.0 / 3.0).unwrap());
+    /// ```
+    pub fn fit(data: &[u64]) -> Result<Self, FitError> {
+        Self::fit_counts(Counts::new(data.iter().map(|&k| (k, 1)))?)
+    }
+
+    /// Fit to observed numbers of failures before the first success, given
+    /// as a frequency table of `(value, frequency)` pairs, by maximum
+    /// likelihood.
+    ///
+    /// See [`Geometric::fit`].
+    pub fn fit_frequencies(table: &[(u64, u64)]) -> Result<Self, FitError> {
+        Self::fit_counts(Counts::new(table.iter().copied())?)
+    }
+
+    fn fit_counts(counts: Counts<f64>) -> Result<Self, FitError> {
+        Geometric::new(1.0 / (1.0 + counts.mean)).map_err(|_| FitError::OutOfSupport)
+    }
 }
 
 impl Distribution<u64> for Geometric {
@@ -151,6 +190,133 @@ impl Distribution<u64> for Geometric {
     }
 }
 
+impl DiscretePmf<f64> for Geometric {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        if k == 0 {
+            return self.p.ln();
+        }
+        k as f64 * (-self.p).ln_1p() + self.p.ln()
+    }
+}
+
+impl Cdf<f64> for Geometric {
+    fn cdf(&self, x: f64) -> f64 {
+        if !(x >= 0.0) {
+            return 0.0;
+        }
+        // P(X > k) = (1 - p)^(k + 1)
+        -((x.floor() + 1.0) * (-self.p).ln_1p()).exp_m1()
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if !(x >= 0.0) {
+            return 1.0;
+        }
+        ((x.floor() + 1.0) * (-self.p).ln_1p()).exp()
+    }
+}
+
+impl Quantile<f64> for Geometric {
+    fn quantile(&self, p: f64) -> f64 {
+        // The closed form `ceil(ln(1 - p) / ln(1 - self.p) - 1)` may be off
+        // by one due to rounding, so use it as a starting point only
+        let guess = (-p).ln_1p() / (-self.p).ln_1p() - 1.0;
+        discrete_quantile(self, p, 0.0, f64::INFINITY, guess.ceil())
+    }
+}
+
+impl Moments<f64> for Geometric {
+    fn mean(&self) -> Option<f64> {
+        if self.p == 0.0 {
+            return None;
+        }
+        Some((1.0 - self.p) / self.p)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        if self.p == 0.0 {
+            return None;
+        }
+        Some((1.0 - self.p) / (self.p * self.p))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        if self.p == 0.0 || self.p == 1.0 {
+            return None;
+        }
+        Some((2.0 - self.p) / (1.0 - self.p).sqrt())
+    }
+
+    fn excess_kurtosis(&self) -> Option<f64> {
+        if self.p == 0.0 || self.p == 1.0 {
+            return None;
+        }
+        Some(6.0 + self.p * self.p / (1.0 - self.p))
+    }
+}
+
+impl Entropy<f64> for Geometric {
+    fn entropy(&self) -> f64 {
+        let p = self.p;
+        if p == 1.0 {
+            return 0.0;
+        }
+        // (-(1 - p) ln(1 - p) - p ln p) / p
+        -(1.0 - p) / p * (-p).ln_1p() - p.ln()
+    }
+}
+
+impl KlDivergence<f64> for Geometric {
+    fn kl_divergence(&self, other: &Self) -> f64 {
+        // Each sample is a sequence of Bernoulli trials of expected length 1 / p
+        bernoulli_kl(self.p, other.p) / self.p
+    }
+}
+
+impl Mgf<f64> for Geometric {
+    fn cgf(&self, t: f64) -> Option<f64> {
+        geometric_cgf(self.p, t)
+    }
+}
+
+impl CharacteristicFunction<f64> for Geometric {
+    fn characteristic(&self, t: f64) -> Complex<f64> {
+        geometric_characteristic(self.p, t)
+    }
+}
+
+impl Summary<f64> for Geometric {
+    fn support(&self) -> Support<f64> {
+        let upper = if self.p == 1.0 { Some(0) } else { None };
+        Support::Integer { lower: 0, upper }
+    }
+
+    fn mode(&self) -> Option<f64> {
+        Some(0.0)
+    }
+
+    fn median(&self) -> f64 {
+        self.quantile(0.5)
+    }
+}
+
+/// `ln(p / (1 - (1 - p) e^t))`, written as `-ln(1 - (1 - p)(e^t - 1) / p)`
+/// so that it keeps its precision close to `t = 0`.
+pub(crate) fn geometric_cgf(p: f64, t: f64) -> Option<f64> {
+    let x = (1.0 - p) / p * t.exp_m1();
+    if !(x < 1.0) {
+        return None;
+    }
+    Some(-(-x).ln_1p())
+}
+
+/// `p / (1 - (1 - p) e^it)`, with `1 - cos t = 2 sin²(t/2)`.
+pub(crate) fn geometric_characteristic(p: f64, t: f64) -> Complex<f64> {
+    let q = 1.0 - p;
+    let s = (0.5 * t).sin();
+    Complex::new(p, 0.0) / Complex::new(p + 2.0 * q * s * s, -q * t.sin())
+}
+
 /// The standard geometric distribution `Geometric(0.5)`.
 ///
 /// This is equivalent to `Geometric::new(0.5)`, but faster.
@@ -193,6 +359,89 @@ impl Distribution<u64> for StandardGeometric {
     }
 }
 
+impl DiscretePmf<f64> for StandardGeometric {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        -(k as f64 + 1.0) * core::f64::consts::LN_2
+    }
+}
+
+impl Cdf<f64> for StandardGeometric {
+    fn cdf(&self, x: f64) -> f64 {
+        if !(x >= 0.0) {
+            return 0.0;
+        }
+        -(-(x.floor() + 1.0) * core::f64::consts::LN_2).exp_m1()
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if !(x >= 0.0) {
+            return 1.0;
+        }
+        (-(x.floor() + 1.0)).exp2()
+    }
+}
+
+impl Quantile<f64> for StandardGeometric {
+    fn quantile(&self, p: f64) -> f64 {
+        let guess = -(-p).ln_1p() / core::f64::consts::LN_2 - 1.0;
+        discrete_quantile(self, p, 0.0, f64::INFINITY, guess.ceil())
+    }
+}
+
+impl Moments<f64> for StandardGeometric {
+    fn mean(&self) -> Option<f64> {
+        Some(1.0)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some(2.0)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        Some(1.5 * core::f64::consts::SQRT_2)
+    }
+
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(6.5)
+    }
+}
+
+impl Entropy<f64> for StandardGeometric {
+    fn entropy(&self) -> f64 {
+        2.0 * core::f64::consts::LN_2
+    }
+}
+
+impl Mgf<f64> for StandardGeometric {
+    fn cgf(&self, t: f64) -> Option<f64> {
+        geometric_cgf(0.5, t)
+    }
+}
+
+impl CharacteristicFunction<f64> for StandardGeometric {
+    fn characteristic(&self, t: f64) -> Complex<f64> {
+        geometric_characteristic(0.5, t)
+    }
+}
+
+impl Summary<f64> for StandardGeometric {
+    fn support(&self) -> Support<f64> {
+        Support::Integer {
+            lower: 0,
+            upper: None,
+        }
+    }
+
+    fn mode(&self) -> Option<f64> {
+        Some(0.0)
+    }
+
+    fn median(&self) -> f64 {
+        // P(X = 0) = 1/2
+        0.0
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -260,8 +509,155 @@ mod test {
         assert!((variance - expected_variance).abs() < expected_variance / 10.0);
     }
 
+    #[test]
+    fn test_geometric_pmf() {
+        let geo = Geometric::new(0.25).unwrap();
+        assert_eq!(geo.pmf(0), 0.25);
+        assert_almost_eq!(geo.pmf(3), 0.10546875, 1e-16);
+        assert_almost_eq!(geo.ln_pmf(10_000), -2878.2070188789291650, 1e-10);
+        let geo = Geometric::new(1.0).unwrap();
+        assert_eq!(geo.pmf(0), 1.0);
+        assert_eq!(geo.pmf(1), 0.0);
+        assert_eq!(Geometric::new(0.0).unwrap().pmf(0), 0.0);
+
+        assert_eq!(StandardGeometric.pmf(0), 0.5);
+        assert_eq!(
+            StandardGeometric.pmf(3),
+            Geometric::new(0.5).unwrap().pmf(3)
+        );
+        assert_almost_eq!(
+            StandardGeometric.ln_pmf(1999),
+            -2000.0 * core::f64::consts::LN_2,
+            1e-10
+        );
+    }
+
+    #[test]
+    fn test_geometric_cdf() {
+        let geo = Geometric::new(0.25).unwrap();
+        assert_almost_eq!(geo.cdf(0.0), 0.25, 1e-16);
+        assert_almost_eq!(geo.cdf(3.5), 0.68359375, 1e-16);
+        assert_almost_eq!(geo.sf(3.0), 0.31640625, 1e-16);
+        assert_eq!(geo.cdf(-1.0), 0.0);
+        assert_eq!(geo.sf(-0.5), 1.0);
+        let geo = Geometric::new(1e-12).unwrap();
+        assert_almost_eq!(geo.cdf(0.0), 1e-12, 1e-27);
+        assert_eq!(Geometric::new(1.0).unwrap().sf(0.0), 0.0);
+
+        assert_eq!(StandardGeometric.cdf(1.0), 0.75);
+        assert_eq!(StandardGeometric.sf(1999.0), 0.5f64.powi(2000));
+    }
+
+    #[test]
+    fn test_geometric_quantile() {
+        let geometric = Geometric::new(0.2).unwrap();
+        assert_eq!(geometric.quantile(0.1), 0.0);
+        assert_eq!(geometric.quantile(0.3), 1.0);
+        assert_eq!(geometric.quantile(0.5), 3.0);
+        assert_eq!(geometric.quantile(0.999), 30.0);
+        assert_eq!(geometric.quantile(1.0), f64::INFINITY);
+        assert_eq!(Geometric::new(1e-12).unwrap().quantile(0.5), 693147180559.0);
+
+        assert_eq!(StandardGeometric.quantile(0.4), 0.0);
+        assert_eq!(StandardGeometric.quantile(0.7), 1.0);
+        assert_eq!(StandardGeometric.quantile(0.8), 2.0);
+        assert_eq!(StandardGeometric.quantile(0.99), 6.0);
+    }
+
+    #[test]
+    fn test_geometric_moments() {
+        let d = Geometric::new(0.2).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 4.0, 1e-13);
+        assert_almost_eq!(d.variance().unwrap(), 20.0, 1e-13);
+        assert_almost_eq!(d.skewness().unwrap(), 2.0124611797498107283, 1e-13);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 6.05, 1e-13);
+        assert_eq!(StandardGeometric.mean(), Some(1.0));
+        assert_eq!(StandardGeometric.variance(), Some(2.0));
+        assert_almost_eq!(
+            StandardGeometric.skewness().unwrap(),
+            2.1213203435596425732,
+            1e-15
+        );
+        assert_eq!(StandardGeometric.excess_kurtosis(), Some(6.5));
+    }
+
+    #[test]
+    fn test_geometric_entropy() {
+        let d = Geometric::new(0.3).unwrap();
+        assert_almost_eq!(d.entropy(), 2.0362143401829782101, 1e-15);
+        let d = Geometric::new(1e-12).unwrap();
+        assert_almost_eq!(d.entropy(), 28.631021115928048208, 1e-13);
+        assert_eq!(Geometric::new(1.0).unwrap().entropy(), 0.0);
+        assert_almost_eq!(StandardGeometric.entropy(), 1.3862943611198906188, 1e-15);
+    }
+
+    #[test]
+    fn test_geometric_kl_divergence() {
+        let p = Geometric::new(0.3).unwrap();
+        let q = Geometric::new(0.5).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 0.27427626168350619615, 1e-15);
+        assert_eq!(p.kl_divergence(&p), 0.0);
+        let p = Geometric::new(1.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), core::f64::consts::LN_2, 1e-15);
+        assert_eq!(q.kl_divergence(&p), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_geometric_mgf() {
+        let d = Geometric::new(0.3).unwrap();
+        assert_almost_eq!(d.cgf(0.2).unwrap(), 0.72692412422103732875, 1e-15);
+        assert_eq!(d.cgf(0.0), Some(0.0));
+        assert_eq!(d.cgf(-(0.7f64.ln())), None);
+        let phi = d.characteristic(1.0);
+        assert_almost_eq!(phi.re, 0.25428356368740396980, 1e-15);
+        assert_almost_eq!(phi.im, 0.24088672597930105715, 1e-15);
+
+        assert_eq!(Geometric::new(1.0).unwrap().cgf(100.0), Some(0.0));
+        assert_almost_eq!(
+            StandardGeometric.cgf(0.5).unwrap(),
+            1.0461752700778734960,
+            1e-15
+        );
+        assert_eq!(StandardGeometric.cgf(core::f64::consts::LN_2), None);
+        let phi = StandardGeometric.characteristic(1.0);
+        assert_almost_eq!(phi.re, 0.51419699760946793950, 1e-15);
+        assert_almost_eq!(phi.im, 0.29641881034897128828, 1e-15);
+    }
+
+    #[test]
+    fn test_geometric_summary() {
+        let d = Geometric::new(0.3).unwrap();
+        assert_eq!(
+            d.support(),
+            Support::Integer {
+                lower: 0,
+                upper: None
+            }
+        );
+        assert_eq!((d.mode(), d.median()), (Some(0.0), 1.0));
+        assert_eq!(Geometric::new(1.0).unwrap().support().upper(), 0.0);
+        assert_eq!(StandardGeometric.median(), StandardGeometric.quantile(0.5));
+    }
+
     #[test]
     fn geometric_distributions_can_be_compared() {
         assert_eq!(Geometric::new(1.0), Geometric::new(1.0));
     }
+
+    #[test]
+    fn test_geometric_fit() {
+        let d = Geometric::fit(&[0, 4, 2, 1, 3]).unwrap();
+        assert_eq!(d.p, 1.0 / 3.0);
+        let d = Geometric::fit_frequencies(&[(0, 3), (1, 1)]).unwrap();
+        assert_eq!(d.p, 0.8);
+        assert_eq!(Geometric::fit(&[0, 0]).unwrap().p, 1.0);
+
+        let mut rng = crate::test::rng(228);
+        let dist = Geometric::new(0.2).unwrap();
+        let data: [u64; 5000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        let d = Geometric::fit(&data).unwrap();
+        assert_almost_eq!(d.p, 0.2, 0.01);
+
+        assert_eq!(Geometric::fit(&[]), Err(FitError::Empty));
+    }
 }
@@ -8,7 +8,11 @@
 
 //! The Gumbel distribution `Gumbel(μ, β)`.
 
-use crate::{Distribution, OpenClosed01};
+use crate::fit::{block_maxima, fit_gumbel};
+use crate::{
+    Cdf, ContinuousPdf, Distribution, Entropy, FitError, FitOptions, Fitted, Moments, OpenClosed01,
+    Quantile, Summary, Support,
+};
 use core::fmt;
 use num_traits::Float;
 use rand::Rng;
@@ -88,6 +92,89 @@ where
         }
         Ok(Gumbel { location, scale })
     }
+
+    /// Construct a Gumbel distribution with the given `mean` and `variance`.
+    ///
+    /// The scale is `β = √(6 variance) / π` and the location is
+    /// `mean - γ β`, with the Euler–Mascheroni constant `γ`. Fails as
+    /// [`Gumbel::new`] if the mean is not finite or the variance is not
+    /// positive and finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Gumbel, Moments};
+    ///
+    /// let gumbel: Gumbel<f64> = Gumbel::from_mean_variance(1.0, 2.0).unwrap();
+    /// assert!((gumbel.mean().unwrap() - 1.0).abs() < 1e-15);
+    /// assert!((gumbel.variance().unwrap() - 2.0).abs() < 1e-15);
+    /// ```
+    pub fn from_mean_variance(mean: F, variance: F) -> Result<Gumbel<F>, Error> {
+        let pi = F::from(core::f64::consts::PI).unwrap();
+        let euler = F::from(0.57721566490153286061).unwrap();
+        let scale = (F::from(6.0).unwrap() * variance).sqrt() / pi;
+        if !(scale > F::zero()) {
+            return Err(Error::ScaleNotPositive);
+        }
+        Gumbel::new(mean - euler * scale, scale)
+    }
+
+    /// Fit to the maxima of blocks of observations by maximum likelihood,
+    /// with the default [`FitOptions`].
+    ///
+    /// See [`Gumbel::fit_block_maxima_with`].
+    pub fn fit_block_maxima(
+        data: &[F],
+        block_size: usize,
+    ) -> Result<Fitted<Gumbel<F>, F>, FitError> {
+        Self::fit_block_maxima_with(data, block_size, FitOptions::new())
+    }
+
+    /// Fit to the maxima of blocks of observations by maximum likelihood.
+    ///
+    /// `data` is split into consecutive blocks of `block_size` observations,
+    /// discarding an incomplete last block, and the distribution is fitted
+    /// to the maximum of each block. With a `block_size` of 1 it is fitted
+    /// to the observations themselves.
+    ///
+    /// The estimate of the scale `β` solves
+    /// `β = mean(m) - Σ m e^(-m/β) / Σ e^(-m/β)` for the maxima `m`, which
+    /// is found by Newton's method from the estimate of the method of
+    /// moments; the estimate of the location is then
+    /// `-β ln(mean(e^(-m/β)))`.
+    ///
+    /// Fails if `block_size` is zero, there is no complete block, the
+    /// maxima are all equal or any observation is not finite, or if the
+    /// iteration does not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Distribution, Exp, Gumbel};
+    ///
+    /// // Fifty years of daily observations
+    /// let exp = Exp::new(1.0).unwrap();
+    /// let daily: Vec<f64> = exp.sample_iter(rand::rng()).take(50 * 365).collect();
+    /// // Annual maxima of exponential observations are close to Gumbel(ln 365, 1)
+    /// let fitted = Gumbel::fit_block_maxima(&daily, 365).unwrap();
+    /// println!("{:?}", fitted.distribution);
+    /// ```
+    pub fn fit_block_maxima_with(
+        data: &[F],
+        block_size: usize,
+        options: FitOptions<F>,
+    ) -> Result<Fitted<Gumbel<F>, F>, FitError> {
+        if block_size == 0 {
+            return Err(FitError::InvalidSize);
+        }
+        let fitted = fit_gumbel(|| block_maxima(data, block_size), options)?;
+        let (location, scale) = fitted.distribution;
+        Ok(Fitted {
+            distribution: Gumbel::new(location, scale).map_err(|_| FitError::NoConvergence)?,
+            iterations: fitted.iterations,
+            log_likelihood: fitted.log_likelihood,
+        })
+    }
 }
 
 impl<F> Distribution<F> for Gumbel<F>
@@ -101,6 +188,103 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for Gumbel<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        let z = (x - self.location) / self.scale;
+        -self.scale.ln() - (z + (-z).exp())
+    }
+}
+
+impl<F> Cdf<F> for Gumbel<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        let z = (x - self.location) / self.scale;
+        (-(-z).exp()).exp()
+    }
+
+    fn sf(&self, x: F) -> F {
+        let z = (x - self.location) / self.scale;
+        -(-(-z).exp()).exp_m1()
+    }
+}
+
+impl<F> Quantile<F> for Gumbel<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        if !(p >= F::zero() && p <= F::one()) {
+            return F::nan();
+        }
+        self.location - self.scale * (-p.ln()).ln()
+    }
+}
+
+impl<F> Moments<F> for Gumbel<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        // The Euler–Mascheroni constant γ
+        let euler = F::from(0.57721566490153286061).unwrap();
+        Some(self.location + euler * self.scale)
+    }
+
+    fn variance(&self) -> Option<F> {
+        // π² / 6
+        let c = F::from(1.6449340668482264365).unwrap();
+        Some(c * self.scale * self.scale)
+    }
+
+    fn skewness(&self) -> Option<F> {
+        // 12 √6 ζ(3) / π³
+        Some(F::from(1.1395470994046486575).unwrap())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        Some(F::from(2.4).unwrap())
+    }
+}
+
+impl<F> Entropy<F> for Gumbel<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // The Euler–Mascheroni constant γ
+        let euler = F::from(0.57721566490153286061).unwrap();
+        self.scale.ln() + euler + F::one()
+    }
+}
+
+impl<F> Summary<F> for Gumbel<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::real_line()
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some(self.location)
+    }
+
+    fn median(&self) -> F {
+        self.location - self.scale * F::from(core::f64::consts::LN_2).unwrap().ln()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,8 +350,121 @@ mod tests {
             .all(|(p_hat, p)| (p_hat - p).abs() < 0.003))
     }
 
+    #[test]
+    fn test_pdf() {
+        let d = Gumbel::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(d.pdf(1.5), 0.17871767308609127369, 1e-16);
+        let d = Gumbel::new(0.0, 1.0).unwrap();
+        assert_almost_eq!(d.ln_pdf(-30.0), -10686474581494.462147, 1e-2);
+        assert_eq!(d.pdf(-1000.0), 0.0);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let d = Gumbel::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(d.cdf(1.5), 0.45895606930766381846, 1e-15);
+        assert_almost_eq!(d.sf(1.5), 0.54104393069233618154, 1e-15);
+        assert_almost_eq!(d.sf(100.0), 3.1799709001977494982e-22, 1e-36);
+        assert_eq!(d.cdf(-1000.0), 0.0);
+    }
+
+    #[test]
+    fn test_quantile() {
+        let d = Gumbel::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(d.quantile(0.3), 0.62874648227526865097, 1e-15);
+        assert_almost_eq!(d.quantile(0.999), 14.814510141047431223, 1e-13);
+        assert_eq!(d.quantile(0.0), f64::NEG_INFINITY);
+        assert_eq!(d.quantile(1.0), f64::INFINITY);
+        assert!(d.quantile(-0.1).is_nan());
+    }
+
+    #[test]
+    fn test_moments() {
+        let d = Gumbel::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 2.1544313298030657212, 1e-14);
+        assert_almost_eq!(d.variance().unwrap(), 6.5797362673929057459, 1e-14);
+        assert_almost_eq!(d.skewness().unwrap(), 1.1395470994046486575, 1e-14);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 2.4, 1e-14);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let d = Gumbel::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(d.entropy(), 2.2703628454614781700, 1e-15);
+    }
+
+    #[test]
+    fn test_summary() {
+        let d = Gumbel::new(1.0, 2.0).unwrap();
+        assert_eq!(d.support(), Support::real_line());
+        assert_eq!(d.mode(), Some(1.0));
+        assert_almost_eq!(d.median(), 1.7330258411633286540, 1e-15);
+    }
+
     #[test]
     fn gumbel_distributions_can_be_compared() {
         assert_eq!(Gumbel::new(1.0, 2.0), Gumbel::new(1.0, 2.0));
     }
+
+    #[test]
+    fn test_fit_block_maxima() {
+        // The maxima are 3.4, 5.1 and 4.0, with an incomplete last block
+        let data = [1.2, 3.4, 2.2, 5.1, 2.9, 4.0, 0.5];
+        let fitted = Gumbel::fit_block_maxima(&data, 2).unwrap();
+        let d = fitted.distribution;
+        assert_almost_eq!(d.location, 3.8306272788277377690, 1e-12);
+        assert_almost_eq!(d.scale, 0.56009932062053629340, 1e-12);
+        assert_almost_eq!(fitted.log_likelihood, -3.0609683224375523250, 1e-12);
+        let maxima = [3.4, 5.1, 4.0];
+        assert_eq!(Gumbel::fit_block_maxima(&maxima, 1), Ok(fitted));
+
+        let mut rng = crate::test::rng(235);
+        let dist = Gumbel::new(-2.0, 0.5).unwrap();
+        let data: [f64; 5000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        let d = Gumbel::fit_block_maxima(&data, 1).unwrap().distribution;
+        assert_almost_eq!(d.location, -2.0, 0.03);
+        assert_almost_eq!(d.scale, 0.5, 0.03);
+
+        assert_eq!(
+            Gumbel::fit_block_maxima(&data, 0),
+            Err(FitError::InvalidSize)
+        );
+        assert_eq!(
+            Gumbel::fit_block_maxima(&[1.0, 2.0], 3),
+            Err(FitError::Empty)
+        );
+        assert_eq!(
+            Gumbel::fit_block_maxima(&[1.0, 2.0, 2.0, 1.0], 2),
+            Err(FitError::Constant)
+        );
+        assert_eq!(
+            Gumbel::fit_block_maxima(&[1.0, f64::NAN, 2.0, 1.0], 2),
+            Err(FitError::OutOfSupport)
+        );
+        let options = FitOptions::new().with_max_iterations(1);
+        assert_eq!(
+            Gumbel::fit_block_maxima_with(&maxima, 1, options),
+            Err(FitError::NoConvergence)
+        );
+    }
+
+    #[test]
+    fn test_from_mean_variance() {
+        let gumbel = Gumbel::from_mean_variance(3.0, 4.0).unwrap();
+        assert_almost_eq!(gumbel.mean().unwrap(), 3.0, 1e-15);
+        assert_almost_eq!(gumbel.variance().unwrap(), 4.0, 1e-14);
+
+        assert_eq!(
+            Gumbel::from_mean_variance(f64::INFINITY, 1.0),
+            Err(Error::LocationNotFinite)
+        );
+        assert_eq!(
+            Gumbel::from_mean_variance(0.0, 0.0),
+            Err(Error::ScaleNotPositive)
+        );
+        assert_eq!(
+            Gumbel::from_mean_variance(0.0, f64::NAN),
+            Err(Error::ScaleNotPositive)
+        );
+    }
 }
@@ -1,6 +1,9 @@
 //! The hypergeometric distribution `Hypergeometric(N, K, n)`.
 
-use crate::Distribution;
+use crate::entropy::lattice_entropy;
+use crate::quantile::discrete_quantile;
+use crate::special::ln_binomial_raw;
+use crate::{Cdf, DiscretePmf, Distribution, Entropy, Moments, Quantile, Summary, Support};
 use core::fmt;
 #[allow(unused_imports)]
 use num_traits::Float;
@@ -98,37 +101,25 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
-// evaluate fact(numerator.0)*fact(numerator.1) / fact(denominator.0)*fact(denominator.1)
-fn fraction_of_products_of_factorials(numerator: (u64, u64), denominator: (u64, u64)) -> f64 {
-    let min_top = u64::min(numerator.0, numerator.1);
-    let min_bottom = u64::min(denominator.0, denominator.1);
-    // the factorial of this will cancel out:
-    let min_all = u64::min(min_top, min_bottom);
-
-    let max_top = u64::max(numerator.0, numerator.1);
-    let max_bottom = u64::max(denominator.0, denominator.1);
-    let max_all = u64::max(max_top, max_bottom);
-
-    let mut result = 1.0;
-    for i in (min_all + 1)..=max_all {
-        if i <= min_top {
-            result *= i as f64;
-        }
-
-        if i <= min_bottom {
-            result /= i as f64;
-        }
-
-        if i <= max_top {
-            result *= i as f64;
-        }
-
-        if i <= max_bottom {
-            result /= i as f64;
-        }
+// evaluate ln(fact(n1)*fact(n2)*fact(k)*fact(n - k) / fact(x)*fact(n1 - x)*fact(k - x)*fact(n2 - k + x)*fact(n)),
+// with n = n1 + n2, i.e. the log-probability of x successes in Hypergeometric(n, n1, k)
+fn ln_fraction_of_products_of_factorials(n1: u64, n2: u64, k: u64, x: u64) -> f64 {
+    if x > u64::min(n1, k) || k - x > n2 {
+        return f64::NEG_INFINITY;
+    }
+    let n = n1 + n2;
+    if k == 0 || k == n {
+        return 0.0;
     }
 
-    result
+    // Group the factorials into the binomial coefficients of
+    // b(x; n1, p) b(k - x; n2, p) / b(k; n, p): with the same p = k / n the
+    // powers of p and q cancel, and each term stays close to its mode, so
+    // neither large populations nor the tails underflow.
+    let p = k as f64 / n as f64;
+    let q = (n - k) as f64 / n as f64;
+    ln_binomial_raw(x as f64, n1 as f64, p, q) + ln_binomial_raw((k - x) as f64, n2 as f64, p, q)
+        - ln_binomial_raw(k as f64, n as f64, p, q)
 }
 
 const LOGSQRT2PI: f64 = 0.91893853320467274178; // log(sqrt(2*pi))
@@ -204,13 +195,10 @@ impl Hypergeometric {
         let m = ((k + 1) as f64 * (n1 + 1) as f64 / (n + 2) as f64).floor();
         let sampling_method = if m - f64::max(0.0, k as f64 - n2 as f64) < HIN_THRESHOLD {
             let (initial_p, initial_x) = if k < n2 {
-                (
-                    fraction_of_products_of_factorials((n2, n - k), (n, n2 - k)),
-                    0,
-                )
+                (ln_fraction_of_products_of_factorials(n1, n2, k, 0).exp(), 0)
             } else {
                 (
-                    fraction_of_products_of_factorials((n1, k), (n, k - n2)),
+                    ln_fraction_of_products_of_factorials(n1, n2, k, k - n2).exp(),
                     (k - n2) as i64,
                 )
             };
@@ -446,6 +434,188 @@ impl Distribution<u64> for Hypergeometric {
     }
 }
 
+impl Hypergeometric {
+    /// Map a number of successes in the internal parameterization to the
+    /// corresponding outcome.
+    fn external(&self, x: u64) -> u64 {
+        (self.offset_x + self.sign_x * x as i64) as u64
+    }
+
+    /// The log-probability of `x` successes in the internal parameterization
+    /// (with `n1 <= n2` and `k <= n / 2`), which has the same probabilities.
+    fn ln_pmf_internal(&self, x: u64) -> f64 {
+        let Hypergeometric { n1, n2, k, .. } = *self;
+        ln_fraction_of_products_of_factorials(n1, n2, k, x)
+    }
+
+    /// `(P(X <= j), P(X > j))` in the internal parameterization.
+    ///
+    /// The tail not containing the mode is summed term by term, using the
+    /// ratio of consecutive probabilities; the other is its complement.
+    fn tails_internal(&self, j: f64) -> (f64, f64) {
+        let Hypergeometric { n1, n2, k, .. } = *self;
+        let (lo, hi) = (k.saturating_sub(n2), u64::min(n1, k));
+        if !(j >= lo as f64) {
+            return (0.0, 1.0);
+        }
+        if j >= hi as f64 {
+            return (1.0, 0.0);
+        }
+        let j = j as u64;
+        let (n1, n2, k) = (n1 as f64, n2 as f64, k as f64);
+        let mode = ((k + 1.0) * (n1 + 1.0) / (n1 + n2 + 2.0)).floor() as u64;
+        if j < mode {
+            // f(i - 1) / f(i) = i (n2 - k + i) / ((n1 - i + 1) (k - i + 1))
+            let mut term = self.ln_pmf_internal(j).exp();
+            let mut sum = term;
+            let mut i = j;
+            while i > lo && term > sum * f64::EPSILON * 0.1 {
+                let x = i as f64;
+                term *= x * (n2 - k + x) / ((n1 - x + 1.0) * (k - x + 1.0));
+                sum += term;
+                i -= 1;
+            }
+            (sum, 1.0 - sum)
+        } else {
+            // f(i + 1) / f(i) = (n1 - i) (k - i) / ((i + 1) (n2 - k + i + 1))
+            let mut term = self.ln_pmf_internal(j + 1).exp();
+            let mut sum = term;
+            let mut i = j + 1;
+            while i < hi && term > sum * f64::EPSILON * 0.1 {
+                let x = i as f64;
+                term *= (n1 - x) * (k - x) / ((x + 1.0) * (n2 - k + x + 1.0));
+                sum += term;
+                i += 1;
+            }
+            (1.0 - sum, sum)
+        }
+    }
+}
+
+impl DiscretePmf<f64> for Hypergeometric {
+    fn ln_pmf(&self, x: u64) -> f64 {
+        // Map `x` to the number of successes in the internal parameterization.
+        if x > i64::MAX as u64 {
+            return f64::NEG_INFINITY;
+        }
+        let x = (x as i64 - self.offset_x) * self.sign_x;
+        if x < 0 {
+            return f64::NEG_INFINITY;
+        }
+        self.ln_pmf_internal(x as u64)
+    }
+}
+
+impl Cdf<f64> for Hypergeometric {
+    fn cdf(&self, x: f64) -> f64 {
+        let x = x.floor();
+        let offset = self.offset_x as f64;
+        if self.sign_x > 0 {
+            self.tails_internal(x - offset).0
+        } else {
+            // X <= x if and only if the internal count exceeds offset - x - 1
+            self.tails_internal(offset - x - 1.0).1
+        }
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        let x = x.floor();
+        let offset = self.offset_x as f64;
+        if self.sign_x > 0 {
+            self.tails_internal(x - offset).1
+        } else {
+            self.tails_internal(offset - x - 1.0).0
+        }
+    }
+}
+
+impl Quantile<f64> for Hypergeometric {
+    fn quantile(&self, p: f64) -> f64 {
+        let Hypergeometric { n1, n2, k, .. } = *self;
+        let (lo, hi) = (k.saturating_sub(n2) as f64, u64::min(n1, k) as f64);
+        let mean = k as f64 * n1 as f64 / (n1 + n2) as f64;
+        let (offset, sign) = (self.offset_x as f64, self.sign_x as f64);
+        let (a, b) = (offset + sign * lo, offset + sign * hi);
+        discrete_quantile(self, p, a.min(b), a.max(b), offset + sign * mean)
+    }
+}
+
+impl Moments<f64> for Hypergeometric {
+    fn mean(&self) -> Option<f64> {
+        let Hypergeometric { n1, n2, k, .. } = *self;
+        let mean = k as f64 * n1 as f64 / (n1 + n2) as f64;
+        Some(self.offset_x as f64 + self.sign_x as f64 * mean)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        let Hypergeometric { n1, n2, k, .. } = *self;
+        let (n1, n2, k) = (n1 as f64, n2 as f64, k as f64);
+        let n = n1 + n2;
+        Some(k * n1 * n2 * (n - k) / (n * n * (n - 1.0)))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let Hypergeometric { n1, n2, k, .. } = *self;
+        let (n1, n2, k) = (n1 as f64, n2 as f64, k as f64);
+        let n = n1 + n2;
+        let prod = k * n1 * n2 * (n - k);
+        if prod == 0.0 {
+            return None;
+        }
+        let skew = (n2 - n1) * (n - 1.0).sqrt() * (n - 2.0 * k) / (prod.sqrt() * (n - 2.0));
+        Some(self.sign_x as f64 * skew)
+    }
+
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let Hypergeometric { n1, n2, k, .. } = *self;
+        let (n1, n2, k) = (n1 as f64, n2 as f64, k as f64);
+        let n = n1 + n2;
+        let prod = k * n1 * n2 * (n - k);
+        if prod == 0.0 {
+            return None;
+        }
+        let a = (n - 1.0) * n * n * (n * (n + 1.0) - 6.0 * n1 * n2 - 6.0 * k * (n - k));
+        let b = 6.0 * prod * (5.0 * n - 6.0);
+        Some((a + b) / (prod * (n - 2.0) * (n - 3.0)))
+    }
+}
+
+impl Entropy<f64> for Hypergeometric {
+    fn entropy(&self) -> f64 {
+        lattice_entropy(self)
+    }
+}
+
+impl Summary<f64> for Hypergeometric {
+    fn support(&self) -> Support<f64> {
+        let Hypergeometric { n1, n2, k, .. } = *self;
+        let (a, b) = (
+            self.external(k.saturating_sub(n2)),
+            self.external(n1.min(k)),
+        );
+        Support::Integer {
+            lower: a.min(b),
+            upper: Some(a.max(b)),
+        }
+    }
+
+    fn mode(&self) -> Option<f64> {
+        let Hypergeometric { n1, n2, k, .. } = *self;
+        // The internal modes are m - 1 and m if m = (k + 1)(n1 + 1) / (n + 2)
+        // is an integer, and floor(m) otherwise
+        let num = (u128::from(k) + 1) * (u128::from(n1) + 1);
+        let den = u128::from(n1 + n2) + 2;
+        let m = (num / den) as u64;
+        let (lo, hi) = if num % den == 0 { (m - 1, m) } else { (m, m) };
+        let (a, b) = (self.external(lo), self.external(hi));
+        Some(a.min(b) as f64)
+    }
+
+    fn median(&self) -> f64 {
+        self.quantile(0.5)
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -498,6 +668,154 @@ mod test {
         test_hypergeometric_mean_and_variance(100100, 100, 10000, &mut rng);
     }
 
+    #[test]
+    fn test_hypergeometric_pmf() {
+        let distr = Hypergeometric::new(60, 24, 7).unwrap();
+        assert_almost_eq!(distr.pmf(3), 0.30870425625724158438, 1e-15);
+        assert_almost_eq!(distr.pmf(0), 0.021614527259117987839, 1e-15);
+        assert_eq!(distr.pmf(8), 0.0);
+        let total: f64 = (0..=7).map(|x| distr.pmf(x)).sum();
+        assert_almost_eq!(total, 1.0, 1e-14);
+
+        // Both switches of the internal parameterization:
+        let distr = Hypergeometric::new(50, 40, 45).unwrap();
+        assert_almost_eq!(distr.pmf(38), 0.044176782646453586060, 1e-15);
+        assert_eq!(distr.pmf(34), 0.0);
+        assert_eq!(distr.pmf(41), 0.0);
+
+        let distr = Hypergeometric::new(10, 10, 4).unwrap();
+        assert_eq!(distr.pmf(4), 1.0);
+        assert_eq!(distr.pmf(3), 0.0);
+
+        // Population too large for the inverse transform sampler's setup:
+        let distr = Hypergeometric::new(10_000_000_000, 3_000_000_000, 1_000_000).unwrap();
+        assert_almost_eq!(distr.ln_pmf(299_000), -9.4280702215533053055, 1e-8);
+    }
+
+    #[test]
+    fn test_hypergeometric_cdf() {
+        // Covers each of the switches of the internal parameterization
+        for &(n, k, s) in &[(60, 24, 7), (50, 40, 45), (50, 10, 30), (50, 40, 10)] {
+            let distr = Hypergeometric::new(n, k, s).unwrap();
+            let mut lower = 0.0;
+            for x in 0..=s {
+                lower += distr.pmf(x);
+                assert_almost_eq!(distr.cdf(x as f64), lower, 1e-14);
+                assert_almost_eq!(distr.sf(x as f64 + 0.5), 1.0 - lower, 1e-14);
+            }
+            assert_eq!(distr.cdf(-1.0), 0.0);
+            assert_eq!(distr.sf(s as f64), 0.0);
+        }
+
+        let distr = Hypergeometric::new(60, 24, 7).unwrap();
+        assert_almost_eq!(distr.sf(6.0), 8.9616208844730177284e-4, 1e-17);
+        assert_almost_eq!(distr.cdf(2.0), 0.41207050355286228429, 1e-15);
+    }
+
+    #[test]
+    fn test_hypergeometric_quantile() {
+        let hyper = Hypergeometric::new(20, 7, 10).unwrap();
+        assert_eq!(hyper.quantile(0.1), 2.0);
+        assert_eq!(hyper.quantile(0.45), 3.0);
+        assert_eq!(hyper.quantile(0.9), 5.0);
+        assert_eq!(hyper.quantile(0.0), 0.0);
+        assert_eq!(hyper.quantile(1.0), 7.0);
+        let hyper = Hypergeometric::new(100, 90, 50).unwrap();
+        assert_eq!(hyper.quantile(0.1), 43.0);
+        assert_eq!(hyper.quantile(0.5), 45.0);
+        assert_eq!(hyper.quantile(0.9), 47.0);
+        assert_eq!(hyper.quantile(0.0), 40.0);
+        assert_eq!(hyper.quantile(1.0), 50.0);
+
+        for &(n, k, s) in &[(60, 24, 7), (50, 40, 45), (50, 10, 30), (50, 40, 10)] {
+            let distr = Hypergeometric::new(n, k, s).unwrap();
+            for i in 1..100 {
+                let p = i as f64 / 100.0;
+                let x = distr.quantile(p);
+                assert!(distr.cdf(x) >= p && distr.cdf(x - 1.0) < p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hypergeometric_moments() {
+        // Covers each of the switches of the internal parameterization
+        let expected = [
+            (
+                (60, 24, 7),
+                [
+                    2.8,
+                    1.5091525423728813559,
+                    0.12911992465053382296,
+                    -0.21803940560575152912,
+                ],
+            ),
+            (
+                (50, 40, 10),
+                [
+                    8.0,
+                    1.3061224489795918367,
+                    -0.328125,
+                    -0.11465259308510638298,
+                ],
+            ),
+            (
+                (50, 40, 45),
+                [
+                    36.0,
+                    0.73469387755102040816,
+                    0.58333333333333333333,
+                    -0.075059101654846335697,
+                ],
+            ),
+        ];
+        for &((n, k, s), m) in &expected {
+            let d = Hypergeometric::new(n, k, s).unwrap();
+            assert_almost_eq!(d.mean().unwrap(), m[0], 1e-14);
+            assert_almost_eq!(d.variance().unwrap(), m[1], 1e-14);
+            assert_almost_eq!(d.skewness().unwrap(), m[2], 1e-14);
+            assert_almost_eq!(d.excess_kurtosis().unwrap(), m[3], 1e-14);
+        }
+        let d = Hypergeometric::new(10, 10, 5).unwrap();
+        assert_eq!(d.variance(), Some(0.0));
+        assert_eq!(d.skewness(), None);
+    }
+
+    #[test]
+    fn test_hypergeometric_entropy() {
+        // Invariant under the symmetries of the internal parameterization
+        for &(n, k, draws) in &[(20, 7, 5), (20, 13, 5), (20, 5, 7), (20, 7, 15)] {
+            let d = Hypergeometric::new(n, k, draws).unwrap();
+            assert_almost_eq!(d.entropy(), 1.3572874368991208247, 1e-15);
+        }
+        let d = Hypergeometric::new(1_000_000, 400_000, 300_000).unwrap();
+        assert_almost_eq!(d.entropy(), 6.8328122496522589665, 1e-13);
+        assert_eq!(Hypergeometric::new(20, 7, 0).unwrap().entropy(), 0.0);
+    }
+
+    #[test]
+    fn test_hypergeometric_summary() {
+        for &(params, support, mode, median) in &[
+            ((20, 7, 12), (0, 7), 4.0, 4.0),
+            ((10, 7, 9), (6, 7), 6.0, 6.0),
+            ((100, 60, 70), (30, 60), 42.0, 42.0),
+            ((10, 6, 5), (1, 5), 3.0, 3.0),
+            // Ties, also with swapped internal parameterizations
+            ((10, 5, 3), (0, 3), 1.0, 1.0),
+            ((7, 5, 5), (3, 5), 3.0, 4.0),
+            ((10, 7, 8), (5, 7), 5.0, 6.0),
+            ((10, 8, 7), (5, 7), 5.0, 6.0),
+        ] {
+            let (n, k, s) = params;
+            let d = Hypergeometric::new(n, k, s).unwrap();
+            let (lower, upper) = support;
+            let upper = Some(upper);
+            assert_eq!(d.support(), Support::Integer { lower, upper });
+            assert_eq!(d.mode(), Some(mode));
+            assert_eq!(d.median(), median);
+        }
+    }
+
     #[test]
     fn hypergeometric_distributions_can_be_compared() {
         assert_eq!(Hypergeometric::new(1, 2, 3), Hypergeometric::new(1, 2, 3));
@@ -508,7 +826,7 @@ mod test {
         let test = [0.5, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
         for &v in test.iter() {
             let ln_fac = ln_of_factorial(v);
-            assert!((special::Gamma::ln_gamma(v + 1.0).0 - ln_fac).abs() < 1e-4);
+            assert!((crate::special::ln_gamma(v + 1.0) - ln_fac).abs() < 1e-4);
         }
     }
 }
@@ -1,17 +1,30 @@
 //! The inverse Gaussian distribution `IG(μ, λ)`.
 
-use crate::{Distribution, StandardNormal, StandardUniform};
+use crate::entropy::numeric_entropy;
+use crate::quantile::invert_cdf;
+use crate::special::{ln_erfc, std_normal_cdf, LN_2PI};
+use crate::utils::integrate;
+use crate::{
+    Cdf, CharacteristicFunction, ContinuousPdf, Distribution, Entropy, Mgf, Moments, Quantile,
+    StandardNormal, StandardUniform, Summary, Support,
+};
 use core::fmt;
+use core::ops::Bound;
+use num_complex::Complex;
 use num_traits::Float;
 use rand::Rng;
 
-/// Error type returned from [`InverseGaussian::new`]
+/// Error type returned from [`InverseGaussian::new`] and
+/// [`InverseGaussian::from_mean_variance`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// `mean <= 0` or `nan`.
     MeanNegativeOrNull,
     /// `shape <= 0` or `nan`.
     ShapeNegativeOrNull,
+    /// `variance <= 0`, infinite or `nan`.
+    VarianceOutOfRange,
 }
 
 impl fmt::Display for Error {
@@ -19,6 +32,9 @@ impl fmt::Display for Error {
         f.write_str(match self {
             Error::MeanNegativeOrNull => "mean <= 0 or is NaN in inverse Gaussian distribution",
             Error::ShapeNegativeOrNull => "shape <= 0 or is NaN in inverse Gaussian distribution",
+            Error::VarianceOutOfRange => {
+                "variance is not positive and finite in inverse Gaussian distribution"
+            }
         })
     }
 }
@@ -79,6 +95,34 @@ where
 
         Ok(Self { mean, shape })
     }
+
+    /// Construct an inverse Gaussian distribution with the given `mean` and
+    /// `variance`.
+    ///
+    /// The shape is `mean³ / variance`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::InverseGaussian;
+    ///
+    /// let ig = InverseGaussian::from_mean_variance(2.0, 4.0).unwrap();
+    /// assert_eq!(ig, InverseGaussian::new(2.0, 2.0).unwrap());
+    /// ```
+    pub fn from_mean_variance(mean: F, variance: F) -> Result<InverseGaussian<F>, Error> {
+        if !(mean > F::zero()) {
+            return Err(Error::MeanNegativeOrNull);
+        }
+        if !(variance > F::zero() && variance.is_finite()) {
+            return Err(Error::VarianceOutOfRange);
+        }
+        Self::new(mean, mean * mean / variance * mean)
+    }
+
+    /// Returns the `(mean, shape)` parameters.
+    pub(crate) fn params(&self) -> (F, F) {
+        (self.mean, self.shape)
+    }
 }
 
 impl<F> Distribution<F> for InverseGaussian<F>
@@ -112,6 +156,181 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for InverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::neg_infinity();
+        }
+        let (mu, l) = (self.mean, self.shape);
+        let half = F::from(0.5).unwrap();
+        let d = (x - mu) / mu;
+        half * (l.ln() - F::from(LN_2PI).unwrap() - F::from(3.).unwrap() * x.ln())
+            - half * l * d * d / x
+    }
+}
+
+impl<F> Cdf<F> for InverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::zero();
+        }
+        let (a, term) = cdf_terms(self.mean, self.shape, x);
+        std_normal_cdf(a) + term
+    }
+
+    fn sf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::one();
+        }
+        let (a, term) = cdf_terms(self.mean, self.shape, x);
+        let upper = std_normal_cdf(-a);
+        if term <= F::from(0.5).unwrap() * upper {
+            return upper - term;
+        }
+        // The difference cancels in the far upper tail, so integrate the
+        // density instead, over `t = x + c (1 - u) / u` where `c` is the
+        // length scale of the exponential decay.
+        let (mu, l) = (self.mean, self.shape);
+        let c = F::from(2.0).unwrap() * mu * mu / l;
+        integrate(
+            |u: F| {
+                let density = self.pdf(x + c * (F::one() - u) / u);
+                if density == F::zero() {
+                    F::zero()
+                } else {
+                    density * c / u / u
+                }
+            },
+            F::zero(),
+            F::one(),
+        )
+    }
+}
+
+impl<F> Quantile<F> for InverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        invert_cdf(self, p, F::zero(), F::infinity(), self.mean, F::one())
+    }
+}
+
+impl<F> Moments<F> for InverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some(self.mean)
+    }
+
+    fn variance(&self) -> Option<F> {
+        Some(self.mean * self.mean * self.mean / self.shape)
+    }
+
+    fn skewness(&self) -> Option<F> {
+        Some(F::from(3.0).unwrap() * (self.mean / self.shape).sqrt())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        Some(F::from(15.0).unwrap() * self.mean / self.shape)
+    }
+}
+
+impl<F> Entropy<F> for InverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // The coefficient of variation sets the width on a logarithmic scale
+        let cv = (self.mean / self.shape).sqrt();
+        numeric_entropy(|x| self.ln_pdf(x), F::zero(), self.mean, cv.min(F::one()))
+    }
+}
+
+impl<F> Mgf<F> for InverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn cgf(&self, t: F) -> Option<F> {
+        // (λ/μ)(1 - sqrt(1 - x)) with x = 2μ²t/λ, which converges for x <= 1
+        let two_mu_t = (self.mean + self.mean) * t;
+        let x = two_mu_t * self.mean / self.shape;
+        if !(x <= F::one()) {
+            return None;
+        }
+        Some(two_mu_t / (F::one() + (F::one() - x).sqrt()))
+    }
+}
+
+impl<F> CharacteristicFunction<F> for InverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn characteristic(&self, t: F) -> Complex<F> {
+        let two_mu_t = (self.mean + self.mean) * t;
+        let x = two_mu_t * self.mean / self.shape;
+        let root = Complex::new(F::one(), -x).sqrt();
+        (Complex::new(F::zero(), two_mu_t) / (root + F::one())).exp()
+    }
+}
+
+impl<F> Summary<F> for InverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::Real {
+            lower: Bound::Excluded(F::zero()),
+            upper: Bound::Unbounded,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        // μ (sqrt(1 + r²) - r) with r = 3μ / 2λ
+        let r = F::from(1.5).unwrap() * self.mean / self.shape;
+        Some(self.mean / (r.hypot(F::one()) + r))
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
+/// The distribution function of `IG(μ, λ)` is `Φ(a) + exp(2λ/μ) Φ(-b)`, with
+/// `a = sqrt(λ/x) (x/μ - 1)` and `b = sqrt(λ/x) (x/μ + 1)`; returns `a` and
+/// the second term, evaluated without overflow.
+fn cdf_terms<F: Float>(mu: F, l: F, x: F) -> (F, F) {
+    let r = (l / x).sqrt();
+    let (a, b) = (r * (x / mu - F::one()), r * (x / mu + F::one()));
+    // ln Φ(-b) = ln(erfc(b / sqrt(2))) - ln 2
+    let ln_phi = ln_erfc(b / F::from(core::f64::consts::SQRT_2).unwrap())
+        - F::from(core::f64::consts::LN_2).unwrap();
+    (a, (F::from(2.0).unwrap() * l / mu + ln_phi).exp())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +344,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_inverse_gaussian_pdf() {
+        let inv_gauss = InverseGaussian::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(inv_gauss.pdf(1.5), 0.25995954096365484186, 1e-15);
+        assert_eq!(inv_gauss.pdf(0.0), 0.0);
+        assert_eq!(inv_gauss.ln_pdf(-1.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_inverse_gaussian_cdf() {
+        let inv_gauss = InverseGaussian::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(inv_gauss.cdf(1.5), 0.82440795620513710249, 1e-15);
+        assert_almost_eq!(inv_gauss.sf(1.5), 0.17559204379486289751, 1e-15);
+        assert_almost_eq!(inv_gauss.cdf(0.05) / 1.7890225350327072931e-9, 1.0, 1e-13);
+        assert_almost_eq!(inv_gauss.sf(30.0) / 2.1922318242781505163e-15, 1.0, 1e-12);
+        assert_eq!(inv_gauss.cdf(0.0), 0.0);
+        assert_eq!(inv_gauss.sf(-1.0), 1.0);
+        let inv_gauss = InverseGaussian::new(1.0, 1000.0).unwrap();
+        assert_almost_eq!(inv_gauss.cdf(1.1), 0.99878245141939282490, 1e-13);
+    }
+
+    #[test]
+    fn test_inverse_gaussian_quantile() {
+        let d = InverseGaussian::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(d.quantile(0.5), 0.80433904129600161921, 1e-15);
+        assert_almost_eq!(d.quantile(0.99), 3.5809303313709188849, 1e-14);
+        assert_eq!(d.quantile(0.0), 0.0);
+        assert_eq!(d.quantile(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_inverse_gaussian_moments() {
+        let d = InverseGaussian::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 1.0, 1e-15);
+        assert_almost_eq!(d.variance().unwrap(), 0.5, 1e-15);
+        assert_almost_eq!(d.skewness().unwrap(), 2.1213203435596425732, 1e-15);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 7.5, 1e-15);
+    }
+
+    #[test]
+    fn test_inverse_gaussian_entropy() {
+        let d = InverseGaussian::new(1.5, 2.0).unwrap();
+        assert_almost_eq!(d.entropy(), 1.2481394987473088484, 1e-13);
+        let d = InverseGaussian::new(1.0, 0.01).unwrap();
+        assert_almost_eq!(d.entropy(), -1.4121924376974114081, 1e-13);
+        let d = InverseGaussian::new(1.0, 1000.0).unwrap();
+        assert_almost_eq!(d.entropy(), -2.0356887316608344064, 1e-13);
+        let d = InverseGaussian::new(1.0, 1e-4).unwrap();
+        assert_almost_eq!(d.entropy(), -5.8885398470561205702, 1e-13);
+    }
+
+    #[test]
+    fn test_inverse_gaussian_mgf() {
+        let d = InverseGaussian::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(d.cgf(0.3).unwrap(), 0.82917960675006309108, 1e-15);
+        // The moment-generating function is finite at the boundary
+        assert_eq!(d.cgf(0.375), Some(1.5));
+        assert_eq!(d.cgf(0.4), None);
+        let phi = d.characteristic(1.0);
+        assert_almost_eq!(phi.re, 0.071938464756257041677, 1e-15);
+        assert_almost_eq!(phi.im, 0.55490474199278111913, 1e-15);
+    }
+
+    #[test]
+    fn test_inverse_gaussian_summary() {
+        let d = InverseGaussian::new(2.0, 3.0).unwrap();
+        assert!(!d.support().contains(0.0));
+        assert_almost_eq!(d.mode().unwrap(), 0.82842712474619009760, 1e-15);
+        assert_almost_eq!(d.median(), 1.5122506636053671019, 1e-14);
+    }
+
     #[test]
     fn test_inverse_gaussian_invalid_param() {
         assert!(InverseGaussian::new(-1.0, 1.0).is_err());
@@ -140,4 +430,21 @@ mod tests {
             InverseGaussian::new(1.0, 2.0)
         );
     }
+
+    #[test]
+    fn test_from_mean_variance() {
+        let dist = InverseGaussian::from_mean_variance(2.0, 4.0).unwrap();
+        assert_almost_eq!(dist.mean().unwrap(), 2.0, 1e-15);
+        assert_almost_eq!(dist.variance().unwrap(), 4.0, 1e-15);
+        assert_eq!(dist, InverseGaussian::new(2.0, 2.0).unwrap());
+
+        assert_eq!(
+            InverseGaussian::from_mean_variance(0.0, 1.0),
+            Err(Error::MeanNegativeOrNull)
+        );
+        assert_eq!(
+            InverseGaussian::from_mean_variance(1.0, -1.0),
+            Err(Error::VarianceOutOfRange)
+        );
+    }
 }
@@ -49,8 +49,12 @@
 //!   - [`Cauchy`] distribution
 //! - Related to Bernoulli trials (yes/no events, with a given probability):
 //!   - [`Binomial`] distribution
+//!   - [`BetaBinomial`] distribution
+//!   - [`PoissonBinomial`] distribution
 //!   - [`Geometric`] distribution
+//!   - [`NegativeBinomial`] distribution
 //!   - [`Hypergeometric`] distribution
+//!   - [`NegativeHypergeometric`] distribution
 //! - Related to positive real-valued quantities that grow exponentially
 //!   (e.g. prices, incomes, populations):
 //!   - [`LogNormal`] distribution
@@ -73,6 +77,17 @@
 //!   - [`Triangular`] distribution
 //! - Multivariate probability distributions
 //!   - [`Dirichlet`] distribution
+//!   - [`Multinomial`] distribution, and [`MultinomialDyn`] for a number of
+//!     categories chosen at run time
+//!   - [`DirichletMultinomial`] distribution
+//!   - [`MultivariateHypergeometric`] distribution
+//!   - [`MultivariateNormal`] distribution, and [`MultivariateNormalDyn`] for
+//!     a dimension chosen at run time
+//!   - [`MultivariateStudentT`] distribution
+//!   - [`MultivariateSkewNormal`] distribution
+//!   - [`Wishart`] and [`InverseWishart`] distributions of random matrices
+//!   - [`LkjCorrelation`] distribution of correlation matrices, and
+//!     [`LkjCholesky`] of their Cholesky factors
 //!   - [`UnitSphere`] distribution
 //!   - [`UnitBall`] distribution
 //!   - [`UnitCircle`] distribution
@@ -80,6 +95,47 @@
 //! - Misc. distributions
 //!   - [`InverseGaussian`] distribution
 //!   - [`NormalInverseGaussian`] distribution
+//!
+//! ## Analytic properties
+//!
+//! Besides sampling, many distributions expose analytic properties through
+//! the following traits:
+//!
+//! - [`ContinuousPdf`]: probability density function of continuous
+//!   distributions
+//! - [`DiscretePmf`]: probability mass function of discrete distributions
+//! - [`Cdf`]: cumulative distribution and survival functions of univariate
+//!   distributions
+//! - [`Quantile`]: quantile (inverse CDF) functions of univariate
+//!   distributions, and sampling by inversion
+//! - [`Moments`]: mean, variance, skewness and excess kurtosis of univariate
+//!   distributions
+//! - [`Entropy`]: differential entropy of continuous distributions and
+//!   Shannon entropy of discrete distributions
+//! - [`KlDivergence`]: Kullback–Leibler divergence between distributions of
+//!   the same family
+//! - [`Mgf`]: moment- and cumulant-generating functions of univariate
+//!   distributions
+//! - [`CharacteristicFunction`]: characteristic function of univariate
+//!   distributions
+//! - [`Summary`]: [`Support`], mode and median of univariate distributions
+//!
+//! The [`special`] module provides the special functions these are built on,
+//! such as the log-gamma, error and incomplete gamma and beta functions.
+//!
+//! ## Fitting
+//!
+//! Some distributions can be estimated from observed data with a `fit`
+//! constructor, such as [`Normal::fit`], which fails with a [`FitError`] if
+//! the data do not determine the distribution. Where the estimate has no
+//! closed form, as for [`Gamma::fit`], it is found iteratively as
+//! configured by [`FitOptions`], and returned as [`Fitted`] together with
+//! the number of iterations and the log-likelihood.
+//!
+//! For extreme values, the [`tail`] module estimates the heaviness of the
+//! upper tail of observations, and [`Pareto::fit_hill`],
+//! [`Gumbel::fit_block_maxima`] and [`Frechet::fit_block_maxima`]
+//! calibrate distributions to the tail or to the maxima of blocks.
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -97,19 +153,44 @@ pub use rand::distr::{
 };
 
 pub use self::beta::{Beta, Error as BetaError};
+pub use self::beta_binomial::{BetaBinomial, Error as BetaBinomialError};
 pub use self::binomial::{Binomial, Error as BinomialError};
 pub use self::cauchy::{Cauchy, Error as CauchyError};
+pub use self::cdf::Cdf;
 pub use self::chi_squared::{ChiSquared, Error as ChiSquaredError};
+pub use self::density::{ContinuousPdf, DiscretePmf};
 #[cfg(feature = "alloc")]
 pub use self::dirichlet::{Dirichlet, Error as DirichletError};
+pub use self::dirichlet_multinomial::{DirichletMultinomial, Error as DirichletMultinomialError};
+pub use self::divergence::KlDivergence;
+pub use self::entropy::Entropy;
 pub use self::exponential::{Error as ExpError, Exp, Exp1};
 pub use self::fisher_f::{Error as FisherFError, FisherF};
+pub use self::fit::{FitError, FitOptions, Fitted};
 pub use self::frechet::{Error as FrechetError, Frechet};
 pub use self::gamma::{Error as GammaError, Gamma};
+pub use self::generating::{CharacteristicFunction, Mgf};
 pub use self::geometric::{Error as GeoError, Geometric, StandardGeometric};
 pub use self::gumbel::{Error as GumbelError, Gumbel};
 pub use self::hypergeometric::{Error as HyperGeoError, Hypergeometric};
 pub use self::inverse_gaussian::{Error as InverseGaussianError, InverseGaussian};
+pub use self::lkj::{Error as LkjError, LkjCholesky, LkjCorrelation};
+pub use self::moments::Moments;
+#[cfg(feature = "alloc")]
+pub use self::multinomial::MultinomialDyn;
+pub use self::multinomial::{Error as MultinomialError, Multinomial};
+pub use self::multivariate_hypergeometric::{
+    Error as MultivariateHyperGeoError, MultivariateHypergeometric,
+};
+#[cfg(feature = "alloc")]
+pub use self::multivariate_normal::MultivariateNormalDyn;
+pub use self::multivariate_normal::{Error as MultivariateNormalError, MultivariateNormal};
+pub use self::multivariate_skew_normal::{
+    Error as MultivariateSkewNormalError, MultivariateSkewNormal,
+};
+pub use self::multivariate_student_t::{Error as MultivariateStudentTError, MultivariateStudentT};
+pub use self::negative_binomial::{Error as NegativeBinomialError, NegativeBinomial};
+pub use self::negative_hypergeometric::{Error as NegativeHyperGeoError, NegativeHypergeometric};
 pub use self::normal::{Error as NormalError, LogNormal, Normal, StandardNormal};
 pub use self::normal_inverse_gaussian::{
     Error as NormalInverseGaussianError, NormalInverseGaussian,
@@ -117,17 +198,23 @@ pub use self::normal_inverse_gaussian::{
 pub use self::pareto::{Error as ParetoError, Pareto};
 pub use self::pert::{Pert, PertBuilder, PertError};
 pub use self::poisson::{Error as PoissonError, Poisson};
+#[cfg(feature = "alloc")]
+pub use self::poisson_binomial::{Error as PoissonBinomialError, PoissonBinomial};
+pub use self::quantile::Quantile;
 pub use self::skew_normal::{Error as SkewNormalError, SkewNormal};
+pub use self::summary::{Summary, Support};
 pub use self::triangular::{Triangular, TriangularError};
 pub use self::unit_ball::UnitBall;
 pub use self::unit_circle::UnitCircle;
 pub use self::unit_disc::UnitDisc;
 pub use self::unit_sphere::UnitSphere;
 pub use self::weibull::{Error as WeibullError, Weibull};
+pub use self::wishart::{Error as WishartError, InverseWishart, Wishart};
 pub use self::zeta::{Error as ZetaError, Zeta};
 pub use self::zipf::{Error as ZipfError, Zipf};
 pub use student_t::StudentT;
 
+pub use num_complex;
 pub use num_traits;
 
 #[cfg(feature = "alloc")]
@@ -185,25 +272,49 @@ mod test {
 }
 
 mod beta;
+mod beta_binomial;
 mod binomial;
 mod cauchy;
+mod cdf;
 mod chi_squared;
+mod density;
 mod dirichlet;
+mod dirichlet_multinomial;
+mod divergence;
+mod entropy;
 mod exponential;
 mod fisher_f;
+mod fit;
 mod frechet;
 mod gamma;
+mod generating;
 mod geometric;
 mod gumbel;
 mod hypergeometric;
 mod inverse_gaussian;
+mod linalg;
+mod lkj;
+mod moments;
+mod multinomial;
+mod multivariate_hypergeometric;
+mod multivariate_normal;
+mod multivariate_skew_normal;
+mod multivariate_student_t;
+mod negative_binomial;
+mod negative_hypergeometric;
 mod normal;
 mod normal_inverse_gaussian;
 mod pareto;
 mod pert;
 pub(crate) mod poisson;
+#[cfg(feature = "alloc")]
+mod poisson_binomial;
+mod quantile;
 mod skew_normal;
+pub mod special;
 mod student_t;
+mod summary;
+pub mod tail;
 mod triangular;
 mod unit_ball;
 mod unit_circle;
@@ -211,6 +322,7 @@ mod unit_disc;
 mod unit_sphere;
 mod utils;
 mod weibull;
+mod wishart;
 mod zeta;
 mod ziggurat_tables;
 mod zipf;
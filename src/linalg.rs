@@ -0,0 +1,222 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Dense linear algebra for the multivariate distributions.
+//!
+//! Square matrices are slices of rows, so that the same code serves
+//! `[[F; N]; N]` without allocation and `Vec<Vec<F>>` for sizes only known
+//! at run time.
+
+use num_traits::Float;
+
+/// The reason a matrix is rejected by [`factorize`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MatrixError {
+    Infinite,
+    Asymmetric,
+    Indefinite,
+}
+
+/// Check that the square matrix `a` is finite, symmetric and
+/// positive-definite, and replace it by its Cholesky factor.
+pub(crate) fn factorize<F, R>(a: &mut [R]) -> Result<(), MatrixError>
+where
+    F: Float,
+    R: AsRef<[F]> + AsMut<[F]>,
+{
+    if !a
+        .iter()
+        .all(|row| row.as_ref().iter().all(|x| x.is_finite()))
+    {
+        return Err(MatrixError::Infinite);
+    }
+    if !is_symmetric(a) {
+        return Err(MatrixError::Asymmetric);
+    }
+    if !cholesky(a) {
+        return Err(MatrixError::Indefinite);
+    }
+    Ok(())
+}
+
+/// Whether the square matrix `a` is symmetric up to rounding.
+///
+/// A matrix computed in floating point, like `B Bᵀ`, is often symmetric only
+/// to a few ulps, and [`cholesky`] reads the lower triangle only. So each
+/// entry may differ from its transpose by `sqrt(ε)` relative to the larger of
+/// the two and `sqrt(|aᵢᵢ aⱼⱼ|)`, which bounds the entries of a covariance
+/// matrix.
+pub(crate) fn is_symmetric<F: Float, R: AsRef<[F]>>(a: &[R]) -> bool {
+    let tolerance = F::epsilon().sqrt();
+    (0..a.len()).all(|i| {
+        (0..i).all(|j| {
+            let (x, y) = (a[i].as_ref()[j], a[j].as_ref()[i]);
+            let scale = (a[i].as_ref()[i] * a[j].as_ref()[j])
+                .abs()
+                .sqrt()
+                .max(x.abs())
+                .max(y.abs());
+            (x - y).abs() <= tolerance * scale
+        })
+    })
+}
+
+/// Replace the symmetric matrix `a` by its Cholesky factor, the lower
+/// triangular matrix `L` with a positive diagonal such that `a = L Lᵀ`.
+///
+/// Only the lower triangle of `a` is read, and the strict upper triangle is
+/// set to zero. Returns `false`, leaving `a` in an unspecified state, if `a`
+/// is not positive-definite.
+pub(crate) fn cholesky<F: Float, R: AsMut<[F]>>(a: &mut [R]) -> bool {
+    let n = a.len();
+    for i in 0..n {
+        let (head, tail) = a.split_at_mut(i);
+        let row = tail[0].as_mut();
+        for j in 0..i {
+            let prev = head[j].as_mut();
+            let dot = (0..j).fold(F::zero(), |sum, k| sum + row[k] * prev[k]);
+            row[j] = (row[j] - dot) / prev[j];
+        }
+        let d = row[i] - (0..i).fold(F::zero(), |sum, k| sum + row[k] * row[k]);
+        // This also catches NaN
+        if !(d > F::zero()) {
+            return false;
+        }
+        row[i] = d.sqrt();
+        for x in row[i + 1..].iter_mut() {
+            *x = F::zero();
+        }
+    }
+    true
+}
+
+/// Replace the lower triangular matrix `l`, with a nonzero diagonal, by its
+/// inverse, which is also lower triangular.
+pub(crate) fn invert_lower<F: Float, R: AsMut<[F]>>(l: &mut [R]) {
+    let n = l.len();
+    for i in 0..n {
+        let (head, tail) = l.split_at_mut(i);
+        let row = tail[0].as_mut();
+        // Rows before `i` are already inverted; entries of this row after
+        // `j` are still those of `l`
+        for j in 0..i {
+            let sum = (j..i).fold(F::zero(), |sum, k| sum + row[k] * head[k].as_mut()[j]);
+            row[j] = -sum / row[i];
+        }
+        row[i] = row[i].recip();
+    }
+}
+
+/// Write `Mᵀ M` for the lower triangular matrix `m` into `out`.
+pub(crate) fn lower_gram<F: Float, R: AsRef<[F]>, S: AsMut<[F]>>(m: &[R], out: &mut [S]) {
+    let n = m.len();
+    for i in 0..n {
+        for j in 0..=i {
+            let sum = (i..n).fold(F::zero(), |sum, k| {
+                let row = m[k].as_ref();
+                sum + row[i] * row[j]
+            });
+            out[i].as_mut()[j] = sum;
+            out[j].as_mut()[i] = sum;
+        }
+    }
+}
+
+/// Write `M Mᵀ` for the lower triangular matrix `m` into `out`.
+pub(crate) fn lower_outer<F: Float, R: AsRef<[F]>, S: AsMut<[F]>>(m: &[R], out: &mut [S]) {
+    for i in 0..m.len() {
+        for j in 0..=i {
+            let (a, b) = (m[i].as_ref(), m[j].as_ref());
+            let sum = (0..=j).fold(F::zero(), |sum, k| sum + a[k] * b[k]);
+            out[i].as_mut()[j] = sum;
+            out[j].as_mut()[i] = sum;
+        }
+    }
+}
+
+/// Write the product `A B` of the lower triangular matrices `a` and `b` into
+/// `out`.
+pub(crate) fn lower_mul<F: Float, R: AsRef<[F]>, S: AsMut<[F]>>(a: &[R], b: &[R], out: &mut [S]) {
+    for (i, row) in out.iter_mut().enumerate() {
+        let (a, row) = (a[i].as_ref(), row.as_mut());
+        for (j, x) in row.iter_mut().enumerate() {
+            *x = if j > i {
+                F::zero()
+            } else {
+                (j..=i).fold(F::zero(), |sum, k| sum + a[k] * b[k].as_ref()[j])
+            };
+        }
+    }
+}
+
+/// Write `μ + L z` for the lower triangular matrix `l` into `out`.
+pub(crate) fn lower_mul_add<F: Float, R: AsRef<[F]>>(l: &[R], z: &[F], mean: &[F], out: &mut [F]) {
+    for (i, (x, &m)) in out.iter_mut().zip(mean).enumerate() {
+        let row = l[i].as_ref();
+        *x = (0..=i).fold(m, |sum, j| sum + row[j] * z[j]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cholesky() {
+        let mut a = [[4.0, 0.0, 0.0], [2.0, 10.0, 0.0], [-2.0, 5.0, 9.0]];
+        assert!(cholesky(&mut a[..]));
+        assert_eq!(a, [[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [-1.0, 2.0, 2.0]]);
+
+        let mut l = a;
+        invert_lower(&mut l[..]);
+        for (i, row) in a.iter().enumerate() {
+            for j in 0..3 {
+                let product: f64 = row.iter().zip(l.iter()).map(|(x, y)| x * y[j]).sum();
+                assert_almost_eq!(product, if i == j { 1.0 } else { 0.0 }, 1e-15);
+            }
+        }
+
+        let mut gram = [[0.0; 3]; 3];
+        lower_gram(&a[..], &mut gram[..]);
+        assert_eq!(gram, [[6.0, 1.0, -2.0], [1.0, 13.0, 4.0], [-2.0, 4.0, 4.0]]);
+
+        lower_outer(&a[..], &mut gram[..]);
+        assert_eq!(gram, [[4.0, 2.0, -2.0], [2.0, 10.0, 5.0], [-2.0, 5.0, 9.0]]);
+
+        let mut product = [[0.0; 3]; 3];
+        lower_mul(&a[..], &a[..], &mut product[..]);
+        assert_eq!(
+            product,
+            [[4.0, 0.0, 0.0], [5.0, 9.0, 0.0], [-2.0, 10.0, 4.0]]
+        );
+
+        let mut x = [0.0; 3];
+        lower_mul_add(&a[..], &[1.0, 1.0, 1.0], &[0.0, 1.0, 2.0], &mut x);
+        assert_eq!(x, [2.0, 5.0, 5.0]);
+
+        let mut singular = [[1.0, 1.0], [1.0, 1.0]];
+        assert!(!cholesky(&mut singular[..]));
+        let mut indefinite = [[1.0, 0.0], [2.0, 1.0]];
+        assert!(!cholesky(&mut indefinite[..]));
+        let mut nan = [[f64::NAN]];
+        assert!(!cholesky(&mut nan[..]));
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        assert!(is_symmetric(&[[1.0, 2.0], [2.0, 1.0]][..]));
+        assert!(!is_symmetric(&[[1.0, 2.0], [3.0, 1.0]][..]));
+        assert!(!is_symmetric(&[[1.0, f64::NAN], [f64::NAN, 1.0]][..]));
+
+        // Rounding errors relative to the entries or the diagonal are accepted
+        assert!(is_symmetric(&[[1.0, 0.1 + 0.2], [0.3, 1.0]][..]));
+        assert!(is_symmetric(&[[1e10, 1e-7], [0.0, 1e10]][..]));
+        assert!(!is_symmetric(&[[1.0, 1e-7], [0.0, 1.0]][..]));
+        assert!(!is_symmetric(&[[1.0, 2.0], [2.0 + 1e-6, 4.0]][..]));
+    }
+}
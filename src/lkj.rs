@@ -0,0 +1,297 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The LKJ distribution of random correlation matrices.
+
+use crate::linalg::lower_outer;
+use crate::{Beta, Distribution, Open01, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+
+/// The Lewandowski-Kurowicka-Joe ([LKJ](https://en.wikipedia.org/wiki/Lewandowski-Kurowicka-Joe_distribution))
+/// distribution of `N × N` correlation matrices.
+///
+/// The density of a correlation matrix `R`, that is a symmetric,
+/// positive-definite matrix with a unit diagonal, is proportional to
+/// `det(R)^(η - 1)` for the concentration `η > 0`. With `η = 1` the
+/// distribution is uniform over all correlation matrices, larger `η` favour
+/// weaker correlations, and smaller `η` stronger ones. Each correlation
+/// `Rᵢⱼ`, for `i ≠ j`, follows the [`Beta`] distribution with both shapes
+/// `η - 1 + N / 2` scaled to `[-1, 1]`.
+///
+/// [`LkjCholesky`] samples the Cholesky factors of the same matrices.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, LkjCorrelation};
+///
+/// let lkj = LkjCorrelation::<f64, 3>::new(2.0).unwrap();
+/// let r = lkj.sample(&mut rand::rng());
+/// println!("{:?} is a random correlation matrix", r);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LkjCorrelation<F, const N: usize>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    cholesky: LkjCholesky<F, N>,
+}
+
+/// The distribution of the Cholesky factors of the correlation matrices of
+/// the [`LkjCorrelation`] distribution.
+///
+/// Samples are lower triangular matrices `L` with a positive diagonal whose
+/// rows have unit length, so that `L Lᵀ` is a correlation matrix. Models
+/// that only need the factor, for example to sample from a
+/// [`MultivariateNormal`](crate::MultivariateNormal) distribution with the
+/// correlation matrix, avoid recomputing it.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, LkjCholesky};
+///
+/// let lkj = LkjCholesky::<f64, 3>::new(2.0).unwrap();
+/// let l = lkj.sample(&mut rand::rng());
+/// println!("{:?} is the Cholesky factor of a random correlation matrix", l);
+/// ```
+///
+/// # Implementation details
+///
+/// Samples are generated by the onion method of Lewandowski, Kurowicka and
+/// Joe, which builds the factor row by row: row `i` is `(√y u, √(1 - y))`
+/// for `y` following the [`Beta`] distribution with shapes `i / 2` and
+/// `η + (N - 1 - i) / 2` and `u` uniformly distributed on the unit sphere in
+/// `i` dimensions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "F: serde::Serialize")))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "F: serde::Deserialize<'de>"))
+)]
+pub struct LkjCholesky<F, const N: usize>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// The distribution of the squared length of the first `i` entries of
+    /// row `i`, with none for the first row
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    beta: [Option<Beta<F>>; N],
+}
+
+/// Error type returned from [`LkjCorrelation::new`] and
+/// [`LkjCholesky::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `N == 0`.
+    BadDimension,
+    /// `eta <= 0`, infinite or `nan`.
+    EtaOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::BadDimension => "zero dimensions in LKJ distribution",
+            Error::EtaOutOfRange => "eta is not positive and finite in LKJ distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl<F, const N: usize> LkjCholesky<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `LkjCholesky` with the concentration `eta`.
+    ///
+    /// Requires `N >= 1` and finite `eta > 0`.
+    pub fn new(eta: F) -> Result<LkjCholesky<F, N>, Error> {
+        if N == 0 {
+            return Err(Error::BadDimension);
+        }
+        if !(eta > F::zero() && eta.is_finite()) {
+            return Err(Error::EtaOutOfRange);
+        }
+        let half = F::from(0.5).unwrap();
+        // Both shapes are positive for i >= 1
+        let beta = core::array::from_fn(|i| {
+            if i == 0 {
+                return None;
+            }
+            let alpha = F::from(i).unwrap() * half;
+            let beta = eta + F::from(N - 1 - i).unwrap() * half;
+            Some(Beta::new(alpha, beta).unwrap())
+        });
+        Ok(LkjCholesky { beta })
+    }
+}
+
+impl<F, const N: usize> Distribution<[[F; N]; N]> for LkjCholesky<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [[F; N]; N] {
+        let mut l = [[F::zero(); N]; N];
+        for ((i, row), beta) in l.iter_mut().enumerate().zip(self.beta.iter()) {
+            let y = match beta {
+                Some(beta) => beta.sample(rng),
+                None => {
+                    row[0] = F::one();
+                    continue;
+                }
+            };
+
+            // A uniformly distributed direction, scaled to length √y
+            let mut norm = F::zero();
+            while norm == F::zero() {
+                for x in row[..i].iter_mut() {
+                    *x = rng.sample(StandardNormal);
+                }
+                norm = row[..i]
+                    .iter()
+                    .fold(F::zero(), |sum, &x| sum + x * x)
+                    .sqrt();
+            }
+            let scale = y.sqrt() / norm;
+            for x in row[..i].iter_mut() {
+                *x = *x * scale;
+            }
+            row[i] = (F::one() - y).sqrt();
+        }
+        l
+    }
+}
+
+impl<F, const N: usize> LkjCorrelation<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `LkjCorrelation` with the concentration `eta`.
+    ///
+    /// Requires `N >= 1` and finite `eta > 0`.
+    pub fn new(eta: F) -> Result<LkjCorrelation<F, N>, Error> {
+        Ok(LkjCorrelation {
+            cholesky: LkjCholesky::new(eta)?,
+        })
+    }
+}
+
+impl<F, const N: usize> Distribution<[[F; N]; N]> for LkjCorrelation<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [[F; N]; N] {
+        let l = self.cholesky.sample(rng);
+        let mut r = [[F::zero(); N]; N];
+        lower_outer(&l[..], &mut r[..]);
+        // The rows of `l` have unit length up to rounding
+        for (i, row) in r.iter_mut().enumerate() {
+            row[i] = F::one();
+        }
+        r
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::linalg::cholesky;
+
+    #[test]
+    fn test_new() {
+        assert!(LkjCorrelation::<f64, 1>::new(0.5).is_ok());
+        assert_eq!(LkjCorrelation::<f64, 0>::new(1.0), Err(Error::BadDimension));
+        assert_eq!(
+            LkjCorrelation::<f64, 2>::new(0.0),
+            Err(Error::EtaOutOfRange)
+        );
+        assert_eq!(
+            LkjCholesky::<f64, 2>::new(f64::INFINITY),
+            Err(Error::EtaOutOfRange)
+        );
+        assert_eq!(
+            LkjCholesky::<f64, 2>::new(f64::NAN),
+            Err(Error::EtaOutOfRange)
+        );
+
+        let mut rng = crate::test::rng(244);
+        assert_eq!(
+            LkjCorrelation::<f64, 1>::new(1.0).unwrap().sample(&mut rng),
+            [[1.0]]
+        );
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let mut rng = crate::test::rng(245);
+        let lkj = LkjCholesky::<f64, 4>::new(0.5).unwrap();
+        for _ in 0..100 {
+            let l = lkj.sample(&mut rng);
+            for (i, row) in l.iter().enumerate() {
+                assert!(row[i] > 0.0);
+                assert!(row[i + 1..].iter().all(|&x| x == 0.0));
+                let norm: f64 = row.iter().map(|x| x * x).sum();
+                assert_almost_eq!(norm, 1.0, 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_correlation() {
+        // The correlations follow Beta(β, β) on [-1, 1] with β = η - 1 + N / 2,
+        // which has the variance 1 / (2β + 1)
+        let eta = 2.0;
+        let variance = 1.0 / (2.0 * (eta - 1.0 + 1.5) + 1.0);
+        let lkj = LkjCorrelation::<f64, 3>::new(eta).unwrap();
+        let mut rng = crate::test::rng(246);
+        const SAMPLES: usize = 100_000;
+        let mut sum = [[0.0; 3]; 3];
+        let mut sum_sq = [[0.0; 3]; 3];
+        for _ in 0..SAMPLES {
+            let r = lkj.sample(&mut rng);
+            let mut factor = r;
+            assert!(cholesky(&mut factor[..]));
+            for (i, row) in r.iter().enumerate() {
+                assert_eq!(row[i], 1.0);
+                for (j, &x) in row.iter().enumerate() {
+                    assert_eq!(x, r[j][i]);
+                    sum[i][j] += x;
+                    sum_sq[i][j] += x * x;
+                }
+            }
+        }
+        for i in 0..3 {
+            for j in 0..i {
+                assert_almost_eq!(sum[i][j] / SAMPLES as f64, 0.0, 0.01);
+                assert_almost_eq!(sum_sq[i][j] / SAMPLES as f64, variance, 0.005);
+            }
+        }
+    }
+}
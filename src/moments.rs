@@ -0,0 +1,74 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Moments of univariate distributions.
+
+use num_traits::Float;
+
+/// The mean, variance, skewness and excess kurtosis of a univariate
+/// distribution.
+///
+/// Each method returns `None` if the moment does not exist: either the
+/// defining expectation diverges (as for every moment of the
+/// [`Cauchy`](crate::Cauchy) distribution, or the variance of a
+/// [`StudentT`](crate::StudentT) distribution with `ν <= 2`), or it is a
+/// standardized moment of a distribution with zero variance.
+///
+/// [`Normal`](crate::Normal) has inherent `mean` and `std_dev` methods which
+/// take precedence over this trait; call `Moments::mean(&normal)` to use the
+/// trait method.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Cauchy, Gamma, Moments};
+///
+/// let gamma = Gamma::new(4.0f64, 0.5).unwrap();
+/// assert_eq!(gamma.mean(), Some(2.0));
+/// assert_eq!(gamma.variance(), Some(1.0));
+/// assert_eq!(gamma.skewness(), Some(1.0));
+/// assert_eq!(gamma.excess_kurtosis(), Some(1.5));
+///
+/// let cauchy = Cauchy::new(0.0f64, 1.0).unwrap();
+/// assert_eq!(cauchy.mean(), None);
+/// ```
+pub trait Moments<F: Float> {
+    /// The mean `E[X]`.
+    fn mean(&self) -> Option<F>;
+
+    /// The variance `E[(X - μ)²]`.
+    fn variance(&self) -> Option<F>;
+
+    /// The skewness `E[(X - μ)³] / σ³`.
+    fn skewness(&self) -> Option<F>;
+
+    /// The excess kurtosis `E[(X - μ)⁴] / σ⁴ - 3`, which is zero for the
+    /// normal distribution.
+    fn excess_kurtosis(&self) -> Option<F>;
+}
+
+/// The variance given the raw moments `E[X]` and `E[X²]`.
+pub(crate) fn variance_from_raw<F: Float>(m1: F, m2: F) -> F {
+    m2 - m1 * m1
+}
+
+/// The skewness given the raw moments `E[X]`, `E[X²]` and `E[X³]`.
+pub(crate) fn skewness_from_raw<F: Float>(m1: F, m2: F, m3: F) -> F {
+    let var = variance_from_raw(m1, m2);
+    let c3 = m3 - F::from(3.0).unwrap() * m1 * m2 + F::from(2.0).unwrap() * m1 * m1 * m1;
+    c3 / (var * var.sqrt())
+}
+
+/// The excess kurtosis given the raw moments `E[X]` to `E[X⁴]`.
+pub(crate) fn excess_kurtosis_from_raw<F: Float>(m1: F, m2: F, m3: F, m4: F) -> F {
+    let var = variance_from_raw(m1, m2);
+    let m1_2 = m1 * m1;
+    let c4 = m4 - F::from(4.0).unwrap() * m1 * m3 + F::from(6.0).unwrap() * m1_2 * m2
+        - F::from(3.0).unwrap() * m1_2 * m1_2;
+    c4 / (var * var) - F::from(3.0).unwrap()
+}
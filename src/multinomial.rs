@@ -0,0 +1,330 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multinomial distribution `Multinomial(n, p)`.
+
+use crate::special::{bd0, stirlerr, LN_2PI};
+use crate::{Binomial, Distribution};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::Rng;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// The largest deviation of the sum of the probabilities from one that is
+/// accepted.
+const SUM_TOLERANCE: f64 = 1e-8;
+
+/// The [multinomial distribution](https://en.wikipedia.org/wiki/Multinomial_distribution)
+/// `Multinomial(n, p)` with `N` categories.
+///
+/// The distribution of the counts `x` of each category in `n` independent
+/// trials, each of which results in category `i` with probability `pᵢ`. Each
+/// count `xᵢ` follows the [`Binomial`] distribution `Binomial(n, pᵢ)`, and
+/// the counts sum to `n`.
+///
+/// The number of categories is a const generic parameter, so that samples are
+/// arrays `[u64; N]`; [`MultinomialDyn`] is the equivalent for a number of
+/// categories chosen at run time.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, Multinomial};
+///
+/// let multinomial = Multinomial::new(10, [0.2, 0.3, 0.5]).unwrap();
+/// let counts = multinomial.sample(&mut rand::rng());
+/// assert_eq!(counts.iter().sum::<u64>(), 10);
+/// ```
+///
+/// # Implementation details
+///
+/// The counts are sampled in turn from the conditional distribution of each
+/// count given the previous ones, which is the [`Binomial`] distribution with
+/// the remaining trials and the probability of the category relative to the
+/// categories not yet sampled. Sampling stops early once all trials are
+/// accounted for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Multinomial<const N: usize> {
+    n: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    p: [f64; N],
+}
+
+/// Error type returned from [`Multinomial::new`] and [`MultinomialDyn::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A probability is negative, infinite or `nan`.
+    ProbabilityOutOfRange,
+    /// The probabilities do not sum to one within a tolerance of `1e-8`.
+    ProbabilitySumNotOne,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ProbabilityOutOfRange => {
+                "a probability is negative or not finite in multinomial distribution"
+            }
+            Error::ProbabilitySumNotOne => {
+                "probabilities do not sum to one in multinomial distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Check the probabilities `p`, and normalize them to sum to exactly one.
+fn normalize(p: &mut [f64]) -> Result<(), Error> {
+    if !p.iter().all(|&x| x >= 0.0 && x.is_finite()) {
+        return Err(Error::ProbabilityOutOfRange);
+    }
+    let sum: f64 = p.iter().sum();
+    if !((sum - 1.0).abs() <= SUM_TOLERANCE) {
+        return Err(Error::ProbabilitySumNotOne);
+    }
+    for x in p.iter_mut() {
+        *x /= sum;
+    }
+    Ok(())
+}
+
+/// Sample the counts of `n` trials with the probabilities `p` into `counts`,
+/// which must be zero.
+fn sample_counts<R: Rng + ?Sized>(n: u64, p: &[f64], counts: &mut [u64], rng: &mut R) {
+    // The probabilities sum to one, so some are positive; the last of them
+    // takes all remaining trials, whatever the rounding of `rest`
+    let last = p.iter().rposition(|&p| p > 0.0).unwrap();
+    let mut trials = n;
+    let mut rest = 1.0;
+    for (i, (x, &p)) in counts.iter_mut().zip(p).enumerate() {
+        if trials == 0 {
+            break;
+        }
+        *x = if i == last || p >= rest {
+            trials
+        } else if p == 0.0 {
+            0
+        } else {
+            // `0 < p / rest < 1`, a valid probability
+            Binomial::new(trials, p / rest).unwrap().sample(rng)
+        };
+        trials -= *x;
+        rest -= p;
+    }
+}
+
+/// `ln P(x)` for `n` trials with the probabilities `p`.
+fn ln_pmf(n: u64, p: &[f64], x: &[u64]) -> f64 {
+    if x.len() != p.len() || x.iter().try_fold(0u64, |sum, &x| sum.checked_add(x)) != Some(n) {
+        return f64::NEG_INFINITY;
+    }
+    if n == 0 {
+        return 0.0;
+    }
+    // Loader's saddle point form, as for the binomial distribution: the terms
+    // of size `n ln n` of the factorials cancel analytically, leaving the
+    // errors of Stirling's approximation and the deviances
+    // `xᵢ ln(xᵢ / (n pᵢ)) + n pᵢ - xᵢ`, as the probabilities sum to one
+    let n = n as f64;
+    let ln_stirling = |x: f64| stirlerr(x) + 0.5 * (LN_2PI + x.ln());
+    x.iter().zip(p).fold(ln_stirling(n), |sum, (&x, &p)| {
+        if x == 0 {
+            sum - n * p
+        } else {
+            let x = x as f64;
+            sum - ln_stirling(x) - bd0(x, n * p)
+        }
+    })
+}
+
+impl<const N: usize> Multinomial<N> {
+    /// Construct a new `Multinomial` with `n` trials and the probabilities
+    /// `p` of the categories.
+    ///
+    /// Requires finite, non-negative probabilities that sum to one within a
+    /// tolerance of `1e-8`; they are normalized to sum to exactly one.
+    pub fn new(n: u64, p: [f64; N]) -> Result<Multinomial<N>, Error> {
+        let mut p = p;
+        normalize(&mut p)?;
+        Ok(Multinomial { n, p })
+    }
+
+    /// Evaluate the probability mass function at the counts `x`.
+    pub fn pmf(&self, x: &[u64; N]) -> f64 {
+        self.ln_pmf(x).exp()
+    }
+
+    /// Evaluate the natural logarithm of the probability mass function at
+    /// the counts `x`.
+    pub fn ln_pmf(&self, x: &[u64; N]) -> f64 {
+        ln_pmf(self.n, &self.p, x)
+    }
+}
+
+impl<const N: usize> Distribution<[u64; N]> for Multinomial<N> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [u64; N] {
+        let mut counts = [0; N];
+        sample_counts(self.n, &self.p, &mut counts, rng);
+        counts
+    }
+}
+
+/// The [multinomial distribution](https://en.wikipedia.org/wiki/Multinomial_distribution)
+/// `Multinomial(n, p)` with a number of categories chosen at run time.
+///
+/// This is the equivalent of [`Multinomial`] with vectors instead of arrays,
+/// with samples of type `Vec<u64>`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, MultinomialDyn};
+///
+/// let multinomial = MultinomialDyn::new(10, vec![0.2, 0.3, 0.5]).unwrap();
+/// let counts: Vec<u64> = multinomial.sample(&mut rand::rng());
+/// assert_eq!(counts.iter().sum::<u64>(), 10);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultinomialDyn {
+    n: u64,
+    p: Vec<f64>,
+}
+
+#[cfg(feature = "alloc")]
+impl MultinomialDyn {
+    /// Construct a new `MultinomialDyn` with `n` trials and the probabilities
+    /// `p` of the categories.
+    ///
+    /// Requires finite, non-negative probabilities that sum to one within a
+    /// tolerance of `1e-8`; they are normalized to sum to exactly one.
+    pub fn new(n: u64, p: Vec<f64>) -> Result<MultinomialDyn, Error> {
+        let mut p = p;
+        normalize(&mut p)?;
+        Ok(MultinomialDyn { n, p })
+    }
+
+    /// Evaluate the probability mass function at the counts `x`.
+    ///
+    /// This is zero if `x` does not have one count per category.
+    pub fn pmf(&self, x: &[u64]) -> f64 {
+        self.ln_pmf(x).exp()
+    }
+
+    /// Evaluate the natural logarithm of the probability mass function at
+    /// the counts `x`.
+    pub fn ln_pmf(&self, x: &[u64]) -> f64 {
+        ln_pmf(self.n, &self.p, x)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Distribution<Vec<u64>> for MultinomialDyn {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u64> {
+        let mut counts = vec![0; self.p.len()];
+        sample_counts(self.n, &self.p, &mut counts, rng);
+        counts
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DiscretePmf;
+
+    #[test]
+    fn test_new() {
+        assert!(Multinomial::new(5, [0.5, 0.5 + 1e-9]).is_ok());
+        assert_eq!(
+            Multinomial::new(5, [0.5, 0.4]),
+            Err(Error::ProbabilitySumNotOne)
+        );
+        assert_eq!(Multinomial::new(5, []), Err(Error::ProbabilitySumNotOne));
+        assert_eq!(
+            Multinomial::new(5, [1.5, -0.5]),
+            Err(Error::ProbabilityOutOfRange)
+        );
+        assert_eq!(
+            Multinomial::new(5, [f64::NAN, 1.0]),
+            Err(Error::ProbabilityOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_pmf() {
+        let multinomial = Multinomial::new(4, [0.25, 0.25, 0.5]).unwrap();
+        // 4! / (1! 1! 2!) (1/4)² (1/2)² = 12 / 64
+        assert_almost_eq!(multinomial.pmf(&[1, 1, 2]), 12.0 / 64.0, 1e-15);
+        assert_almost_eq!(multinomial.pmf(&[0, 0, 4]), 1.0 / 16.0, 1e-15);
+        assert_eq!(multinomial.pmf(&[1, 1, 1]), 0.0);
+        assert_eq!(multinomial.pmf(&[u64::MAX, 1, 0]), 0.0);
+
+        // Accurate for many trials, like the binomial distribution
+        let n = 1_000_000_000_000;
+        let multinomial = Multinomial::new(n, [0.3, 0.7]).unwrap();
+        let binomial = Binomial::new(n, 0.3).unwrap();
+        for &x in &[0, 299_999_000_000, 300_000_000_000, 300_000_500_000, n] {
+            let e = binomial.ln_pmf(x);
+            assert_almost_eq!(multinomial.ln_pmf(&[x, n - x]), e, 1e-14 * e.abs().max(1.0));
+        }
+
+        let multinomial = Multinomial::new(3, [0.0, 1.0]).unwrap();
+        assert_eq!(multinomial.pmf(&[0, 3]), 1.0);
+        assert_eq!(multinomial.pmf(&[1, 2]), 0.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        let p = [0.1, 0.0, 0.6, 0.3];
+        let multinomial = Multinomial::new(20, p).unwrap();
+        let mut rng = crate::test::rng(247);
+        const SAMPLES: usize = 10_000;
+        let mut sum = [0u64; 4];
+        for _ in 0..SAMPLES {
+            let counts = multinomial.sample(&mut rng);
+            assert_eq!(counts.iter().sum::<u64>(), 20);
+            assert_eq!(counts[1], 0);
+            for (s, x) in sum.iter_mut().zip(counts.iter()) {
+                *s += x;
+            }
+        }
+        for (&s, &p) in sum.iter().zip(p.iter()) {
+            assert_almost_eq!(s as f64 / SAMPLES as f64, 20.0 * p, 0.05);
+        }
+
+        let multinomial = Multinomial::new(5, [0.0, 0.0, 1.0]).unwrap();
+        assert_eq!(multinomial.sample(&mut rng), [0, 0, 5]);
+        let multinomial = Multinomial::new(0, [0.5, 0.5]).unwrap();
+        assert_eq!(multinomial.sample(&mut rng), [0, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_dyn() {
+        let multinomial = MultinomialDyn::new(4, vec![0.25, 0.25, 0.5]).unwrap();
+        assert_almost_eq!(multinomial.pmf(&[1, 1, 2]), 12.0 / 64.0, 1e-15);
+        assert_eq!(multinomial.pmf(&[1, 3]), 0.0);
+        let counts = multinomial.sample(&mut crate::test::rng(248));
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts.iter().sum::<u64>(), 4);
+        assert_eq!(
+            MultinomialDyn::new(4, vec![0.5]),
+            Err(Error::ProbabilitySumNotOne)
+        );
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multivariate hypergeometric distribution.
+
+use crate::special::ln_beta;
+use crate::{Distribution, Hypergeometric};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::Rng;
+
+/// The [multivariate hypergeometric distribution](https://en.wikipedia.org/wiki/Hypergeometric_distribution#Multivariate_hypergeometric_distribution)
+/// with `N` colours.
+///
+/// The counts of each colour in a sample of size `n` drawn without
+/// replacement from a population holding `Kᵢ` items of colour `i`, such as
+/// the cards of each suit in a hand. Each count `xᵢ` follows the
+/// [`Hypergeometric`] distribution `Hypergeometric(∑ K, Kᵢ, n)`, and the
+/// counts sum to `n`. See the [`Multinomial`](crate::Multinomial)
+/// distribution for the analogous distribution for sampling with replacement.
+///
+/// # Density function
+///
+/// `f(x) = ∏ binomial(Kᵢ, xᵢ) / binomial(∑ K, n)` for counts `xᵢ <= Kᵢ`
+/// that sum to `n`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, MultivariateHypergeometric};
+///
+/// // A hand of 13 cards by suit
+/// let hand = MultivariateHypergeometric::new([13, 13, 13, 13], 13).unwrap();
+/// let suits = hand.sample(&mut rand::rng());
+/// assert_eq!(suits.iter().sum::<u64>(), 13);
+/// ```
+///
+/// # Implementation details
+///
+/// The counts are sampled in turn from the conditional distribution of each
+/// count given the previous ones, which is the [`Hypergeometric`]
+/// distribution with the remaining population and sample size, so that the
+/// sampler of [`Hypergeometric`] is used for each colour. Sampling stops
+/// early once the sample is complete.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultivariateHypergeometric<const N: usize> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    counts: [u64; N],
+    total: u64,
+    sample_size: u64,
+}
+
+/// Error type returned from [`MultivariateHypergeometric::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The total population size overflows `u64`.
+    PopulationTooLarge,
+    /// `sample_size` exceeds the total population size.
+    SampleSizeTooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::PopulationTooLarge => {
+                "total population size overflows in multivariate hypergeometric distribution"
+            }
+            Error::SampleSizeTooLarge => {
+                "sample_size > total population size in multivariate hypergeometric distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// `ln binomial(n, k)` for `k <= n`.
+fn ln_binomial_coefficient(n: u64, k: u64) -> f64 {
+    let (n, k) = (n as f64, k as f64);
+    -(n + 1.0).ln() - ln_beta(k + 1.0, n - k + 1.0)
+}
+
+impl<const N: usize> MultivariateHypergeometric<N> {
+    /// Construct a new `MultivariateHypergeometric` with the numbers of
+    /// items `counts` of each colour in the population and the size
+    /// `sample_size` of the sample.
+    ///
+    /// Requires the total population size to fit in `u64`, and
+    /// `sample_size` not to exceed it.
+    pub fn new(counts: [u64; N], sample_size: u64) -> Result<Self, Error> {
+        let total = counts
+            .iter()
+            .try_fold(0u64, |sum, &k| sum.checked_add(k))
+            .ok_or(Error::PopulationTooLarge)?;
+        if sample_size > total {
+            return Err(Error::SampleSizeTooLarge);
+        }
+        Ok(MultivariateHypergeometric {
+            counts,
+            total,
+            sample_size,
+        })
+    }
+
+    /// Evaluate the probability mass function at the counts `x`.
+    pub fn pmf(&self, x: &[u64; N]) -> f64 {
+        self.ln_pmf(x).exp()
+    }
+
+    /// Evaluate the natural logarithm of the probability mass function at
+    /// the counts `x`.
+    pub fn ln_pmf(&self, x: &[u64; N]) -> f64 {
+        if x.iter().zip(self.counts.iter()).any(|(x, k)| x > k)
+            || x.iter().sum::<u64>() != self.sample_size
+        {
+            return f64::NEG_INFINITY;
+        }
+        x.iter().zip(self.counts.iter()).fold(
+            -ln_binomial_coefficient(self.total, self.sample_size),
+            |sum, (&x, &k)| sum + ln_binomial_coefficient(k, x),
+        )
+    }
+}
+
+impl<const N: usize> Distribution<[u64; N]> for MultivariateHypergeometric<N> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [u64; N] {
+        let mut x = [0; N];
+        let mut population = self.total;
+        let mut draws = self.sample_size;
+        for (x, &k) in x.iter_mut().zip(self.counts.iter()) {
+            if draws == 0 {
+                break;
+            }
+            // The last colours take the remaining draws exactly
+            *x = if k == population {
+                draws
+            } else {
+                Hypergeometric::new(population, k, draws)
+                    .unwrap()
+                    .sample(rng)
+            };
+            population -= k;
+            draws -= *x;
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DiscretePmf;
+
+    #[test]
+    fn test_new() {
+        assert!(MultivariateHypergeometric::new([5, 3, 2], 10).is_ok());
+        assert!(MultivariateHypergeometric::new([], 0).is_ok());
+        assert_eq!(
+            MultivariateHypergeometric::new([5, 3, 2], 11),
+            Err(Error::SampleSizeTooLarge)
+        );
+        assert_eq!(
+            MultivariateHypergeometric::new([u64::MAX, 1], 1),
+            Err(Error::PopulationTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_pmf() {
+        let mh = MultivariateHypergeometric::new([5, 3, 2], 4).unwrap();
+        // binomial(5, 2) binomial(3, 1) binomial(2, 1) / binomial(10, 4)
+        assert_almost_eq!(mh.pmf(&[2, 1, 1]), 60.0 / 210.0, 1e-14);
+        assert_almost_eq!(mh.pmf(&[4, 0, 0]), 5.0 / 210.0, 1e-14);
+        assert_eq!(mh.pmf(&[1, 0, 3]), 0.0);
+        assert_eq!(mh.pmf(&[1, 1, 1]), 0.0);
+
+        // With two colours, the hypergeometric distribution
+        let mh = MultivariateHypergeometric::new([24, 36], 7).unwrap();
+        let hypergeo = Hypergeometric::new(60, 24, 7).unwrap();
+        for k in 0..=7 {
+            assert_almost_eq!(mh.pmf(&[k, 7 - k]), hypergeo.pmf(k), 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let counts = [13, 0, 26, 13];
+        let mh = MultivariateHypergeometric::new(counts, 13).unwrap();
+        let mut rng = crate::test::rng(253);
+        const SAMPLES: usize = 10_000;
+        let mut sum = [0u64; 4];
+        for _ in 0..SAMPLES {
+            let x = mh.sample(&mut rng);
+            assert_eq!(x.iter().sum::<u64>(), 13);
+            assert_eq!(x[1], 0);
+            for (s, x) in sum.iter_mut().zip(x.iter()) {
+                *s += x;
+            }
+        }
+        for (&s, &k) in sum.iter().zip(counts.iter()) {
+            assert_almost_eq!(s as f64 / SAMPLES as f64, 13.0 * k as f64 / 52.0, 0.05);
+        }
+
+        // The whole population
+        let mh = MultivariateHypergeometric::new([3, 4, 5], 12).unwrap();
+        assert_eq!(mh.sample(&mut rng), [3, 4, 5]);
+        let mh = MultivariateHypergeometric::new([3, 4, 5], 0).unwrap();
+        assert_eq!(mh.sample(&mut rng), [0, 0, 0]);
+    }
+}
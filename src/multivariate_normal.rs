@@ -0,0 +1,464 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multivariate normal distribution `N(μ, Σ)`.
+
+use crate::linalg::{self, cholesky, invert_lower, lower_gram, lower_mul_add, MatrixError};
+use crate::{Distribution, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// The [multivariate normal distribution](https://en.wikipedia.org/wiki/Multivariate_normal_distribution)
+/// `N(μ, Σ)` in `N` dimensions.
+///
+/// The distribution of a vector `μ + L Z`, where the components of `Z` are
+/// independent samples of [`StandardNormal`] and `L` is the Cholesky factor
+/// of the covariance matrix `Σ = L Lᵀ`. Its components are normally
+/// distributed with means `μ` and are correlated with covariances `Σ`.
+///
+/// The dimension is a const generic parameter, so that samples are arrays
+/// `[F; N]`; [`MultivariateNormalDyn`] is the equivalent for a dimension
+/// chosen at run time.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, MultivariateNormal};
+///
+/// // Two standard normal variables with a correlation of 0.5
+/// let mvn = MultivariateNormal::new([0.0, 0.0], [[1.0, 0.5], [0.5, 1.0]]).unwrap();
+/// let [x, y] = mvn.sample(&mut rand::rng());
+/// println!("({}, {}) is from a multivariate normal distribution", x, y);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "F: serde::Serialize")))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "F: serde::Deserialize<'de>"))
+)]
+pub struct MultivariateNormal<F, const N: usize>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    mean: [F; N],
+    /// The Cholesky factor of the covariance matrix
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[[serde_with::Same; N]; N]>")
+    )]
+    factor: [[F; N]; N],
+}
+
+/// Error type returned from [`MultivariateNormal`] and
+/// [`MultivariateNormalDyn`] constructors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The dimension is zero, or the matrix is not square with one row per
+    /// component of the mean.
+    BadDimension,
+    /// The mean or the matrix contains a value that is infinite or `nan`.
+    NotFinite,
+    /// The matrix is not symmetric.
+    NotSymmetric,
+    /// The matrix is not positive-definite.
+    NotPositiveDefinite,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::BadDimension => "dimensions do not match in multivariate normal distribution",
+            Error::NotFinite => "non-finite parameter in multivariate normal distribution",
+            Error::NotSymmetric => "matrix is not symmetric in multivariate normal distribution",
+            Error::NotPositiveDefinite => {
+                "matrix is not positive-definite in multivariate normal distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<MatrixError> for Error {
+    fn from(error: MatrixError) -> Self {
+        match error {
+            MatrixError::Infinite => Error::NotFinite,
+            MatrixError::Asymmetric => Error::NotSymmetric,
+            MatrixError::Indefinite => Error::NotPositiveDefinite,
+        }
+    }
+}
+
+/// Validate the mean and the symmetric matrix `a` and replace `a` by its
+/// Cholesky factor.
+fn factorize<F: Float, R: AsRef<[F]> + AsMut<[F]>>(mean: &[F], a: &mut [R]) -> Result<(), Error> {
+    if mean.is_empty()
+        || a.len() != mean.len()
+        || a.iter().any(|row| row.as_ref().len() != mean.len())
+    {
+        return Err(Error::BadDimension);
+    }
+    if !mean.iter().all(|x| x.is_finite()) {
+        return Err(Error::NotFinite);
+    }
+    Ok(linalg::factorize(a)?)
+}
+
+/// Validate the mean and the precision matrix `a` and write the Cholesky
+/// factor of its inverse, the covariance matrix, into `factor`.
+fn factorize_precision<F, R, S>(mean: &[F], a: &mut [R], factor: &mut [S]) -> Result<(), Error>
+where
+    F: Float,
+    R: AsRef<[F]> + AsMut<[F]>,
+    S: AsMut<[F]>,
+{
+    factorize(mean, a)?;
+    // With `P = L Lᵀ`, the covariance matrix is `L⁻ᵀ L⁻¹`
+    invert_lower(a);
+    lower_gram(a, factor);
+    if !cholesky(factor) {
+        return Err(Error::NotPositiveDefinite);
+    }
+    Ok(())
+}
+
+impl<F, const N: usize> MultivariateNormal<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    /// Construct a new `MultivariateNormal` with the given `mean` and
+    /// `covariance` matrix.
+    ///
+    /// Requires `N >= 1`, finite parameters and a symmetric, positive-definite
+    /// covariance matrix, which is checked by computing its Cholesky
+    /// decomposition.
+    pub fn new(mean: [F; N], covariance: [[F; N]; N]) -> Result<MultivariateNormal<F, N>, Error> {
+        let mut factor = covariance;
+        factorize(&mean, &mut factor[..])?;
+        Ok(MultivariateNormal { mean, factor })
+    }
+
+    /// Construct a new `MultivariateNormal` with the given `mean` and
+    /// `precision` matrix, the inverse of the covariance matrix.
+    ///
+    /// Requires `N >= 1`, finite parameters and a symmetric, positive-definite
+    /// precision matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::MultivariateNormal;
+    ///
+    /// let precision = [[4.0 / 3.0, -2.0 / 3.0], [-2.0 / 3.0, 4.0 / 3.0]];
+    /// let mvn: MultivariateNormal<f64, 2> =
+    ///     MultivariateNormal::from_precision([1.0, 2.0], precision).unwrap();
+    /// let covariance = mvn.covariance();
+    /// assert!((covariance[0][1] - 0.5).abs() < 1e-14);
+    /// ```
+    pub fn from_precision(
+        mean: [F; N],
+        precision: [[F; N]; N],
+    ) -> Result<MultivariateNormal<F, N>, Error> {
+        let mut a = precision;
+        let mut factor = [[F::zero(); N]; N];
+        factorize_precision(&mean, &mut a[..], &mut factor[..])?;
+        Ok(MultivariateNormal { mean, factor })
+    }
+
+    /// Returns the mean `μ`.
+    pub fn mean(&self) -> [F; N] {
+        self.mean
+    }
+
+    /// Returns the covariance matrix `Σ`.
+    pub fn covariance(&self) -> [[F; N]; N] {
+        let mut covariance = [[F::zero(); N]; N];
+        for (i, row) in covariance.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                let len = i.min(j) + 1;
+                *x = (0..len).fold(F::zero(), |sum, k| {
+                    sum + self.factor[i][k] * self.factor[j][k]
+                });
+            }
+        }
+        covariance
+    }
+}
+
+impl<F, const N: usize> Distribution<[F; N]> for MultivariateNormal<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; N] {
+        let z: [F; N] = core::array::from_fn(|_| rng.sample(StandardNormal));
+        let mut x = [F::zero(); N];
+        lower_mul_add(&self.factor[..], &z, &self.mean, &mut x);
+        x
+    }
+}
+
+/// The [multivariate normal distribution](https://en.wikipedia.org/wiki/Multivariate_normal_distribution)
+/// `N(μ, Σ)` in a dimension chosen at run time.
+///
+/// This is the equivalent of [`MultivariateNormal`] with vectors instead of
+/// arrays, with samples of type `Vec<F>`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, MultivariateNormalDyn};
+///
+/// let covariance = vec![vec![1.0, 0.5], vec![0.5, 1.0]];
+/// let mvn = MultivariateNormalDyn::new(vec![0.0, 0.0], covariance).unwrap();
+/// let v: Vec<f64> = mvn.sample(&mut rand::rng());
+/// assert_eq!(v.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultivariateNormalDyn<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    mean: Vec<F>,
+    /// The Cholesky factor of the covariance matrix
+    factor: Vec<Vec<F>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<F> MultivariateNormalDyn<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    /// Construct a new `MultivariateNormalDyn` with the given `mean` and
+    /// `covariance` matrix, given as a vector of rows.
+    ///
+    /// Requires a nonempty mean, a square matrix with one row per component
+    /// of the mean, finite parameters and a symmetric, positive-definite
+    /// covariance matrix.
+    pub fn new(mean: Vec<F>, covariance: Vec<Vec<F>>) -> Result<MultivariateNormalDyn<F>, Error> {
+        let mut factor = covariance;
+        factorize(&mean, &mut factor)?;
+        Ok(MultivariateNormalDyn { mean, factor })
+    }
+
+    /// Construct a new `MultivariateNormalDyn` with the given `mean` and
+    /// `precision` matrix, the inverse of the covariance matrix, given as a
+    /// vector of rows.
+    ///
+    /// Requires a nonempty mean, a square matrix with one row per component
+    /// of the mean, finite parameters and a symmetric, positive-definite
+    /// precision matrix.
+    pub fn from_precision(
+        mean: Vec<F>,
+        precision: Vec<Vec<F>>,
+    ) -> Result<MultivariateNormalDyn<F>, Error> {
+        let mut a = precision;
+        let mut factor = vec![vec![F::zero(); mean.len()]; mean.len()];
+        factorize_precision(&mean, &mut a, &mut factor)?;
+        Ok(MultivariateNormalDyn { mean, factor })
+    }
+
+    /// Returns the dimension.
+    pub fn dim(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Returns the mean `μ`.
+    pub fn mean(&self) -> &[F] {
+        &self.mean
+    }
+
+    /// Returns the covariance matrix `Σ`, as a vector of rows.
+    pub fn covariance(&self) -> Vec<Vec<F>> {
+        let n = self.dim();
+        let mut covariance = vec![vec![F::zero(); n]; n];
+        for (i, row) in covariance.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                let len = i.min(j) + 1;
+                *x = (0..len).fold(F::zero(), |sum, k| {
+                    sum + self.factor[i][k] * self.factor[j][k]
+                });
+            }
+        }
+        covariance
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F> Distribution<Vec<F>> for MultivariateNormalDyn<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<F> {
+        let z: Vec<F> = (0..self.dim())
+            .map(|_| rng.sample(StandardNormal))
+            .collect();
+        let mut x = vec![F::zero(); self.dim()];
+        lower_mul_add(&self.factor, &z, &self.mean, &mut x);
+        x
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let mvn = MultivariateNormal::new(
+            [1.0, 2.0, 3.0],
+            [[4.0, 2.0, -2.0], [2.0, 10.0, 5.0], [-2.0, 5.0, 9.0]],
+        )
+        .unwrap();
+        assert_eq!(
+            mvn.factor,
+            [[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [-1.0, 2.0, 2.0]]
+        );
+        assert_eq!(mvn.mean(), [1.0, 2.0, 3.0]);
+        assert_eq!(
+            mvn.covariance(),
+            [[4.0, 2.0, -2.0], [2.0, 10.0, 5.0], [-2.0, 5.0, 9.0]]
+        );
+
+        assert_eq!(
+            MultivariateNormal::<f64, 0>::new([], []),
+            Err(Error::BadDimension)
+        );
+        assert_eq!(
+            MultivariateNormal::new([f64::NAN], [[1.0]]),
+            Err(Error::NotFinite)
+        );
+        assert_eq!(
+            MultivariateNormal::new([0.0], [[f64::INFINITY]]),
+            Err(Error::NotFinite)
+        );
+        assert_eq!(
+            MultivariateNormal::new([0.0, 0.0], [[1.0, 0.5], [0.4, 1.0]]),
+            Err(Error::NotSymmetric)
+        );
+        assert_eq!(
+            MultivariateNormal::new([0.0, 0.0], [[1.0, 2.0], [2.0, 1.0]]),
+            Err(Error::NotPositiveDefinite)
+        );
+        assert_eq!(
+            MultivariateNormal::new([0.0, 0.0], [[1.0, 1.0], [1.0, 1.0]]),
+            Err(Error::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn test_from_precision() {
+        let covariance = [[4.0, 2.0, -2.0], [2.0, 10.0, 5.0], [-2.0, 5.0, 9.0]];
+        // The inverse of `covariance`, whose determinant is 144
+        let precision = [
+            [65.0, -28.0, 30.0],
+            [-28.0, 32.0, -24.0],
+            [30.0, -24.0, 36.0],
+        ]
+        .map(|row| row.map(|x: f64| x / 144.0));
+        let mvn = MultivariateNormal::from_precision([0.0; 3], precision).unwrap();
+        let expected = MultivariateNormal::new([0.0; 3], covariance).unwrap();
+        for (row, expected_row) in mvn.factor.iter().zip(expected.factor.iter()) {
+            for (&x, &y) in row.iter().zip(expected_row.iter()) {
+                assert_almost_eq!(x, y, 1e-14);
+            }
+        }
+
+        assert_eq!(
+            MultivariateNormal::from_precision([0.0, 0.0], [[1.0, 0.0], [0.0, -1.0]]),
+            Err(Error::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let mean = [1.0, -1.0];
+        let covariance = [[2.0, -1.2], [-1.2, 1.0]];
+        let mvn = MultivariateNormal::new(mean, covariance).unwrap();
+        let mut rng = crate::test::rng(237);
+        const SAMPLES: usize = 100_000;
+        let mut sum = [0.0; 2];
+        let mut sum_sq = [[0.0; 2]; 2];
+        for _ in 0..SAMPLES {
+            let x = mvn.sample(&mut rng);
+            for i in 0..2 {
+                sum[i] += x[i];
+                for j in 0..2 {
+                    sum_sq[i][j] += (x[i] - mean[i]) * (x[j] - mean[j]);
+                }
+            }
+        }
+        for i in 0..2 {
+            assert_almost_eq!(sum[i] / SAMPLES as f64, mean[i], 0.02);
+            for j in 0..2 {
+                assert_almost_eq!(sum_sq[i][j] / SAMPLES as f64, covariance[i][j], 0.03);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dyn() {
+        use alloc::vec;
+
+        let covariance = [[4.0, 2.0, -2.0], [2.0, 10.0, 5.0], [-2.0, 5.0, 9.0]];
+        let mvn = MultivariateNormalDyn::new(
+            vec![1.0, 2.0, 3.0],
+            covariance.iter().map(|row| row.to_vec()).collect(),
+        )
+        .unwrap();
+        let fixed = MultivariateNormal::new([1.0, 2.0, 3.0], covariance).unwrap();
+        assert_eq!(mvn.dim(), 3);
+        assert_eq!(
+            mvn.covariance(),
+            covariance.map(|row| row.to_vec()).to_vec()
+        );
+
+        // The same normal draws give the same sample
+        let x = mvn.sample(&mut crate::test::rng(238));
+        assert_eq!(x, fixed.sample(&mut crate::test::rng(238)).to_vec());
+
+        let precision = vec![vec![0.25, 0.0], vec![0.0, 0.0625]];
+        let mvn = MultivariateNormalDyn::from_precision(vec![0.0, 0.0], precision).unwrap();
+        assert_eq!(mvn.covariance(), vec![vec![4.0, 0.0], vec![0.0, 16.0]]);
+
+        assert_eq!(
+            MultivariateNormalDyn::<f64>::new(vec![], vec![]),
+            Err(Error::BadDimension)
+        );
+        assert_eq!(
+            MultivariateNormalDyn::new(vec![0.0, 0.0], vec![vec![1.0, 0.0]]),
+            Err(Error::BadDimension)
+        );
+        assert_eq!(
+            MultivariateNormalDyn::new(vec![0.0, 0.0], vec![vec![1.0, 0.0], vec![0.0]]),
+            Err(Error::BadDimension)
+        );
+        assert_eq!(
+            MultivariateNormalDyn::new(vec![0.0], vec![vec![-1.0]]),
+            Err(Error::NotPositiveDefinite)
+        );
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multivariate skew normal distribution `SN(ξ, Ω, α)`.
+
+use crate::linalg::{factorize, lower_mul_add, MatrixError};
+use crate::{Distribution, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+
+/// The [multivariate skew normal distribution](https://en.wikipedia.org/wiki/Skew_normal_distribution)
+/// `SN(ξ, Ω, α)` of Azzalini and Dalla Valle in `N` dimensions.
+///
+/// The distribution has the density `2 φ(x - ξ; Ω) Φ(αᵀ ω⁻¹ (x - ξ))`, where
+/// `φ(·; Ω)` is the density of the
+/// [`MultivariateNormal`](crate::MultivariateNormal) distribution with mean
+/// zero and covariance matrix `Ω`, `Φ` is the standard normal distribution
+/// function and `ω` is the diagonal matrix of the standard deviations
+/// `√Ωᵢᵢ`. It has location `ξ`, scale matrix `Ω` and shape `α`; with
+/// `α = 0`, it is the multivariate normal distribution `N(ξ, Ω)`, and each
+/// component follows a [`SkewNormal`](crate::SkewNormal) distribution.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, MultivariateSkewNormal};
+///
+/// let scale = [[1.0, 0.5], [0.5, 1.0]];
+/// let sn = MultivariateSkewNormal::new([0.0, 0.0], scale, [4.0, -1.0]).unwrap();
+/// let [x, y] = sn.sample(&mut rand::rng());
+/// println!("({}, {}) is from a multivariate skew normal distribution", x, y);
+/// ```
+///
+/// # Implementation details
+///
+/// Samples are generated by reflection: for `Y`, following the multivariate
+/// normal distribution with mean zero and covariance matrix `Ω`, and an
+/// independent `U`, following the standard normal distribution, samples are
+/// `ξ + Y` if `U <= αᵀ ω⁻¹ Y`, and `ξ - Y` otherwise. This only needs the
+/// Cholesky factor of `Ω`, so that any finite shape is accepted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "F: serde::Serialize")))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "F: serde::Deserialize<'de>"))
+)]
+pub struct MultivariateSkewNormal<F, const N: usize>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    location: [F; N],
+    /// `ω⁻¹ α`
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    weights: [F; N],
+    /// The Cholesky factor of `Ω`
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[[serde_with::Same; N]; N]>")
+    )]
+    factor: [[F; N]; N],
+}
+
+/// Error type returned from [`MultivariateSkewNormal::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `N == 0`.
+    BadDimension,
+    /// The location, the scale matrix or the shape contains a value that is
+    /// infinite or `nan`.
+    NotFinite,
+    /// The scale matrix is not symmetric.
+    NotSymmetric,
+    /// The scale matrix is not positive-definite.
+    NotPositiveDefinite,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::BadDimension => "zero dimensions in multivariate skew normal distribution",
+            Error::NotFinite => "non-finite parameter in multivariate skew normal distribution",
+            Error::NotSymmetric => {
+                "scale matrix is not symmetric in multivariate skew normal distribution"
+            }
+            Error::NotPositiveDefinite => {
+                "scale matrix is not positive-definite in multivariate skew normal distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<MatrixError> for Error {
+    fn from(error: MatrixError) -> Self {
+        match error {
+            MatrixError::Infinite => Error::NotFinite,
+            MatrixError::Asymmetric => Error::NotSymmetric,
+            MatrixError::Indefinite => Error::NotPositiveDefinite,
+        }
+    }
+}
+
+impl<F, const N: usize> MultivariateSkewNormal<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    /// Construct a new `MultivariateSkewNormal` with the given `location`,
+    /// `scale` matrix and `shape`.
+    ///
+    /// Requires `N >= 1`, finite parameters and a symmetric, positive-definite
+    /// scale matrix, which is checked by computing its Cholesky
+    /// decomposition. The shape may be arbitrarily large.
+    pub fn new(
+        location: [F; N],
+        scale: [[F; N]; N],
+        shape: [F; N],
+    ) -> Result<MultivariateSkewNormal<F, N>, Error> {
+        if N == 0 {
+            return Err(Error::BadDimension);
+        }
+        if !location.iter().chain(shape.iter()).all(|x| x.is_finite()) {
+            return Err(Error::NotFinite);
+        }
+        let mut factor = scale;
+        factorize(&mut factor[..])?;
+        // The diagonal is positive for a positive-definite scale matrix
+        let weights = core::array::from_fn(|i| shape[i] / scale[i][i].sqrt());
+        Ok(MultivariateSkewNormal {
+            location,
+            weights,
+            factor,
+        })
+    }
+}
+
+impl<F, const N: usize> Distribution<[F; N]> for MultivariateSkewNormal<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; N] {
+        let u: F = rng.sample(StandardNormal);
+        let z: [F; N] = core::array::from_fn(|_| rng.sample(StandardNormal));
+        let mut y = [F::zero(); N];
+        lower_mul_add(&self.factor[..], &z, &[F::zero(); N], &mut y);
+        let t = (0..N).fold(F::zero(), |sum, i| sum + self.weights[i] * y[i]);
+        let sign = if u <= t { F::one() } else { -F::one() };
+        core::array::from_fn(|i| self.location[i] + sign * y[i])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // Without skew, this is the multivariate normal distribution
+        let sn =
+            MultivariateSkewNormal::new([1.0, 2.0], [[4.0, 2.0], [2.0, 10.0]], [0.0, 0.0]).unwrap();
+        assert_eq!(sn.weights, [0.0, 0.0]);
+        assert_eq!(sn.factor, [[2.0, 0.0], [1.0, 3.0]]);
+
+        let scale = [[1.0, 0.0], [0.0, 1.0]];
+        assert_eq!(
+            MultivariateSkewNormal::<f64, 0>::new([], [], []),
+            Err(Error::BadDimension)
+        );
+        assert_eq!(
+            MultivariateSkewNormal::new([0.0, 0.0], scale, [f64::INFINITY, 0.0]),
+            Err(Error::NotFinite)
+        );
+        assert_eq!(
+            MultivariateSkewNormal::new([0.0, 0.0], [[1.0, 0.1], [0.0, 1.0]], [0.0, 0.0]),
+            Err(Error::NotSymmetric)
+        );
+        assert_eq!(
+            MultivariateSkewNormal::new([0.0, 0.0], [[1.0, 2.0], [2.0, 1.0]], [0.0, 0.0]),
+            Err(Error::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let location = [1.0, -1.0];
+        let scale = [[4.0, -1.2], [-1.2, 1.0]];
+        let shape = [3.0, 1.0];
+        let sn = MultivariateSkewNormal::new(location, scale, shape).unwrap();
+
+        // The mean is `ξ + √(2 / π) ω δ` and the covariance matrix
+        // `Ω - (2 / π) ω δ δᵀ ω`, where here
+        // `δ = [2.4, -0.8] / √(1 + 6.4)`
+        let norm = 7.4f64.sqrt();
+        let skew = [2.0 * 2.4 / norm, -0.8 / norm];
+        assert_eq!(sn.weights, [1.5, 1.0]);
+        let c = 2.0 / core::f64::consts::PI;
+        let mean = [
+            location[0] + c.sqrt() * skew[0],
+            location[1] + c.sqrt() * skew[1],
+        ];
+
+        let mut rng = crate::test::rng(240);
+        const SAMPLES: usize = 100_000;
+        let mut sum = [0.0; 2];
+        let mut sum_sq = [[0.0; 2]; 2];
+        for _ in 0..SAMPLES {
+            let x = sn.sample(&mut rng);
+            for i in 0..2 {
+                sum[i] += x[i];
+                for j in 0..2 {
+                    sum_sq[i][j] += (x[i] - mean[i]) * (x[j] - mean[j]);
+                }
+            }
+        }
+        for i in 0..2 {
+            assert_almost_eq!(sum[i] / SAMPLES as f64, mean[i], 0.02);
+            for j in 0..2 {
+                let covariance = scale[i][j] - c * skew[i] * skew[j];
+                assert_almost_eq!(sum_sq[i][j] / SAMPLES as f64, covariance, 0.05);
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_shape() {
+        // `δᵀ Ω̄⁻¹ δ` rounds to 1, and `Ω - ω δ δᵀ ω` is numerically singular
+        let location = [1.0, -1.0];
+        let scale = [[4.0, -1.2], [-1.2, 1.0]];
+        let sn = MultivariateSkewNormal::new(location, scale, [1e12, 1e12]).unwrap();
+
+        // In the limit, `αᵀ ω⁻¹ (x - ξ)` is never negative, and
+        // `δ = Ω̄ α / √(αᵀ Ω̄ α) = [1, 1] / √5`
+        let c = (2.0 / core::f64::consts::PI).sqrt();
+        let mean = [
+            location[0] + c * 2.0 / 5f64.sqrt(),
+            location[1] + c / 5f64.sqrt(),
+        ];
+        let mut rng = crate::test::rng(241);
+        const SAMPLES: usize = 100_000;
+        let mut sum = [0.0; 2];
+        for _ in 0..SAMPLES {
+            let x = sn.sample(&mut rng);
+            assert!((x[0] - location[0]) / 2.0 + (x[1] - location[1]) >= 0.0);
+            sum[0] += x[0];
+            sum[1] += x[1];
+        }
+        for i in 0..2 {
+            assert_almost_eq!(sum[i] / SAMPLES as f64, mean[i], 0.02);
+        }
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multivariate Student's t-distribution `t_ν(μ, Σ)`.
+
+use crate::linalg::{factorize, lower_mul_add, MatrixError};
+use crate::{ChiSquared, Distribution, Exp1, Open01, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+
+/// The [multivariate Student's t-distribution](https://en.wikipedia.org/wiki/Multivariate_t-distribution)
+/// `t_ν(μ, Σ)` in `N` dimensions.
+///
+/// The distribution of `μ + L Z / √(W / ν)`, where the components of `Z` are
+/// independent samples of [`StandardNormal`], `L` is the Cholesky factor of
+/// the scale matrix `Σ = L Lᵀ`, and `W` follows the
+/// [`ChiSquared`] distribution with `ν` degrees of freedom. Each component
+/// follows a scaled and shifted [`StudentT`](crate::StudentT) distribution,
+/// and the components are dependent even when uncorrelated, as they share
+/// the factor `W`, which makes joint extremes more likely than for the
+/// [`MultivariateNormal`](crate::MultivariateNormal) distribution.
+///
+/// The mean is `μ` for `ν > 1`, and the covariance matrix `ν / (ν - 2) Σ`
+/// for `ν > 2`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, MultivariateStudentT};
+///
+/// let t = MultivariateStudentT::new([0.0, 0.0], [[1.0, 0.5], [0.5, 1.0]], 4.0).unwrap();
+/// let [x, y] = t.sample(&mut rand::rng());
+/// println!("({}, {}) is from a multivariate t-distribution", x, y);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "F: serde::Serialize")))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "F: serde::Deserialize<'de>"))
+)]
+pub struct MultivariateStudentT<F, const N: usize>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    location: [F; N],
+    /// The Cholesky factor of the scale matrix
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[[serde_with::Same; N]; N]>")
+    )]
+    factor: [[F; N]; N],
+    chi: ChiSquared<F>,
+    dof: F,
+}
+
+/// Error type returned from [`MultivariateStudentT::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `N == 0`.
+    BadDimension,
+    /// The location or the scale matrix contains a value that is infinite or
+    /// `nan`.
+    NotFinite,
+    /// The scale matrix is not symmetric.
+    NotSymmetric,
+    /// The scale matrix is not positive-definite.
+    NotPositiveDefinite,
+    /// `dof <= 0`, infinite or `nan`.
+    DofOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::BadDimension => "zero dimensions in multivariate t-distribution",
+            Error::NotFinite => "non-finite parameter in multivariate t-distribution",
+            Error::NotSymmetric => "scale matrix is not symmetric in multivariate t-distribution",
+            Error::NotPositiveDefinite => {
+                "scale matrix is not positive-definite in multivariate t-distribution"
+            }
+            Error::DofOutOfRange => {
+                "degrees of freedom are not positive and finite in multivariate t-distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<MatrixError> for Error {
+    fn from(error: MatrixError) -> Self {
+        match error {
+            MatrixError::Infinite => Error::NotFinite,
+            MatrixError::Asymmetric => Error::NotSymmetric,
+            MatrixError::Indefinite => Error::NotPositiveDefinite,
+        }
+    }
+}
+
+impl<F, const N: usize> MultivariateStudentT<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `MultivariateStudentT` with the given `location`,
+    /// `scale` matrix and degrees of freedom `dof`.
+    ///
+    /// Requires `N >= 1`, finite parameters, a symmetric, positive-definite
+    /// scale matrix, which is checked by computing its Cholesky
+    /// decomposition, and finite `dof > 0`.
+    pub fn new(
+        location: [F; N],
+        scale: [[F; N]; N],
+        dof: F,
+    ) -> Result<MultivariateStudentT<F, N>, Error> {
+        if N == 0 {
+            return Err(Error::BadDimension);
+        }
+        if !location.iter().all(|x| x.is_finite()) {
+            return Err(Error::NotFinite);
+        }
+        let mut factor = scale;
+        factorize(&mut factor[..])?;
+        if !(dof > F::zero() && dof.is_finite()) {
+            return Err(Error::DofOutOfRange);
+        }
+        let chi = ChiSquared::new(dof).map_err(|_| Error::DofOutOfRange)?;
+        Ok(MultivariateStudentT {
+            location,
+            factor,
+            chi,
+            dof,
+        })
+    }
+}
+
+impl<F, const N: usize> Distribution<[F; N]> for MultivariateStudentT<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; N] {
+        let w = (self.chi.sample(rng) / self.dof).sqrt();
+        let z: [F; N] = core::array::from_fn(|_| rng.sample::<F, _>(StandardNormal) / w);
+        let mut x = [F::zero(); N];
+        lower_mul_add(&self.factor[..], &z, &self.location, &mut x);
+        x
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let t = MultivariateStudentT::new([1.0, 2.0], [[4.0, 2.0], [2.0, 10.0]], 3.0).unwrap();
+        assert_eq!(t.factor, [[2.0, 0.0], [1.0, 3.0]]);
+
+        let scale = [[1.0, 0.0], [0.0, 1.0]];
+        assert_eq!(
+            MultivariateStudentT::<f64, 0>::new([], [], 1.0),
+            Err(Error::BadDimension)
+        );
+        assert_eq!(
+            MultivariateStudentT::new([f64::NAN, 0.0], scale, 1.0),
+            Err(Error::NotFinite)
+        );
+        assert_eq!(
+            MultivariateStudentT::new([0.0, 0.0], [[1.0, 0.1], [0.0, 1.0]], 1.0),
+            Err(Error::NotSymmetric)
+        );
+        assert_eq!(
+            MultivariateStudentT::new([0.0, 0.0], [[1.0, 0.0], [0.0, 0.0]], 1.0),
+            Err(Error::NotPositiveDefinite)
+        );
+        assert_eq!(
+            MultivariateStudentT::new([0.0, 0.0], scale, 0.0),
+            Err(Error::DofOutOfRange)
+        );
+        assert_eq!(
+            MultivariateStudentT::new([0.0, 0.0], scale, f64::NAN),
+            Err(Error::DofOutOfRange)
+        );
+        assert_eq!(
+            MultivariateStudentT::new([0.0, 0.0], scale, f64::INFINITY),
+            Err(Error::DofOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let location = [1.0, -1.0];
+        let scale = [[2.0, -1.2], [-1.2, 1.0]];
+        let dof = 8.0;
+        let t = MultivariateStudentT::new(location, scale, dof).unwrap();
+        let mut rng = crate::test::rng(239);
+        const SAMPLES: usize = 100_000;
+        let mut sum = [0.0; 2];
+        let mut sum_sq = [[0.0; 2]; 2];
+        for _ in 0..SAMPLES {
+            let x = t.sample(&mut rng);
+            for i in 0..2 {
+                sum[i] += x[i];
+                for j in 0..2 {
+                    sum_sq[i][j] += (x[i] - location[i]) * (x[j] - location[j]);
+                }
+            }
+        }
+        let factor = dof / (dof - 2.0);
+        for i in 0..2 {
+            assert_almost_eq!(sum[i] / SAMPLES as f64, location[i], 0.02);
+            for j in 0..2 {
+                assert_almost_eq!(sum_sq[i][j] / SAMPLES as f64, factor * scale[i][j], 0.05);
+            }
+        }
+    }
+}
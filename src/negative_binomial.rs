@@ -0,0 +1,452 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The negative binomial distribution `NegativeBinomial(r, p)`.
+
+use crate::divergence::bernoulli_kl;
+use crate::entropy::lattice_entropy;
+use crate::geometric::{geometric_cgf, geometric_characteristic};
+use crate::quantile::discrete_quantile;
+use crate::special::{beta_pq, ln_binomial_raw, std_normal_quantile};
+use crate::{
+    Cdf, CharacteristicFunction, DiscretePmf, Distribution, Entropy, Gamma, Geometric,
+    KlDivergence, Mgf, Moments, Poisson, Quantile, Summary, Support,
+};
+use core::fmt;
+use num_complex::Complex;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::Rng;
+
+/// The [negative binomial distribution](https://en.wikipedia.org/wiki/Negative_binomial_distribution)
+/// `NegativeBinomial(r, p)`.
+///
+/// The negative binomial distribution is a discrete probability distribution
+/// which describes the number of failures before the `r`-th success in
+/// independent trials, each of which has success probability `p`. It extends
+/// to real `r > 0` as the Gamma–Poisson mixture: the [`Poisson`] distribution
+/// whose mean follows the [`Gamma`] distribution with shape `r` and scale
+/// `(1 - p) / p`. This makes it the usual model for overdispersed counts,
+/// whose variance `μ + μ² / r` exceeds their mean `μ`; see
+/// [`NegativeBinomial::from_mean_dispersion`].
+///
+/// With `r = 1` it is the [`Geometric`] distribution.
+///
+/// # Density function
+///
+/// `f(k) = Γ(k + r) / (k! Γ(r)) p^r (1 - p)^k` for `k >= 0`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, NegativeBinomial};
+///
+/// let nb = NegativeBinomial::new(2.5, 0.3).unwrap();
+/// let v = nb.sample(&mut rand::rng());
+/// println!("{} is from a negative binomial distribution", v);
+/// ```
+///
+/// # Implementation details
+///
+/// For small integer `r` and `p` not close to zero, samples are sums of `r`
+/// samples of the [`Geometric`] distribution. Otherwise a mean `λ` is
+/// sampled from the [`Gamma`] distribution and then a sample of the
+/// [`Poisson`] distribution with mean `λ`; samples saturate at [`u64::MAX`]
+/// if `λ` exceeds [`Poisson::MAX_LAMBDA`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NegativeBinomial {
+    r: f64,
+    p: f64,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Method {
+    Geometric(Geometric, u64),
+    GammaPoisson(Gamma<f64>),
+    Constant(u64),
+}
+
+/// The largest integer `r` for which samples are sums of geometric samples.
+const GEOMETRIC_MAX_R: f64 = 8.0;
+
+/// The smallest `p` for which samples are sums of geometric samples.
+const GEOMETRIC_MIN_P: f64 = 0.1;
+
+/// Error type returned from [`NegativeBinomial::new`] and
+/// [`NegativeBinomial::from_mean_dispersion`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `r <= 0`, infinite or `nan`.
+    ROutOfRange,
+    /// `p <= 0`, `p > 1` or `nan`.
+    ProbabilityOutOfRange,
+    /// `mean < 0`, infinite or `nan`.
+    MeanOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ROutOfRange => "r is not positive and finite in negative binomial distribution",
+            Error::ProbabilityOutOfRange => "p is not in (0, 1] in negative binomial distribution",
+            Error::MeanOutOfRange => {
+                "mean is negative or not finite in negative binomial distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl NegativeBinomial {
+    /// Construct a new `NegativeBinomial` with the given number of successes
+    /// `r` and probability of success `p`.
+    ///
+    /// Requires finite `r > 0` and `0 < p <= 1`.
+    pub fn new(r: f64, p: f64) -> Result<NegativeBinomial, Error> {
+        if !(r > 0.0 && r.is_finite()) {
+            return Err(Error::ROutOfRange);
+        }
+        if !(p > 0.0 && p <= 1.0) {
+            return Err(Error::ProbabilityOutOfRange);
+        }
+        let method = if p == 1.0 {
+            Method::Constant(0)
+        } else if r == r.floor() && r <= GEOMETRIC_MAX_R && p >= GEOMETRIC_MIN_P {
+            Method::Geometric(Geometric::new(p).unwrap(), r as u64)
+        } else {
+            Method::GammaPoisson(Gamma::new(r, (1.0 - p) / p).unwrap())
+        };
+        Ok(NegativeBinomial { r, p, method })
+    }
+
+    /// Construct a new `NegativeBinomial` with the given `mean` and
+    /// `dispersion`, the parameter `r`, so that the variance is
+    /// `mean + mean² / dispersion`.
+    ///
+    /// This is the parameterization of overdispersed count models such as
+    /// negative binomial regression: the smaller the dispersion, the larger
+    /// the variance, and as it grows the distribution approaches the
+    /// [`Poisson`] distribution with the same mean. The probability of success
+    /// is `p = dispersion / (dispersion + mean)`.
+    ///
+    /// Requires finite `mean >= 0` and finite `dispersion > 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Moments, NegativeBinomial};
+    ///
+    /// let nb = NegativeBinomial::from_mean_dispersion(6.0, 2.0).unwrap();
+    /// assert_eq!(nb.mean(), Some(6.0));
+    /// assert_eq!(nb.variance(), Some(24.0));
+    /// ```
+    pub fn from_mean_dispersion(mean: f64, dispersion: f64) -> Result<NegativeBinomial, Error> {
+        if !(mean >= 0.0 && mean.is_finite()) {
+            return Err(Error::MeanOutOfRange);
+        }
+        if !(dispersion > 0.0 && dispersion.is_finite()) {
+            return Err(Error::ROutOfRange);
+        }
+        NegativeBinomial::new(dispersion, dispersion / (dispersion + mean))
+    }
+}
+
+impl Distribution<u64> for NegativeBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        match self.method {
+            Method::Geometric(geometric, r) => {
+                (0..r).fold(0, |sum: u64, _| sum.saturating_add(geometric.sample(rng)))
+            }
+            Method::GammaPoisson(gamma) => {
+                let lambda = gamma.sample(rng);
+                if !(lambda > 0.0) {
+                    return 0;
+                }
+                match Poisson::new(lambda) {
+                    Ok(poisson) => poisson.sample(rng) as u64,
+                    Err(_) => u64::MAX,
+                }
+            }
+            Method::Constant(c) => c,
+        }
+    }
+}
+
+impl DiscretePmf<f64> for NegativeBinomial {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        // f(k) = r / (k + r) Binomial(k + r, p) at r successes
+        let (k, r) = (k as f64, self.r);
+        (r / (k + r)).ln() + ln_binomial_raw(r, k + r, self.p, 1.0 - self.p)
+    }
+}
+
+impl Cdf<f64> for NegativeBinomial {
+    fn cdf(&self, x: f64) -> f64 {
+        self.pq(x).0
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.pq(x).1
+    }
+}
+
+impl Quantile<f64> for NegativeBinomial {
+    fn quantile(&self, p: f64) -> f64 {
+        // Start from the normal approximation
+        let (mean, var) = (self.mean().unwrap(), self.variance().unwrap());
+        let guess = mean + std_normal_quantile(p) * var.sqrt();
+        discrete_quantile(self, p, 0.0, f64::INFINITY, guess)
+    }
+}
+
+impl Moments<f64> for NegativeBinomial {
+    fn mean(&self) -> Option<f64> {
+        Some(self.r * (1.0 - self.p) / self.p)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some(self.r * (1.0 - self.p) / (self.p * self.p))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        if self.p == 1.0 {
+            return None;
+        }
+        Some((2.0 - self.p) / (self.r * (1.0 - self.p)).sqrt())
+    }
+
+    fn excess_kurtosis(&self) -> Option<f64> {
+        if self.p == 1.0 {
+            return None;
+        }
+        Some((6.0 + self.p * self.p / (1.0 - self.p)) / self.r)
+    }
+}
+
+impl Entropy<f64> for NegativeBinomial {
+    fn entropy(&self) -> f64 {
+        lattice_entropy(self)
+    }
+}
+
+/// The divergence has a closed form only for equal numbers of successes `r`,
+/// and NaN is returned otherwise.
+impl KlDivergence<f64> for NegativeBinomial {
+    fn kl_divergence(&self, other: &Self) -> f64 {
+        if self.r != other.r {
+            return f64::NAN;
+        }
+        // As for `r` independent geometric samples
+        self.r * bernoulli_kl(self.p, other.p) / self.p
+    }
+}
+
+impl Mgf<f64> for NegativeBinomial {
+    fn cgf(&self, t: f64) -> Option<f64> {
+        geometric_cgf(self.p, t).map(|cgf| self.r * cgf)
+    }
+}
+
+impl CharacteristicFunction<f64> for NegativeBinomial {
+    fn characteristic(&self, t: f64) -> Complex<f64> {
+        geometric_characteristic(self.p, t).powf(self.r)
+    }
+}
+
+impl Summary<f64> for NegativeBinomial {
+    fn support(&self) -> Support<f64> {
+        let upper = if self.p == 1.0 { Some(0) } else { None };
+        Support::Integer { lower: 0, upper }
+    }
+
+    fn mode(&self) -> Option<f64> {
+        if self.r <= 1.0 {
+            return Some(0.0);
+        }
+        // For integer (r - 1)(1 - p) / p both it and the integer below are
+        // modes
+        let x = (self.r - 1.0) * (1.0 - self.p) / self.p;
+        let m = x.floor();
+        if m == x && m > 0.0 {
+            Some(m - 1.0)
+        } else {
+            Some(m)
+        }
+    }
+
+    fn median(&self) -> f64 {
+        self.quantile(0.5)
+    }
+}
+
+impl NegativeBinomial {
+    /// `(P(X <= x), P(X > x))`, using `P(X <= k) = I_p(r, k + 1)`.
+    fn pq(&self, x: f64) -> (f64, f64) {
+        if !(x >= 0.0) {
+            return (0.0, 1.0);
+        }
+        if self.p == 1.0 {
+            return (1.0, 0.0);
+        }
+        beta_pq(self.r, x.floor() + 1.0, self.p, 1.0 - self.p)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(matches!(
+            NegativeBinomial::new(3.0, 0.5).unwrap().method,
+            Method::Geometric(_, 3)
+        ));
+        assert!(matches!(
+            NegativeBinomial::new(2.5, 0.5).unwrap().method,
+            Method::GammaPoisson(_)
+        ));
+        assert!(matches!(
+            NegativeBinomial::new(3.0, 1e-3).unwrap().method,
+            Method::GammaPoisson(_)
+        ));
+        assert_eq!(
+            NegativeBinomial::new(3.0, 1.0).unwrap().method,
+            Method::Constant(0)
+        );
+
+        assert_eq!(NegativeBinomial::new(0.0, 0.5), Err(Error::ROutOfRange));
+        assert_eq!(
+            NegativeBinomial::new(f64::INFINITY, 0.5),
+            Err(Error::ROutOfRange)
+        );
+        assert_eq!(
+            NegativeBinomial::new(1.0, 0.0),
+            Err(Error::ProbabilityOutOfRange)
+        );
+        assert_eq!(
+            NegativeBinomial::new(1.0, 1.5),
+            Err(Error::ProbabilityOutOfRange)
+        );
+        assert_eq!(
+            NegativeBinomial::new(1.0, f64::NAN),
+            Err(Error::ProbabilityOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_from_mean_dispersion() {
+        let nb = NegativeBinomial::from_mean_dispersion(6.0, 2.0).unwrap();
+        assert_eq!((nb.r, nb.p), (2.0, 0.25));
+        assert_eq!(nb.variance(), Some(24.0));
+        let nb = NegativeBinomial::from_mean_dispersion(0.0, 2.0).unwrap();
+        assert_eq!(nb.p, 1.0);
+
+        assert_eq!(
+            NegativeBinomial::from_mean_dispersion(-1.0, 2.0),
+            Err(Error::MeanOutOfRange)
+        );
+        assert_eq!(
+            NegativeBinomial::from_mean_dispersion(1.0, 0.0),
+            Err(Error::ROutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        const SAMPLES: usize = 100_000;
+        let mut rng = crate::test::rng(249);
+        // Both sampling methods
+        for &(r, p) in &[(3.0, 0.4), (2.5, 0.3), (0.5, 0.05)] {
+            let nb = NegativeBinomial::new(r, p).unwrap();
+            let (mean, var) = (nb.mean().unwrap(), nb.variance().unwrap());
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..SAMPLES {
+                let x = nb.sample(&mut rng) as f64;
+                sum += x;
+                sum_sq += (x - mean) * (x - mean);
+            }
+            assert_almost_eq!(sum / SAMPLES as f64, mean, 0.01 * mean);
+            assert_almost_eq!(sum_sq / SAMPLES as f64, var, 0.05 * var);
+        }
+        let nb = NegativeBinomial::new(2.0, 1.0).unwrap();
+        assert_eq!(nb.sample(&mut rng), 0);
+    }
+
+    #[test]
+    fn test_pmf_cdf() {
+        let nb = NegativeBinomial::new(2.5, 0.3).unwrap();
+        assert_almost_eq!(nb.pmf(0), 0.04929503017546495, 1e-16);
+        assert_almost_eq!(nb.pmf(4), 0.10679903078612618, 1e-15);
+        assert_almost_eq!(nb.ln_pmf(100), -32.03572783696589, 1e-12);
+        assert_almost_eq!(nb.cdf(4.0), 0.4589966166928934, 1e-15);
+        assert_almost_eq!(nb.cdf(4.5), 0.4589966166928934, 1e-15);
+        assert_almost_eq!(nb.sf(20.0), 0.008453422341288661, 1e-16);
+        assert_eq!(nb.cdf(-1.0), 0.0);
+        assert_eq!(nb.quantile(0.5), 5.0);
+        assert_eq!(nb.quantile(0.99), 20.0);
+        assert_eq!(nb.median(), 5.0);
+
+        // With r = 1, the geometric distribution
+        let nb = NegativeBinomial::new(1.0, 0.25).unwrap();
+        let geometric = Geometric::new(0.25).unwrap();
+        for k in 0..10 {
+            assert_almost_eq!(nb.pmf(k), geometric.pmf(k), 1e-15);
+            assert_almost_eq!(nb.cdf(k as f64), geometric.cdf(k as f64), 1e-15);
+        }
+
+        let nb = NegativeBinomial::new(2.0, 1.0).unwrap();
+        assert_eq!(nb.pmf(0), 1.0);
+        assert_eq!(nb.pmf(1), 0.0);
+        assert_eq!(nb.cdf(0.0), 1.0);
+    }
+
+    #[test]
+    fn test_moments() {
+        let nb = NegativeBinomial::new(2.5, 0.3).unwrap();
+        assert_almost_eq!(nb.mean().unwrap(), 2.5 * 0.7 / 0.3, 1e-14);
+        assert_almost_eq!(nb.variance().unwrap(), 2.5 * 0.7 / 0.09, 1e-13);
+        assert_almost_eq!(nb.skewness().unwrap(), 1.7 / 1.75f64.sqrt(), 1e-15);
+        assert_almost_eq!(nb.excess_kurtosis().unwrap(), 2.4 + 0.09 / 1.75, 1e-15);
+        assert_almost_eq!(nb.entropy(), 2.741115364084301, 1e-13);
+        assert_eq!(nb.mode(), Some(3.0));
+        assert_eq!(
+            nb.support(),
+            Support::Integer {
+                lower: 0,
+                upper: None
+            }
+        );
+
+        // The KL divergence of r geometric samples
+        let other = NegativeBinomial::new(2.5, 0.5).unwrap();
+        let geometric = Geometric::new(0.3).unwrap();
+        let expected = 2.5 * geometric.kl_divergence(&Geometric::new(0.5).unwrap());
+        assert_almost_eq!(nb.kl_divergence(&other), expected, 1e-15);
+        assert!(nb
+            .kl_divergence(&NegativeBinomial::new(2.0, 0.5).unwrap())
+            .is_nan());
+
+        // The cumulant-generating function gives the mean
+        let h = 1e-6;
+        let derivative = (nb.cgf(h).unwrap() - nb.cgf(-h).unwrap()) / (2.0 * h);
+        assert_almost_eq!(derivative, nb.mean().unwrap(), 1e-6);
+        assert!(nb.cgf(1.0).is_none());
+        let phi = nb.characteristic(0.7);
+        let phi_geometric: Complex<f64> = 0.3 / (1.0 - 0.7 * Complex::new(0.0, 0.7).exp());
+        let expected = phi_geometric.powf(2.5);
+        assert_almost_eq!(phi.re, expected.re, 1e-15);
+        assert_almost_eq!(phi.im, expected.im, 1e-15);
+    }
+}
@@ -0,0 +1,243 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The negative hypergeometric distribution `NegativeHypergeometric(N, K, r)`.
+
+use crate::{BetaBinomial, Cdf, DiscretePmf, Distribution, Moments, Quantile};
+use core::fmt;
+use rand::Rng;
+
+/// The [negative hypergeometric distribution](https://en.wikipedia.org/wiki/Negative_hypergeometric_distribution)
+/// `NegativeHypergeometric(N, K, r)`.
+///
+/// The number of successes drawn without replacement from a population of
+/// size `N` containing `K` success states before the `r`-th failure, as in an
+/// audit that inspects records until `r` compliant ones have been seen. See
+/// the [`NegativeBinomial`](crate::NegativeBinomial) distribution for the
+/// analogous distribution for sampling with replacement.
+///
+/// # Density function
+///
+/// `f(k) = binomial(k + r - 1, k) * binomial(N - r - k, K - k) / binomial(N, K)`
+/// for `0 <= k <= K`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, NegativeHypergeometric};
+///
+/// let nh = NegativeHypergeometric::new(52, 4, 3).unwrap();
+/// let v = nh.sample(&mut rand::rng());
+/// println!("{} is from a negative hypergeometric distribution", v);
+/// ```
+///
+/// # Implementation details
+///
+/// The `N - K` failures split the successes into `N - K + 1` runs, and each
+/// composition of the `K` successes into these runs is equally likely. The
+/// number of successes in the first `r` runs hence follows the
+/// [`BetaBinomial`] distribution `BetaBinomial(K, r, N - K - r + 1)`, whose
+/// sampler and probabilities are used.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NegativeHypergeometric {
+    total_population_size: u64,
+    population_with_feature: u64,
+    failures: u64,
+    beta_binomial: BetaBinomial,
+}
+
+/// Error type returned from [`NegativeHypergeometric::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `population_with_feature > total_population_size`.
+    ProbabilityTooLarge,
+    /// `failures > total_population_size - population_with_feature`.
+    FailuresTooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ProbabilityTooLarge => {
+                "population_with_feature > total_population_size in negative hypergeometric distribution"
+            }
+            Error::FailuresTooLarge => {
+                "failures exceed the population without feature in negative hypergeometric distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl NegativeHypergeometric {
+    /// Constructs a new `NegativeHypergeometric` with the shape parameters
+    /// `N = total_population_size`,
+    /// `K = population_with_feature`,
+    /// `r = failures`.
+    ///
+    /// Requires `K <= N` and `r <= N - K`. With `r = 0`, no success is drawn.
+    pub fn new(
+        total_population_size: u64,
+        population_with_feature: u64,
+        failures: u64,
+    ) -> Result<Self, Error> {
+        if population_with_feature > total_population_size {
+            return Err(Error::ProbabilityTooLarge);
+        }
+        let population_without_feature = total_population_size - population_with_feature;
+        if failures > population_without_feature {
+            return Err(Error::FailuresTooLarge);
+        }
+        let beta_binomial = if failures == 0 {
+            // The distribution concentrated at zero, without trials
+            BetaBinomial::new(0, 1.0, 1.0)
+        } else {
+            BetaBinomial::new(
+                population_with_feature,
+                failures as f64,
+                (population_without_feature - failures) as f64 + 1.0,
+            )
+        }
+        .unwrap();
+        Ok(NegativeHypergeometric {
+            total_population_size,
+            population_with_feature,
+            failures,
+            beta_binomial,
+        })
+    }
+}
+
+impl Distribution<u64> for NegativeHypergeometric {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        self.beta_binomial.sample(rng)
+    }
+}
+
+impl DiscretePmf<f64> for NegativeHypergeometric {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        self.beta_binomial.ln_pmf(k)
+    }
+}
+
+impl Cdf<f64> for NegativeHypergeometric {
+    fn cdf(&self, x: f64) -> f64 {
+        self.beta_binomial.cdf(x)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.beta_binomial.sf(x)
+    }
+}
+
+impl Quantile<f64> for NegativeHypergeometric {
+    fn quantile(&self, p: f64) -> f64 {
+        self.beta_binomial.quantile(p)
+    }
+}
+
+impl Moments<f64> for NegativeHypergeometric {
+    fn mean(&self) -> Option<f64> {
+        self.beta_binomial.mean()
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.beta_binomial.variance()
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.beta_binomial.skewness()
+    }
+
+    fn excess_kurtosis(&self) -> Option<f64> {
+        self.beta_binomial.excess_kurtosis()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(NegativeHypergeometric::new(10, 4, 6).is_ok());
+        assert!(NegativeHypergeometric::new(10, 10, 0).is_ok());
+        assert_eq!(
+            NegativeHypergeometric::new(10, 11, 0),
+            Err(Error::ProbabilityTooLarge)
+        );
+        assert_eq!(
+            NegativeHypergeometric::new(10, 4, 7),
+            Err(Error::FailuresTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_pmf() {
+        let nh = NegativeHypergeometric::new(10, 4, 2).unwrap();
+        // binomial(k + 1, k) binomial(8 - k, 4 - k) / binomial(10, 4)
+        let expected = [70.0, 70.0, 45.0, 20.0, 5.0];
+        for (k, &e) in expected.iter().enumerate() {
+            assert_almost_eq!(nh.pmf(k as u64), e / 210.0, 1e-14);
+        }
+        assert_eq!(nh.pmf(5), 0.0);
+        assert_almost_eq!(nh.cdf(1.0), 2.0 / 3.0, 1e-14);
+        assert_eq!(nh.quantile(0.5), 1.0);
+        // r K / (N - K + 1)
+        assert_almost_eq!(nh.mean().unwrap(), 8.0 / 7.0, 1e-14);
+
+        let nh = NegativeHypergeometric::new(10, 4, 0).unwrap();
+        assert_eq!(nh.pmf(0), 1.0);
+        let nh = NegativeHypergeometric::new(10, 4, 6).unwrap();
+        // All successes precede the last failure
+        assert_almost_eq!(nh.pmf(4), 0.6, 1e-14);
+    }
+
+    #[test]
+    fn test_large_population() {
+        // Close to the negative binomial distribution with p = 1/2, with
+        // almost all of the mass below 100 of the 10⁸ possible successes
+        let nh = NegativeHypergeometric::new(200_000_000, 100_000_000, 50).unwrap();
+        assert_almost_eq!(nh.sf(100.0), 1.3134444798884695e-5, 1e-16);
+        assert_almost_eq!(nh.cdf(100.0), 1.0 - 1.3134444798884695e-5, 1e-15);
+        let median = nh.quantile(0.5);
+        assert!(nh.cdf(median) >= 0.5 && nh.cdf(median - 1.0) < 0.5);
+        assert_almost_eq!(median, 49.0, 1.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        // Compare with drawing from the urn until the r-th failure
+        let (n, k, r) = (30, 12, 5);
+        let nh = NegativeHypergeometric::new(n, k, r).unwrap();
+        let mut rng = crate::test::rng(254);
+        const SAMPLES: usize = 20_000;
+        let mut hist = [0u64; 13];
+        let mut urn_hist = [0u64; 13];
+        for _ in 0..SAMPLES {
+            hist[nh.sample(&mut rng) as usize] += 1;
+            let (mut successes, mut failures) = (k, n - k);
+            while failures > n - k - r {
+                if rng.random_range(0..successes + failures) < successes {
+                    successes -= 1;
+                } else {
+                    failures -= 1;
+                }
+            }
+            urn_hist[(k - successes) as usize] += 1;
+        }
+        for (x, (&h, &u)) in hist.iter().zip(urn_hist.iter()).enumerate() {
+            let p = nh.pmf(x as u64);
+            assert_almost_eq!(h as f64 / SAMPLES as f64, p, 0.015);
+            assert_almost_eq!(u as f64 / SAMPLES as f64, p, 0.015);
+        }
+    }
+}
@@ -9,9 +9,17 @@
 
 //! The Normal and derived distributions.
 
+use crate::fit::Welford;
+use crate::quantile::order_quantiles;
+use crate::special::{log1pmx, std_normal_cdf, std_normal_quantile, LN_SQRT_2PI};
 use crate::utils::ziggurat;
-use crate::{ziggurat_tables, Distribution, Open01};
+use crate::{
+    ziggurat_tables, Cdf, CharacteristicFunction, ContinuousPdf, Distribution, Entropy, FitError,
+    KlDivergence, Mgf, Moments, Open01, Quantile, Summary, Support,
+};
 use core::fmt;
+use core::ops::Bound;
+use num_complex::Complex;
 use num_traits::Float;
 use rand::Rng;
 
@@ -100,6 +108,81 @@ impl Distribution<f64> for StandardNormal {
     }
 }
 
+impl<F: Float> ContinuousPdf<F> for StandardNormal {
+    fn ln_pdf(&self, x: F) -> F {
+        -F::from(0.5).unwrap() * x * x - F::from(LN_SQRT_2PI).unwrap()
+    }
+}
+
+impl<F: Float> Cdf<F> for StandardNormal {
+    fn cdf(&self, x: F) -> F {
+        std_normal_cdf(x)
+    }
+
+    fn sf(&self, x: F) -> F {
+        std_normal_cdf(-x)
+    }
+}
+
+impl<F: Float> Quantile<F> for StandardNormal {
+    fn quantile(&self, p: F) -> F {
+        std_normal_quantile(p)
+    }
+}
+
+impl<F: Float> Moments<F> for StandardNormal {
+    fn mean(&self) -> Option<F> {
+        Some(F::zero())
+    }
+
+    fn variance(&self) -> Option<F> {
+        Some(F::one())
+    }
+
+    fn skewness(&self) -> Option<F> {
+        Some(F::zero())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        Some(F::zero())
+    }
+}
+
+impl<F: Float> Entropy<F> for StandardNormal {
+    fn entropy(&self) -> F {
+        F::from(LN_SQRT_2PI + 0.5).unwrap()
+    }
+}
+
+impl<F: Float> Mgf<F> for StandardNormal {
+    fn cgf(&self, t: F) -> Option<F> {
+        if t.is_nan() {
+            return None;
+        }
+        Some(F::from(0.5).unwrap() * t * t)
+    }
+}
+
+impl<F: Float> CharacteristicFunction<F> for StandardNormal {
+    fn characteristic(&self, t: F) -> Complex<F> {
+        Complex::new((F::from(-0.5).unwrap() * t * t).exp(), F::zero())
+    }
+}
+
+impl<F: Float> Summary<F> for StandardNormal {
+    fn support(&self) -> Support<F> {
+        Support::real_line()
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some(F::zero())
+    }
+
+    fn median(&self) -> F {
+        F::zero()
+    }
+}
+
 /// The [Normal distribution](https://en.wikipedia.org/wiki/Normal_distribution) `N(μ, σ²)`.
 ///
 /// The Normal distribution, also known as the Gaussian distribution or
@@ -155,11 +238,14 @@ where
 
 /// Error type returned from [`Normal::new`] and [`LogNormal::new`](crate::LogNormal::new).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// The mean value is too small (log-normal samples must be positive)
     MeanTooSmall,
     /// The standard deviation or other dispersion parameter is not finite.
     BadVariance,
+    /// The quantiles passed to `from_quantiles` are invalid or not achievable.
+    BadQuantiles,
 }
 
 impl fmt::Display for Error {
@@ -167,6 +253,7 @@ impl fmt::Display for Error {
         f.write_str(match self {
             Error::MeanTooSmall => "mean < 0 or NaN in log-normal distribution",
             Error::BadVariance => "variation parameter is non-finite in (log)normal distribution",
+            Error::BadQuantiles => "quantiles are not achievable in (log)normal distribution",
         })
     }
 }
@@ -208,6 +295,72 @@ where
         Ok(Normal { mean, std_dev })
     }
 
+    /// Construct, from two quantiles
+    ///
+    /// Each of `a` and `b` is a pair `(p, x)` requiring the quantile at
+    /// probability `p` to be `x`, for example `(0.5, median)`. The
+    /// probabilities must be distinct and in `(0, 1)`, and the values finite
+    /// and in the same order as the probabilities.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Normal, Quantile};
+    ///
+    /// // A 90% interval of [4, 30]
+    /// let normal: Normal<f64> = Normal::from_quantiles((0.05, 4.0), (0.95, 30.0)).unwrap();
+    /// assert!((normal.quantile(0.05) - 4.0).abs() < 1e-12);
+    /// assert!((normal.quantile(0.95) - 30.0).abs() < 1e-12);
+    /// ```
+    pub fn from_quantiles(a: (F, F), b: (F, F)) -> Result<Normal<F>, Error> {
+        let ((p1, x1), (p2, x2)) = order_quantiles(a, b).ok_or(Error::BadQuantiles)?;
+        let (z1, z2) = (std_normal_quantile(p1), std_normal_quantile(p2));
+        let std_dev = (x2 - x1) / (z2 - z1);
+        let mean = x1 - std_dev * z1;
+        if !(mean.is_finite() && std_dev.is_finite()) {
+            return Err(Error::BadQuantiles);
+        }
+        Ok(Normal { mean, std_dev })
+    }
+
+    /// Fit to observations by maximum likelihood.
+    ///
+    /// The estimates are the sample mean and the standard deviation
+    /// normalized by the number of observations (not by one less), computed
+    /// in a single pass with Welford's algorithm.
+    ///
+    /// Fails if `data` is empty, its values are all equal or any is not
+    /// finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Normal;
+    ///
+    /// let normal = Normal::fit(&[1.0, 2.0, 4.0, 5.0]).unwrap();
+    /// assert_eq!(normal.mean(), 3.0);
+    /// assert_eq!(normal.std_dev(), 2.5f64.sqrt());
+    /// ```
+    pub fn fit(data: &[F]) -> Result<Normal<F>, FitError> {
+        let mut acc = Welford::new();
+        for &x in data {
+            if !x.is_finite() {
+                return Err(FitError::OutOfSupport);
+            }
+            acc.push(x);
+        }
+        if acc.count == 0 {
+            return Err(FitError::Empty);
+        }
+        if acc.m2 == F::zero() {
+            return Err(FitError::Constant);
+        }
+        Ok(Normal {
+            mean: acc.mean,
+            std_dev: acc.variance().sqrt(),
+        })
+    }
+
     /// Sample from a z-score
     ///
     /// This may be useful for generating correlated samples `x1` and `x2`
@@ -246,6 +399,158 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for Normal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        // A negative standard deviation describes the same distribution.
+        let sigma = self.std_dev.abs();
+        if sigma == F::zero() {
+            return if x == self.mean {
+                F::infinity()
+            } else {
+                F::neg_infinity()
+            };
+        }
+        StandardNormal.ln_pdf((x - self.mean) / sigma) - sigma.ln()
+    }
+}
+
+impl<F> Cdf<F> for Normal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        let sigma = self.std_dev.abs();
+        if sigma == F::zero() {
+            return if x >= self.mean { F::one() } else { F::zero() };
+        }
+        std_normal_cdf((x - self.mean) / sigma)
+    }
+
+    fn sf(&self, x: F) -> F {
+        let sigma = self.std_dev.abs();
+        if sigma == F::zero() {
+            return if x >= self.mean { F::zero() } else { F::one() };
+        }
+        std_normal_cdf((self.mean - x) / sigma)
+    }
+}
+
+impl<F> Quantile<F> for Normal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        let z = std_normal_quantile(p);
+        let sigma = self.std_dev.abs();
+        if sigma == F::zero() && !z.is_nan() {
+            return self.mean;
+        }
+        self.mean + sigma * z
+    }
+}
+
+impl<F> Moments<F> for Normal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some(self.mean)
+    }
+
+    fn variance(&self) -> Option<F> {
+        Some(self.std_dev * self.std_dev)
+    }
+
+    fn skewness(&self) -> Option<F> {
+        if self.std_dev == F::zero() {
+            return None;
+        }
+        Some(F::zero())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        if self.std_dev == F::zero() {
+            return None;
+        }
+        Some(F::zero())
+    }
+}
+
+impl<F> Entropy<F> for Normal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        self.std_dev.abs().ln() + Entropy::<F>::entropy(&StandardNormal)
+    }
+}
+
+impl<F> KlDivergence<F> for Normal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn kl_divergence(&self, other: &Self) -> F {
+        let half = F::from(0.5).unwrap();
+        // With r = σ₁ / σ₂ this is (r² - 1 - ln r²) / 2 + (μ₁ - μ₂)² / 2σ₂²
+        let (s1, s2) = (self.std_dev, other.std_dev);
+        let r2_m1 = (s1 - s2) * (s1 + s2) / (s2 * s2);
+        let z = (self.mean - other.mean) / s2;
+        half * (z * z - log1pmx(r2_m1))
+    }
+}
+
+impl<F> Mgf<F> for Normal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn cgf(&self, t: F) -> Option<F> {
+        if t.is_nan() {
+            return None;
+        }
+        let half = F::from(0.5).unwrap();
+        Some(t * (self.mean + half * self.std_dev * self.std_dev * t))
+    }
+}
+
+impl<F> CharacteristicFunction<F> for Normal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn characteristic(&self, t: F) -> Complex<F> {
+        let z = self.std_dev * t;
+        Complex::from_polar((F::from(-0.5).unwrap() * z * z).exp(), self.mean * t)
+    }
+}
+
+impl<F> Summary<F> for Normal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::real_line()
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some(self.mean)
+    }
+
+    fn median(&self) -> F {
+        self.mean
+    }
+}
+
 /// The [log-normal distribution](https://en.wikipedia.org/wiki/Log-normal_distribution) `ln N(μ, σ²)`.
 ///
 /// This is the distribution of the random variable `X = exp(Y)` where `Y` is
@@ -334,6 +639,69 @@ where
         Ok(LogNormal { norm })
     }
 
+    /// Construct, from two quantiles
+    ///
+    /// Each of `a` and `b` is a pair `(p, x)` requiring the quantile at
+    /// probability `p` to be `x`. The probabilities must be distinct and in
+    /// `(0, 1)`, and the values positive, finite and in the same order as the
+    /// probabilities. The logarithms of the values are then quantiles of the
+    /// underlying normal distribution (see [`Normal::from_quantiles`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{LogNormal, Quantile};
+    ///
+    /// let dist: LogNormal<f64> = LogNormal::from_quantiles((0.5, 10.0), (0.95, 30.0)).unwrap();
+    /// assert!((dist.quantile(0.5) - 10.0).abs() < 1e-12);
+    /// assert!((dist.quantile(0.95) - 30.0).abs() < 1e-12);
+    /// ```
+    pub fn from_quantiles(a: (F, F), b: (F, F)) -> Result<LogNormal<F>, Error> {
+        if !(a.1 > F::zero() && b.1 > F::zero()) {
+            return Err(Error::BadQuantiles);
+        }
+        let norm = Normal::from_quantiles((a.0, a.1.ln()), (b.0, b.1.ln()))?;
+        Ok(LogNormal { norm })
+    }
+
+    /// Fit to observations by maximum likelihood.
+    ///
+    /// The estimates of `μ` and `σ` are those of [`Normal::fit`] for the
+    /// logarithms of the observations.
+    ///
+    /// Fails if `data` is empty, its values are all equal or any is not
+    /// positive and finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::LogNormal;
+    ///
+    /// let e = core::f64::consts::E;
+    /// let log_normal = LogNormal::fit(&[1.0, e * e]).unwrap();
+    /// assert_eq!(log_normal, LogNormal::new(1.0, 1.0).unwrap());
+    /// ```
+    pub fn fit(data: &[F]) -> Result<LogNormal<F>, FitError> {
+        let mut acc = Welford::new();
+        for &x in data {
+            if !(x > F::zero() && x.is_finite()) {
+                return Err(FitError::OutOfSupport);
+            }
+            acc.push(x.ln());
+        }
+        if acc.count == 0 {
+            return Err(FitError::Empty);
+        }
+        if acc.m2 == F::zero() {
+            return Err(FitError::Constant);
+        }
+        let norm = Normal {
+            mean: acc.mean,
+            std_dev: acc.variance().sqrt(),
+        };
+        Ok(LogNormal { norm })
+    }
+
     /// Sample from a z-score
     ///
     /// This may be useful for generating correlated samples `x1` and `x2`
@@ -363,6 +731,128 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for LogNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::neg_infinity();
+        }
+        let ln_x = x.ln();
+        self.norm.ln_pdf(ln_x) - ln_x
+    }
+}
+
+impl<F> Cdf<F> for LogNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::zero();
+        }
+        self.norm.cdf(x.ln())
+    }
+
+    fn sf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::one();
+        }
+        self.norm.sf(x.ln())
+    }
+}
+
+impl<F> Quantile<F> for LogNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        self.norm.quantile(p).exp()
+    }
+}
+
+impl<F> Moments<F> for LogNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        let (mu, sigma) = (self.norm.mean, self.norm.std_dev);
+        Some((mu + F::from(0.5).unwrap() * sigma * sigma).exp())
+    }
+
+    fn variance(&self) -> Option<F> {
+        let (mu, sigma) = (self.norm.mean, self.norm.std_dev);
+        let s2 = sigma * sigma;
+        Some(s2.exp_m1() * (mu + mu + s2).exp())
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let s2 = self.norm.std_dev * self.norm.std_dev;
+        if s2 == F::zero() {
+            return None;
+        }
+        Some((s2.exp() + F::from(2.0).unwrap()) * s2.exp_m1().sqrt())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let s2 = self.norm.std_dev * self.norm.std_dev;
+        if s2 == F::zero() {
+            return None;
+        }
+        // e^(4σ²) + 2 e^(3σ²) + 3 e^(2σ²) - 6, written in terms of e^(σ²) - 1
+        let e = s2.exp_m1();
+        let c = |x: f64| F::from(x).unwrap();
+        Some(e * (c(16.0) + e * (c(15.0) + e * (c(6.0) + e))))
+    }
+}
+
+impl<F> Entropy<F> for LogNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        self.norm.mean + self.norm.entropy()
+    }
+}
+
+impl<F> KlDivergence<F> for LogNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn kl_divergence(&self, other: &Self) -> F {
+        self.norm.kl_divergence(&other.norm)
+    }
+}
+
+impl<F> Summary<F> for LogNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::Real {
+            lower: Bound::Excluded(F::zero()),
+            upper: Bound::Unbounded,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        let Normal { mean, std_dev } = self.norm;
+        Some((mean - std_dev * std_dev).exp())
+    }
+
+    fn median(&self) -> F {
+        self.norm.mean.exp()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -420,6 +910,213 @@ mod tests {
         assert!(LogNormal::from_mean_cv(1.0, -1.0).is_err());
     }
 
+    #[test]
+    fn test_normal_pdf() {
+        let norm = Normal::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(norm.pdf(1.0), 0.12579440923099772134, 1e-16);
+        assert_almost_eq!(norm.ln_pdf(200.0), -2180.0175508218727824, 1e-11);
+        assert_eq!(norm.pdf(200.0), 0.0);
+        // The sign of the standard deviation does not matter
+        let flipped = Normal::new(2.0, -3.0).unwrap();
+        assert_eq!(flipped.pdf(1.0), norm.pdf(1.0));
+        let degenerate = Normal::new(2.0, 0.0).unwrap();
+        assert_eq!(degenerate.pdf(2.0), f64::INFINITY);
+        assert_eq!(degenerate.pdf(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_log_normal_pdf() {
+        let lnorm = LogNormal::new(0.5, 0.8).unwrap();
+        assert_almost_eq!(lnorm.pdf(2.0), 0.24217677488483338909, 1e-16);
+        assert_eq!(lnorm.pdf(0.0), 0.0);
+        assert_eq!(lnorm.ln_pdf(-1.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_normal_cdf() {
+        let norm = Normal::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(norm.cdf(1.0), 0.36944134018176363827, 1e-16);
+        assert_almost_eq!(norm.sf(1.0), 0.63055865981823636173, 1e-15);
+        assert_almost_eq!(norm.cdf(-40.0) / 7.7935368191928002544e-45, 1.0, 1e-13);
+        assert_almost_eq!(norm.sf(40.0) / 4.5239042048987606194e-37, 1.0, 1e-13);
+        let degenerate = Normal::new(2.0, 0.0).unwrap();
+        assert_eq!(degenerate.cdf(2.0), 1.0);
+        assert_eq!(degenerate.sf(2.0), 0.0);
+        assert_eq!(degenerate.cdf(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_log_normal_cdf() {
+        let lnorm = LogNormal::new(0.5, 0.8).unwrap();
+        assert_almost_eq!(lnorm.cdf(2.0), 0.59539060867921496540, 1e-15);
+        assert_almost_eq!(lnorm.sf(2.0), 0.40460939132078503460, 1e-15);
+        assert_eq!(lnorm.cdf(0.0), 0.0);
+        assert_eq!(lnorm.sf(-1.0), 1.0);
+    }
+
+    #[test]
+    fn test_normal_quantile() {
+        let norm = Normal::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(norm.quantile(0.3), 0.42679846187587755209, 1e-15);
+        assert_almost_eq!(norm.quantile(1e-10), -17.084022707212168597, 1e-13);
+        assert_almost_eq!(norm.quantile(0.99), 8.9790436221225223029, 1e-14);
+        assert_eq!(norm.quantile(0.0), f64::NEG_INFINITY);
+        assert_eq!(norm.quantile(1.0), f64::INFINITY);
+        assert!(norm.quantile(1.5).is_nan());
+        assert_eq!(Normal::new(2.0, 0.0).unwrap().quantile(0.3), 2.0);
+
+        let mut rng = crate::test::rng(212);
+        for _ in 0..100 {
+            let x = norm.sample_by_inversion(&mut rng);
+            assert_almost_eq!(norm.quantile(norm.cdf(x)), x, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_normal_moments() {
+        let norm = Normal::new(2.0, 3.0).unwrap();
+        assert_eq!(Moments::mean(&norm), Some(2.0));
+        assert_eq!(norm.variance(), Some(9.0));
+        assert_eq!(norm.skewness(), Some(0.0));
+        assert_eq!(norm.excess_kurtosis(), Some(0.0));
+        let degenerate = Normal::new(2.0, 0.0).unwrap();
+        assert_eq!(degenerate.variance(), Some(0.0));
+        assert_eq!(degenerate.skewness(), None);
+        assert_eq!(Moments::<f64>::variance(&StandardNormal), Some(1.0));
+    }
+
+    #[test]
+    fn test_normal_entropy() {
+        let norm = Normal::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(norm.entropy(), 2.5175508218727822, 1e-15);
+        assert_almost_eq!(
+            Entropy::<f64>::entropy(&StandardNormal),
+            1.4189385332046727,
+            1e-15
+        );
+        let degenerate = Normal::new(2.0, 0.0).unwrap();
+        assert_eq!(degenerate.entropy(), f64::NEG_INFINITY);
+        let negative = Normal::new(2.0, -3.0).unwrap();
+        assert_almost_eq!(negative.entropy(), 2.5175508218727822, 1e-15);
+    }
+
+    #[test]
+    fn test_normal_kl_divergence() {
+        let p = Normal::new(1.0, 2.0).unwrap();
+        let q = Normal::new(-1.0, 3.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 0.34990955255260882642, 1e-15);
+        assert_eq!(p.kl_divergence(&p), 0.0);
+        // Close distributions keep their relative precision
+        let p = Normal::new(0.0, 1.0).unwrap();
+        let q = Normal::new(0.0, 1.0 + 1e-8).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 9.9999997117839195730e-17, 1e-30);
+        let q = Normal::new(1e-9, 1.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 5.0000000000000006228e-19, 1e-33);
+    }
+
+    #[test]
+    fn test_normal_mgf() {
+        let d = Normal::new(1.0, 2.0).unwrap();
+        assert_eq!(d.cgf(0.5), Some(1.0));
+        assert_eq!(d.mgf(0.0), Some(1.0));
+        assert_eq!(d.cgf(f64::NAN), None);
+        let phi = d.characteristic(0.5);
+        assert_almost_eq!(phi.re, 0.53228073021567071484, 1e-15);
+        assert_almost_eq!(phi.im, 0.29078628821269184886, 1e-15);
+        let phi = StandardNormal.characteristic(2.0);
+        assert_eq!((phi.re, phi.im), ((-2.0f64).exp(), 0.0));
+    }
+
+    #[test]
+    fn test_normal_summary() {
+        let d = Normal::new(1.0, 2.0).unwrap();
+        assert_eq!(d.support(), Support::real_line());
+        assert_eq!(d.mode(), Some(1.0));
+        assert_eq!(d.median(), 1.0);
+        assert_eq!(Summary::<f64>::median(&StandardNormal), 0.0);
+    }
+
+    #[test]
+    fn test_normal_fit() {
+        let d = Normal::fit(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+        assert_eq!((d.mean(), d.std_dev()), (5.0, 2.0));
+        // The same observations far from zero
+        let data: [f64; 8] = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].map(|x| 1e9 + x);
+        let d = Normal::fit(&data).unwrap();
+        assert_eq!(d.mean(), 1e9 + 5.0);
+        assert_almost_eq!(d.std_dev(), 2.0, 1e-8);
+
+        let mut rng = crate::test::rng(212);
+        let dist = Normal::new(-3.0, 0.5).unwrap();
+        let data: [f64; 10_000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        let d = Normal::fit(&data).unwrap();
+        assert_almost_eq!(d.mean(), -3.0, 0.02);
+        assert_almost_eq!(d.std_dev(), 0.5, 0.02);
+
+        assert_eq!(Normal::<f64>::fit(&[]), Err(FitError::Empty));
+        assert_eq!(Normal::fit(&[1.5, 1.5]), Err(FitError::Constant));
+        assert_eq!(Normal::fit(&[1.5, f64::NAN]), Err(FitError::OutOfSupport));
+        assert_eq!(
+            Normal::fit(&[1.5, f64::INFINITY]),
+            Err(FitError::OutOfSupport)
+        );
+    }
+
+    #[test]
+    fn test_log_normal_quantile() {
+        let lnorm = LogNormal::new(0.5, 0.8).unwrap();
+        assert_almost_eq!(lnorm.quantile(0.7), 2.5080872483061236600, 1e-15);
+        assert_eq!(lnorm.quantile(0.0), 0.0);
+        assert_eq!(lnorm.quantile(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_log_normal_moments() {
+        let d = LogNormal::new(0.5, 0.8).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 2.2704998375324058613, 1e-13);
+        assert_almost_eq!(d.variance().unwrap(), 4.6215108972942250607, 1e-13);
+        assert_almost_eq!(d.skewness().unwrap(), 3.6892922960912974006, 1e-13);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 31.367653430832428445, 1e-13);
+    }
+
+    #[test]
+    fn test_log_normal_entropy() {
+        let d = LogNormal::new(0.5, 0.8).unwrap();
+        assert_almost_eq!(d.entropy(), 1.695794981890463, 1e-15);
+    }
+
+    #[test]
+    fn test_log_normal_kl_divergence() {
+        let p = LogNormal::new(1.0, 2.0).unwrap();
+        let q = LogNormal::new(-1.0, 3.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 0.34990955255260882642, 1e-15);
+    }
+
+    #[test]
+    fn test_log_normal_summary() {
+        let d = LogNormal::new(1.0, 0.5).unwrap();
+        let support = d.support();
+        assert_eq!(support.lower(), 0.0);
+        assert!(!support.contains(0.0) && support.contains(1e-300));
+        assert_almost_eq!(d.mode().unwrap(), 0.75f64.exp(), 1e-15);
+        assert_almost_eq!(d.median(), 1.0f64.exp(), 1e-15);
+    }
+
+    #[test]
+    fn test_log_normal_fit() {
+        let mut rng = crate::test::rng(213);
+        let dist = LogNormal::new(1.0, 2.0).unwrap();
+        let data: [f64; 10_000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        let d = LogNormal::fit(&data).unwrap();
+        assert_almost_eq!(d.norm.mean, 1.0, 0.05);
+        assert_almost_eq!(d.norm.std_dev, 2.0, 0.05);
+
+        assert_eq!(LogNormal::<f64>::fit(&[]), Err(FitError::Empty));
+        assert_eq!(LogNormal::fit(&[3.0, 3.0]), Err(FitError::Constant));
+        assert_eq!(LogNormal::fit(&[3.0, 0.0]), Err(FitError::OutOfSupport));
+        assert_eq!(LogNormal::fit(&[3.0, -1.0]), Err(FitError::OutOfSupport));
+    }
+
     #[test]
     fn normal_distributions_can_be_compared() {
         assert_eq!(Normal::new(1.0, 2.0), Normal::new(1.0, 2.0));
@@ -429,4 +1126,31 @@ mod tests {
     fn log_normal_distributions_can_be_compared() {
         assert_eq!(LogNormal::new(1.0, 2.0), LogNormal::new(1.0, 2.0));
     }
+
+    #[test]
+    fn test_from_quantiles() {
+        let normal = Normal::from_quantiles((0.975, 5.0), (0.5, 1.0)).unwrap();
+        assert_almost_eq!(normal.mean(), 1.0, 1e-15);
+        assert_almost_eq!(normal.std_dev(), 4.0 / 1.959963984540054, 1e-14);
+        assert_almost_eq!(normal.quantile(0.975), 5.0, 1e-14);
+
+        let lnorm = LogNormal::from_quantiles((0.1, 1.0), (0.9, 100.0)).unwrap();
+        assert_almost_eq!(lnorm.quantile(0.5), 10.0, 1e-13);
+        assert_almost_eq!(lnorm.quantile(0.9), 100.0, 1e-12);
+
+        for &(a, b) in &[
+            ((0.5, 1.0), (0.5, 2.0)),
+            ((0.0, 1.0), (0.5, 2.0)),
+            ((0.5, 1.0), (f64::NAN, 2.0)),
+            ((0.1, 2.0), (0.9, 1.0)),
+            ((0.1, 1.0), (0.9, 1.0)),
+            ((0.1, 1.0), (0.9, f64::INFINITY)),
+        ] {
+            assert_eq!(Normal::from_quantiles(a, b), Err(Error::BadQuantiles));
+        }
+        assert_eq!(
+            LogNormal::from_quantiles((0.1, 0.0), (0.9, 1.0)),
+            Err(Error::BadQuantiles)
+        );
+    }
 }
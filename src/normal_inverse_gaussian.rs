@@ -1,5 +1,13 @@
-use crate::{Distribution, InverseGaussian, StandardNormal, StandardUniform};
+use crate::entropy::numeric_entropy;
+use crate::quantile::invert_cdf;
+use crate::special::{bessel_k0e, bessel_k1e, LN_PI};
+use crate::utils::{find_root, integrate};
+use crate::{
+    Cdf, CharacteristicFunction, ContinuousPdf, Distribution, Entropy, InverseGaussian, Mgf,
+    Moments, Quantile, StandardNormal, StandardUniform, Summary, Support,
+};
 use core::fmt;
+use num_complex::Complex;
 use num_traits::Float;
 use rand::Rng;
 
@@ -88,6 +96,37 @@ where
             inverse_gaussian,
         })
     }
+
+    /// Integrates the density over the tail beyond `x` on the far side of
+    /// the mean, returning the probability and whether it is the upper tail.
+    fn tail(&self, x: F) -> (F, bool) {
+        if x.is_nan() {
+            return (x, true);
+        }
+        if x.is_infinite() {
+            return (F::zero(), x > F::zero());
+        }
+        let gamma = self.inverse_gaussian.params().0.recip();
+        let alpha = gamma.hypot(self.beta);
+        let mean = self.beta / gamma;
+        let std_dev = alpha / (gamma * gamma.sqrt());
+        // Map the tail to `u` in (0, 1] via `t = x ± σ (1 - u) / u`.
+        let sign = if x > mean { F::one() } else { -F::one() };
+        let c = sign * std_dev;
+        let integral = integrate(
+            |u: F| {
+                let density = self.pdf(x + c * (F::one() - u) / u);
+                if density == F::zero() {
+                    F::zero()
+                } else {
+                    density * std_dev / u / u
+                }
+            },
+            F::zero(),
+            F::one(),
+        );
+        (integral, x > mean)
+    }
 }
 
 impl<F> Distribution<F> for NormalInverseGaussian<F>
@@ -106,6 +145,182 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for NormalInverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        // The inner inverse Gaussian has mean `1 / gamma` and unit shape.
+        let gamma = self.inverse_gaussian.params().0.recip();
+        let alpha = gamma.hypot(self.beta);
+        let q = F::one().hypot(x);
+        // Use the exponentially scaled Bessel function so that the density
+        // does not underflow in the tails.
+        alpha.ln() - F::from(LN_PI).unwrap() - q.ln() + bessel_k1e(alpha * q).ln() - alpha * q
+            + gamma
+            + self.beta * x
+    }
+}
+
+impl<F> Cdf<F> for NormalInverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        let (tail, upper) = self.tail(x);
+        if upper {
+            F::one() - tail
+        } else {
+            tail
+        }
+    }
+
+    fn sf(&self, x: F) -> F {
+        let (tail, upper) = self.tail(x);
+        if upper {
+            tail
+        } else {
+            F::one() - tail
+        }
+    }
+}
+
+impl<F> Quantile<F> for NormalInverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        let gamma = self.inverse_gaussian.params().0.recip();
+        let alpha = gamma.hypot(self.beta);
+        let mean = self.beta / gamma;
+        let std_dev = alpha / (gamma * gamma.sqrt());
+        invert_cdf(self, p, F::neg_infinity(), F::infinity(), mean, std_dev)
+    }
+}
+
+impl<F> Moments<F> for NormalInverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        let gamma = self.inverse_gaussian.params().0.recip();
+        Some(self.beta / gamma)
+    }
+
+    fn variance(&self) -> Option<F> {
+        let gamma = self.inverse_gaussian.params().0.recip();
+        let alpha = gamma.hypot(self.beta);
+        Some(alpha * alpha / (gamma * gamma * gamma))
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let gamma = self.inverse_gaussian.params().0.recip();
+        let alpha = gamma.hypot(self.beta);
+        Some(F::from(3.0).unwrap() * self.beta / (alpha * gamma.sqrt()))
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let gamma = self.inverse_gaussian.params().0.recip();
+        let alpha = gamma.hypot(self.beta);
+        let r = self.beta / alpha;
+        let c = |x: f64| F::from(x).unwrap();
+        Some(c(3.0) * (F::one() + c(4.0) * r * r) / gamma)
+    }
+}
+
+impl<F> Entropy<F> for NormalInverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // The density is sharply peaked around the origin for `β` close to `α`
+        numeric_entropy(|x| self.ln_pdf(x), F::neg_infinity(), F::zero(), F::one())
+    }
+}
+
+impl<F> Mgf<F> for NormalInverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn cgf(&self, t: F) -> Option<F> {
+        // γ - sqrt(α² - (β + t)²), which converges for |β + t| <= α
+        let gamma = self.inverse_gaussian.params().0.recip();
+        let alpha = gamma.hypot(self.beta);
+        let u = self.beta + t;
+        if !(u.abs() <= alpha) {
+            return None;
+        }
+        let root = ((alpha - u) * (alpha + u)).sqrt();
+        Some(t * (self.beta + u) / (gamma + root))
+    }
+}
+
+impl<F> CharacteristicFunction<F> for NormalInverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn characteristic(&self, t: F) -> Complex<F> {
+        // exp(γ - sqrt(α² - (β + it)²)), where the principal square root
+        // of α² - (β + it)² = γ² + t² - 2iβt is taken
+        let gamma = self.inverse_gaussian.params().0.recip();
+        let two_beta = self.beta + self.beta;
+        let root = Complex::new(gamma * gamma + t * t, -two_beta * t).sqrt();
+        (Complex::new(-t * t, two_beta * t) / (root + gamma)).exp()
+    }
+}
+
+impl<F> Summary<F> for NormalInverseGaussian<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    StandardUniform: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::real_line()
+    }
+
+    fn mode(&self) -> Option<F> {
+        // The root of the derivative of the log-density,
+        // β - x (α K₀(αq) / K₁(αq) + 2 / q) / q with q = sqrt(1 + x²), which
+        // has the sign of β at zero and of β - α < 0 far out on that side
+        let beta = self.beta;
+        if beta == F::zero() {
+            return Some(F::zero());
+        }
+        let gamma = self.inverse_gaussian.params().0.recip();
+        let alpha = gamma.hypot(beta);
+        let slope = |x: F| {
+            let q = x.hypot(F::one());
+            let ratio = bessel_k0e(alpha * q) / bessel_k1e(alpha * q);
+            beta - x * (alpha * ratio + F::from(2.0).unwrap() / q) / q
+        };
+        // Start from the mean
+        let mut b = beta / gamma;
+        while (slope(b) > F::zero()) == (beta > F::zero()) && b.is_finite() {
+            b = b + b;
+        }
+        Some(find_root(slope, F::zero(), b, F::zero()))
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,6 +334,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_normal_inverse_gaussian_pdf() {
+        let norm_inv_gauss = NormalInverseGaussian::new(2.0, 1.0).unwrap();
+        assert_almost_eq!(norm_inv_gauss.pdf(0.5), 0.54671625002353578007, 1e-14);
+        assert_almost_eq!(norm_inv_gauss.ln_pdf(500.0), -508.16385446906851123, 1e-11);
+    }
+
+    #[test]
+    fn test_normal_inverse_gaussian_cdf() {
+        let norm_inv_gauss = NormalInverseGaussian::new(2.0, 1.0).unwrap();
+        assert_almost_eq!(norm_inv_gauss.cdf(0.5), 0.52389134161459012510, 1e-14);
+        assert_almost_eq!(norm_inv_gauss.sf(0.5), 0.47610865838540987490, 1e-14);
+        assert_almost_eq!(
+            norm_inv_gauss.cdf(-10.0) / 2.7524537048847957635e-15,
+            1.0,
+            1e-12
+        );
+        assert_almost_eq!(
+            norm_inv_gauss.sf(30.0) / 1.6857423866612694748e-15,
+            1.0,
+            1e-12
+        );
+        assert_eq!(norm_inv_gauss.cdf(f64::NEG_INFINITY), 0.0);
+        assert_eq!(norm_inv_gauss.sf(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_normal_inverse_gaussian_quantile() {
+        let d = NormalInverseGaussian::new(2.0, 1.0).unwrap();
+        assert_almost_eq!(d.quantile(0.3), 0.10652160445376081968, 1e-13);
+        assert_almost_eq!(d.quantile(0.9), 1.6667173816673554285, 1e-13);
+        assert_eq!(d.quantile(0.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_normal_inverse_gaussian_moments() {
+        let d = NormalInverseGaussian::new(2.0, 1.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 0.57735026918962576451, 1e-14);
+        assert_almost_eq!(d.variance().unwrap(), 0.76980035891950101935, 1e-14);
+        assert_almost_eq!(d.skewness().unwrap(), 1.139753528477388821, 1e-14);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 3.4641016151377545871, 1e-14);
+    }
+
+    #[test]
+    fn test_normal_inverse_gaussian_entropy() {
+        let d = NormalInverseGaussian::new(2.0, 1.0).unwrap();
+        assert_almost_eq!(d.entropy(), 1.2096082876804426668, 1e-13);
+        let d = NormalInverseGaussian::new(1.0, 0.9).unwrap();
+        assert_almost_eq!(d.entropy(), 2.0709889561961135823, 1e-13);
+        let d = NormalInverseGaussian::new(1.0, 0.999).unwrap();
+        assert_almost_eq!(d.entropy(), 3.2160039533907917344, 1e-13);
+    }
+
+    #[test]
+    fn test_normal_inverse_gaussian_mgf() {
+        let d = NormalInverseGaussian::new(2.0, 1.0).unwrap();
+        assert_almost_eq!(d.cgf(0.5).unwrap(), 0.40917515203658199828, 1e-15);
+        assert_almost_eq!(d.cgf(-2.5).unwrap(), 0.40917515203658199828, 1e-15);
+        // The moment-generating function is finite up to the boundary
+        assert_almost_eq!(d.cgf(1.0 - 1e-12).unwrap(), 3.0f64.sqrt(), 1e-5);
+        assert_eq!(d.cgf(1.0 + 1e-12), None);
+        assert_eq!(d.cgf(-3.0 - 1e-12), None);
+        let phi = d.characteristic(1.5);
+        assert_almost_eq!(phi.re, 0.42376729946814637365, 1e-15);
+        assert_almost_eq!(phi.im, 0.30972012024924981349, 1e-15);
+    }
+
+    #[test]
+    fn test_normal_inverse_gaussian_summary() {
+        let d = NormalInverseGaussian::new(2.0, 1.0).unwrap();
+        assert_eq!(d.support(), Support::real_line());
+        assert_almost_eq!(d.mode().unwrap(), 0.29272856612618261257, 1e-14);
+        assert_almost_eq!(d.median(), 0.45677980006166874887, 1e-14);
+        let d = NormalInverseGaussian::new(2.0, -1.0).unwrap();
+        assert_almost_eq!(d.mode().unwrap(), -0.29272856612618261257, 1e-14);
+        let d = NormalInverseGaussian::new(2.0, 0.0).unwrap();
+        assert_eq!(d.mode(), Some(0.0));
+    }
+
     #[test]
     fn test_normal_inverse_gaussian_invalid_param() {
         assert!(NormalInverseGaussian::new(-1.0, 1.0).is_err());
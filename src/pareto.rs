@@ -8,8 +8,13 @@
 
 //! The Pareto distribution `Pareto(xₘ, α)`.
 
-use crate::{Distribution, OpenClosed01};
+use crate::tail::hill_with_threshold;
+use crate::{
+    Cdf, ContinuousPdf, Distribution, Entropy, FitError, Moments, OpenClosed01, Quantile, Summary,
+    Support,
+};
 use core::fmt;
+use core::ops::Bound;
 use num_traits::Float;
 use rand::Rng;
 
@@ -46,13 +51,18 @@ where
     inv_neg_shape: F,
 }
 
-/// Error type returned from [`Pareto::new`].
+/// Error type returned from [`Pareto::new`] and [`Pareto::from_mean_variance`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// `scale <= 0` or `nan`.
     ScaleTooSmall,
     /// `shape <= 0` or `nan`.
     ShapeTooSmall,
+    /// `mean <= 0` or `nan`.
+    MeanOutOfRange,
+    /// `variance <= 0`, infinite or `nan`.
+    VarianceOutOfRange,
 }
 
 impl fmt::Display for Error {
@@ -60,6 +70,10 @@ impl fmt::Display for Error {
         f.write_str(match self {
             Error::ScaleTooSmall => "scale is not positive in Pareto distribution",
             Error::ShapeTooSmall => "shape is not positive in Pareto distribution",
+            Error::MeanOutOfRange => "mean is not positive in Pareto distribution",
+            Error::VarianceOutOfRange => {
+                "variance is not positive and finite in Pareto distribution"
+            }
         })
     }
 }
@@ -90,6 +104,120 @@ where
             inv_neg_shape: F::from(-1.0).unwrap() / shape,
         })
     }
+
+    /// Construct a Pareto distribution with the given `mean` and `variance`.
+    ///
+    /// The squared coefficient of variation `variance / mean²` equals
+    /// `1 / (α (α - 2))` for the shape `α > 2`, so that
+    /// `α = 1 + √(1 + mean² / variance)`, and the scale is then
+    /// `mean (α - 1) / α`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Pareto;
+    ///
+    /// let pareto = Pareto::from_mean_variance(1.5, 0.75).unwrap();
+    /// assert_eq!(pareto, Pareto::new(1.0, 3.0).unwrap());
+    /// ```
+    pub fn from_mean_variance(mean: F, variance: F) -> Result<Pareto<F>, Error> {
+        if !(mean > F::zero()) {
+            return Err(Error::MeanOutOfRange);
+        }
+        if !(variance > F::zero() && variance.is_finite()) {
+            return Err(Error::VarianceOutOfRange);
+        }
+        let r = mean / variance.sqrt();
+        let shape = F::one() + (F::one() + r * r).sqrt();
+        Pareto::new(mean * (shape - F::one()) / shape, shape)
+    }
+
+    /// Fit to observations by maximum likelihood.
+    ///
+    /// The estimate of the scale is the least observation `xₘ` and that of
+    /// the shape is `n / Σ ln(xᵢ / xₘ)` for `n` observations. The sum is
+    /// accumulated in a single pass, in terms of the relative differences to
+    /// the least observation so far so that it keeps its accuracy also for
+    /// clustered observations.
+    ///
+    /// Fails if `data` is empty, its values are all equal or any is not
+    /// positive and finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Pareto;
+    ///
+    /// let e = core::f64::consts::E;
+    /// let pareto = Pareto::fit(&[2.0, 2.0 * e]).unwrap();
+    /// assert_eq!(pareto, Pareto::new(2.0, 2.0).unwrap());
+    /// ```
+    pub fn fit(data: &[F]) -> Result<Pareto<F>, FitError> {
+        let mut count = 0usize;
+        let mut min = F::infinity();
+        let mut sum = F::zero();
+        for &x in data {
+            if !(x > F::zero() && x.is_finite()) {
+                return Err(FitError::OutOfSupport);
+            }
+            if x < min {
+                // Rebase the earlier terms onto the new least observation
+                if count > 0 {
+                    sum = sum + F::from(count).unwrap() * ((min - x) / x).ln_1p();
+                }
+                min = x;
+            } else {
+                sum = sum + ((x - min) / min).ln_1p();
+            }
+            count += 1;
+        }
+        if count == 0 {
+            return Err(FitError::Empty);
+        }
+        if sum == F::zero() {
+            return Err(FitError::Constant);
+        }
+        Ok(Pareto {
+            scale: min,
+            inv_neg_shape: -sum / F::from(count).unwrap(),
+        })
+    }
+
+    /// Fit to the upper tail of observations with the Hill estimator.
+    ///
+    /// The scale is the threshold given by the `k + 1`-th largest
+    /// observation and the shape is the reciprocal of
+    /// [`tail::hill`](crate::tail::hill), which makes this the
+    /// maximum-likelihood fit to the `k` observations above the threshold.
+    /// The distribution then models observations conditional on exceeding
+    /// the threshold. `data` is partially reordered in place.
+    ///
+    /// Fails if `data` is empty or contains a value that is not finite, if
+    /// `k` is zero or not less than the number of observations, if the
+    /// threshold is not positive or if the `k` largest observations all
+    /// equal the threshold.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Distribution, Pareto, StudentT};
+    ///
+    /// // Student's t distribution has a tail like a Pareto one with shape ν
+    /// let t = StudentT::new(3.0).unwrap();
+    /// let mut data: Vec<f64> = t.sample_iter(rand::rng()).take(10_000).collect();
+    /// let tail = Pareto::fit_hill(&mut data, 500).unwrap();
+    /// println!("{:?}", tail);
+    /// ```
+    pub fn fit_hill(data: &mut [F], k: usize) -> Result<Pareto<F>, FitError> {
+        let (threshold, xi) = hill_with_threshold(data, k)?;
+        if xi == F::zero() {
+            return Err(FitError::Constant);
+        }
+        Ok(Pareto {
+            scale: threshold,
+            inv_neg_shape: -xi,
+        })
+    }
 }
 
 impl<F> Distribution<F> for Pareto<F>
@@ -103,6 +231,128 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for Pareto<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        if !(x >= self.scale) {
+            return F::neg_infinity();
+        }
+        let shape = -self.inv_neg_shape.recip();
+        (shape / self.scale).ln() - (shape + F::one()) * (x / self.scale).ln()
+    }
+}
+
+impl<F> Cdf<F> for Pareto<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        if !(x > self.scale) {
+            return F::zero();
+        }
+        -((x / self.scale).ln() / self.inv_neg_shape).exp_m1()
+    }
+
+    fn sf(&self, x: F) -> F {
+        if !(x > self.scale) {
+            return F::one();
+        }
+        ((x / self.scale).ln() / self.inv_neg_shape).exp()
+    }
+}
+
+impl<F> Quantile<F> for Pareto<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        if !(p >= F::zero() && p <= F::one()) {
+            return F::nan();
+        }
+        self.scale * ((-p).ln_1p() * self.inv_neg_shape).exp()
+    }
+}
+
+impl<F> Moments<F> for Pareto<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        let a = -self.inv_neg_shape.recip();
+        if !(a > F::one()) {
+            return None;
+        }
+        Some(a * self.scale / (a - F::one()))
+    }
+
+    fn variance(&self) -> Option<F> {
+        let a = -self.inv_neg_shape.recip();
+        let two = F::from(2.0).unwrap();
+        if !(a > two) {
+            return None;
+        }
+        let a1 = a - F::one();
+        Some(self.scale * self.scale * a / (a1 * a1 * (a - two)))
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let a = -self.inv_neg_shape.recip();
+        let c = |x: f64| F::from(x).unwrap();
+        if !(a > c(3.0)) {
+            return None;
+        }
+        Some(c(2.0) * (F::one() + a) / (a - c(3.0)) * ((a - c(2.0)) / a).sqrt())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let a = -self.inv_neg_shape.recip();
+        let c = |x: f64| F::from(x).unwrap();
+        if !(a > c(4.0)) {
+            return None;
+        }
+        let num = c(6.0) * (a * a * a + a * a - c(6.0) * a - c(2.0));
+        Some(num / (a * (a - c(3.0)) * (a - c(4.0))))
+    }
+}
+
+impl<F> Entropy<F> for Pareto<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // ln(x_m / α) + 1/α + 1
+        (-self.scale * self.inv_neg_shape).ln() - self.inv_neg_shape + F::one()
+    }
+}
+
+impl<F> Summary<F> for Pareto<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::Real {
+            lower: Bound::Included(self.scale),
+            upper: Bound::Unbounded,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some(self.scale)
+    }
+
+    fn median(&self) -> F {
+        self.scale * (-self.inv_neg_shape).exp2()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,8 +407,139 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pdf() {
+        let d = Pareto::new(2.0, 1.5).unwrap();
+        assert_almost_eq!(d.pdf(3.0), 0.27216552697590871043, 1e-15);
+        assert_almost_eq!(d.pdf(2.0), 0.75, 1e-15);
+        assert_eq!(d.pdf(1.9), 0.0);
+    }
+
+    #[test]
+    fn cdf() {
+        let d = Pareto::new(2.0, 1.5).unwrap();
+        assert_almost_eq!(d.cdf(3.0), 0.45566894604818264485, 1e-15);
+        assert_almost_eq!(d.sf(3.0), 0.54433105395181735515, 1e-15);
+        assert_eq!(d.cdf(2.0), 0.0);
+        assert_eq!(d.sf(1.9), 1.0);
+    }
+
+    #[test]
+    fn quantile() {
+        let d = Pareto::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(d.quantile(0.3), 2.2524957608872122220, 1e-15);
+        assert_almost_eq!(d.quantile(1e-12), 2.0000000000006666667, 1e-15);
+        assert_eq!(d.quantile(0.0), 2.0);
+        assert_eq!(d.quantile(1.0), f64::INFINITY);
+        assert!(d.quantile(-0.1).is_nan());
+    }
+
+    #[test]
+    fn moments() {
+        let d = Pareto::new(2.0, 5.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 2.5, 1e-13);
+        assert_almost_eq!(d.variance().unwrap(), 0.41666666666666666667, 1e-13);
+        assert_almost_eq!(d.skewness().unwrap(), 4.6475800154489002622, 1e-13);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 70.8, 1e-13);
+        let d = Pareto::new(2.0, 1.0).unwrap();
+        assert_eq!(d.mean(), None);
+        assert_eq!(d.variance(), None);
+    }
+
+    #[test]
+    fn entropy() {
+        let d = Pareto::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(d.entropy(), 0.92786822522516895136, 1e-15);
+    }
+
+    #[test]
+    fn summary() {
+        let d = Pareto::new(2.0, 3.0).unwrap();
+        assert_eq!(
+            d.support(),
+            Support::Real {
+                lower: Bound::Included(2.0),
+                upper: Bound::Unbounded
+            }
+        );
+        assert_eq!(d.mode(), Some(2.0));
+        assert_almost_eq!(d.median(), 2.5198420997897463295, 1e-15);
+    }
+
+    #[test]
+    fn fit() {
+        let mut rng = crate::test::rng(215);
+        let dist = Pareto::new(1.5, 2.5).unwrap();
+        let data: [f64; 10_000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        let d = Pareto::fit(&data).unwrap();
+        let min = data.iter().cloned().fold(f64::INFINITY, f64::min);
+        assert_eq!(d.scale, min);
+        assert_almost_eq!(d.scale, 1.5, 1e-3);
+        assert_almost_eq!(-1.0 / d.inv_neg_shape, 2.5, 0.05);
+
+        // Clustered observations, in decreasing order so that the least
+        // observation changes at every step
+        let data: [f64; 100] = core::array::from_fn(|i| 1e6 + (99 - i) as f64);
+        let sum: f64 = data.iter().map(|&x| ((x - 1e6) / 1e6).ln_1p()).sum();
+        let d = Pareto::fit(&data).unwrap();
+        assert_eq!(d.scale, 1e6);
+        assert_almost_eq!(d.inv_neg_shape, -sum / 100.0, 1e-15 * sum / 100.0);
+
+        assert_eq!(Pareto::<f64>::fit(&[]), Err(FitError::Empty));
+        assert_eq!(Pareto::fit(&[2.0, 2.0]), Err(FitError::Constant));
+        assert_eq!(Pareto::fit(&[2.0, 0.0]), Err(FitError::OutOfSupport));
+    }
+
+    #[test]
+    fn fit_hill() {
+        let mut data = [4.0, 1.0, 8.0, 2.0, 16.0];
+        let d = Pareto::fit_hill(&mut data, 2).unwrap();
+        assert_eq!(d.scale, 4.0);
+        assert_almost_eq!(d.inv_neg_shape, -1.5 * core::f64::consts::LN_2, 1e-15);
+
+        // The tail of a Pareto distribution above a threshold is a Pareto
+        // distribution with the same shape
+        let mut rng = crate::test::rng(234);
+        let dist = Pareto::new(1.0, 2.5).unwrap();
+        let mut data: [f64; 10_000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        let d = Pareto::fit_hill(&mut data, 2000).unwrap();
+        assert_almost_eq!(-1.0 / d.inv_neg_shape, 2.5, 0.15);
+
+        assert_eq!(
+            Pareto::fit_hill(&mut [1.0, 2.0, 2.0], 1),
+            Err(FitError::Constant)
+        );
+        assert_eq!(
+            Pareto::fit_hill(&mut [1.0, 2.0], 2),
+            Err(FitError::InvalidSize)
+        );
+    }
+
     #[test]
     fn pareto_distributions_can_be_compared() {
         assert_eq!(Pareto::new(1.0, 2.0), Pareto::new(1.0, 2.0));
     }
+
+    #[test]
+    fn test_from_mean_variance() {
+        let expected = Pareto::new(2.0, 4.0).unwrap();
+        let (mean, variance) = (expected.mean().unwrap(), expected.variance().unwrap());
+        let pareto = Pareto::from_mean_variance(mean, variance).unwrap();
+        assert_almost_eq!(pareto.mean().unwrap(), mean, 1e-14);
+        assert_almost_eq!(pareto.variance().unwrap(), variance, 1e-14);
+        assert_almost_eq!(pareto.scale, 2.0, 1e-14);
+
+        assert_eq!(
+            Pareto::from_mean_variance(0.0, 1.0),
+            Err(Error::MeanOutOfRange)
+        );
+        assert_eq!(
+            Pareto::from_mean_variance(1.0, 0.0),
+            Err(Error::VarianceOutOfRange)
+        );
+        assert_eq!(
+            Pareto::from_mean_variance(1.0, f64::INFINITY),
+            Err(Error::VarianceOutOfRange)
+        );
+    }
 }
@@ -7,8 +7,14 @@
 // except according to those terms.
 //! The PERT distribution.
 
-use crate::{Beta, Distribution, Exp1, Open01, StandardNormal};
+use crate::quantile::order_quantiles;
+use crate::utils::find_root;
+use crate::{
+    Beta, Cdf, ContinuousPdf, Distribution, Entropy, Exp1, Moments, Open01, Quantile,
+    StandardNormal, Summary, Support,
+};
 use core::fmt;
+use core::ops::Bound;
 use num_traits::Float;
 use rand::Rng;
 
@@ -53,6 +59,7 @@ where
 
 /// Error type returned from [`Pert`] constructors.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum PertError {
     /// `max < min` or `min` or `max` is NaN.
     RangeTooSmall,
@@ -60,6 +67,14 @@ pub enum PertError {
     ModeRange,
     /// `shape < 0` or `shape` is NaN
     ShapeTooSmall,
+    /// `mean <= min` or `mean >= max` or `mean` is NaN.
+    MeanRange,
+    /// `variance` is not positive, or too large for the mean to be achieved
+    /// with a mode in `[min, max]`.
+    VarianceRange,
+    /// `p` is not in `(0, 1)`, or the quantile is not achievable with a mode
+    /// in `[min, max]`.
+    QuantileRange,
 }
 
 impl fmt::Display for PertError {
@@ -68,6 +83,9 @@ impl fmt::Display for PertError {
             PertError::RangeTooSmall => "requirement min < max is not met in PERT distribution",
             PertError::ModeRange => "mode is outside [min, max] in PERT distribution",
             PertError::ShapeTooSmall => "shape < 0 or is NaN in PERT distribution",
+            PertError::MeanRange => "mean is outside (min, max) in PERT distribution",
+            PertError::VarianceRange => "variance is not achievable in PERT distribution",
+            PertError::QuantileRange => "quantile is not achievable in PERT distribution",
         })
     }
 }
@@ -100,6 +118,119 @@ where
         let shape = F::from(4.0).unwrap();
         PertBuilder { min, max, shape }
     }
+
+    /// Construct a PERT distribution on `[min, max]` with the given `mean`
+    /// and `variance`, which determine both the mode and the shape.
+    ///
+    /// The distribution is that of `min + (max - min) X` for a variable `X`
+    /// following the beta distribution with the corresponding moments
+    /// (see [`Beta::from_mean_variance`]), whose parameters must both be at
+    /// least one for the mode to be in `[min, max]`. This requires
+    /// `min < mean < max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Moments, Pert};
+    ///
+    /// let pert: Pert<f64> = Pert::from_mean_variance(0.0, 6.0, 2.0, 1.0).unwrap();
+    /// assert!((pert.mean().unwrap() - 2.0).abs() < 1e-14);
+    /// assert!((pert.variance().unwrap() - 1.0).abs() < 1e-14);
+    /// ```
+    pub fn from_mean_variance(min: F, max: F, mean: F, variance: F) -> Result<Pert<F>, PertError> {
+        if !(max > min) {
+            return Err(PertError::RangeTooSmall);
+        }
+        let range = max - min;
+        let m = (mean - min) / range;
+        if !(m > F::zero() && m < F::one()) {
+            return Err(PertError::MeanRange);
+        }
+        let v = variance / (range * range);
+        let q = F::one() - m;
+        if !(v > F::zero() && v < m * q) {
+            return Err(PertError::VarianceRange);
+        }
+        let precision = (m * q - v) / v;
+        let (a, b) = (m * precision, q * precision);
+        if !(a >= F::one() && b >= F::one()) {
+            return Err(PertError::VarianceRange);
+        }
+        let beta = Beta::new(a, b).map_err(|_| PertError::VarianceRange)?;
+        Ok(Pert { min, range, beta })
+    }
+
+    /// Construct a PERT distribution with the given `shape` from three
+    /// quantiles, which determine `min`, `max` and the mode.
+    ///
+    /// Each of `a`, `b` and `c` is a pair `(p, x)` requiring the quantile at
+    /// probability `p` to be `x`, for example `(0.5, median)`. The
+    /// probabilities must be distinct and in `(0, 1)`, and the values finite
+    /// and in the same order as the probabilities. The conventional shape is
+    /// 4; larger shapes concentrate the distribution around the mode.
+    ///
+    /// For a given relative position of the mode, the underlying beta
+    /// distribution is fixed, and the outer quantiles determine `min` and
+    /// `max`; the position of the mode is then found numerically to match the
+    /// middle quantile. This fails with [`PertError::QuantileRange`] if no
+    /// mode matches, as when the quantiles are more skewed than the shape
+    /// allows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Pert, Quantile};
+    ///
+    /// // A median of 10, with a 90% interval of [4, 20]
+    /// let pert: Pert<f64> =
+    ///     Pert::from_quantiles((0.05, 4.0), (0.5, 10.0), (0.95, 20.0), 4.0).unwrap();
+    /// assert!((pert.quantile(0.05) - 4.0).abs() < 1e-9);
+    /// assert!((pert.quantile(0.5) - 10.0).abs() < 1e-9);
+    /// assert!((pert.quantile(0.95) - 20.0).abs() < 1e-9);
+    /// ```
+    pub fn from_quantiles(a: (F, F), b: (F, F), c: (F, F), shape: F) -> Result<Pert<F>, PertError> {
+        if !(shape >= F::zero() && shape.is_finite()) {
+            return Err(PertError::ShapeTooSmall);
+        }
+        // Order the quantiles by probability, checking each adjacent pair
+        let (a, b) = order_quantiles(a, b).ok_or(PertError::QuantileRange)?;
+        let (b, c) = order_quantiles(b, c).ok_or(PertError::QuantileRange)?;
+        let ((p1, x1), (p2, x2)) = order_quantiles(a, b).ok_or(PertError::QuantileRange)?;
+        let (p3, x3) = c;
+        let one = F::one();
+        let beta = |m: F| Beta::new(one + shape * m, one + shape * (one - m));
+        // The relative position of the middle quantile between the outer
+        // ones, for the mode at `min + m * range`
+        let position = |beta: &Beta<F>| {
+            let (u1, u3) = (beta.quantile(p1), beta.quantile(p3));
+            (beta.quantile(p2) - u1) / (u3 - u1)
+        };
+        let r = (x2 - x1) / (x3 - x1);
+        let g = |m: F| match beta(m) {
+            Ok(beta) => position(&beta) - r,
+            Err(_) => F::nan(),
+        };
+        let tol = F::from(8.0).unwrap() * F::epsilon();
+        let (g0, g1) = (g(F::zero()), g(one));
+        if !(g0 <= tol && g1 >= -tol) {
+            return Err(PertError::QuantileRange);
+        }
+        let m = if g0 >= F::zero() {
+            F::zero()
+        } else if g1 <= F::zero() {
+            one
+        } else {
+            find_root(g, F::zero(), one, F::epsilon())
+        };
+        let beta = beta(m).map_err(|_| PertError::QuantileRange)?;
+        let (u1, u3) = (beta.quantile(p1), beta.quantile(p3));
+        let range = (x3 - x1) / (u3 - u1);
+        let min = x1 - range * u1;
+        if !(range > F::zero() && range.is_finite() && min.is_finite()) {
+            return Err(PertError::QuantileRange);
+        }
+        Ok(Pert { min, range, beta })
+    }
 }
 
 /// Struct used to build a [`Pert`]
@@ -134,6 +265,60 @@ where
         self.with_mode(mode)
     }
 
+    /// Specify the quantile `x` at probability `p`, which determines the mode
+    ///
+    /// As `min` and `max` are the quantiles at 0 and 1, this specifies the
+    /// distribution by three quantiles, for the shape set with
+    /// [`with_shape`](Self::with_shape). All quantiles increase with the mode,
+    /// which is found numerically; `x` must lie between the quantiles at `p`
+    /// for the modes `min` and `max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Pert, Quantile};
+    ///
+    /// // A task takes between 2 and 12 days, with a median of 5 days
+    /// let pert: Pert<f64> = Pert::new(2.0, 12.0).with_quantile(0.5, 5.0).unwrap();
+    /// assert!((pert.quantile(0.5) - 5.0).abs() < 1e-9);
+    /// ```
+    pub fn with_quantile(self, p: F, x: F) -> Result<Pert<F>, PertError> {
+        if !(self.max > self.min) {
+            return Err(PertError::RangeTooSmall);
+        }
+        if !(self.shape >= F::zero()) {
+            return Err(PertError::ShapeTooSmall);
+        }
+        if !(p > F::zero() && p < F::one()) {
+            return Err(PertError::QuantileRange);
+        }
+        let (one, shape) = (F::one(), self.shape);
+        let range = self.max - self.min;
+        let u = (x - self.min) / range;
+        // The quantile at `p` of the underlying beta distribution, for the
+        // mode at `min + m * range`, relative to `u`
+        let g = |m: F| match Beta::new(one + shape * m, one + shape * (one - m)) {
+            Ok(beta) => beta.quantile(p) - u,
+            Err(_) => F::nan(),
+        };
+        // Allow for rounding in `u` when `x` is the quantile for an extreme
+        // mode
+        let tol = F::from(8.0).unwrap() * F::epsilon();
+        let (g0, g1) = (g(F::zero()), g(one));
+        if !(g0 <= tol && g1 >= -tol) {
+            return Err(PertError::QuantileRange);
+        }
+        let m = if g0 >= F::zero() {
+            F::zero()
+        } else if g1 <= F::zero() {
+            one
+        } else {
+            find_root(g, F::zero(), one, F::epsilon())
+        };
+        let mode = (self.min + m * range).min(self.max);
+        self.with_mode(mode)
+    }
+
     /// Specify the mode
     #[inline]
     pub fn with_mode(self, mode: F) -> Result<Pert<F>, PertError> {
@@ -169,6 +354,105 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for Pert<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        self.beta.ln_pdf((x - self.min) / self.range) - self.range.ln()
+    }
+}
+
+impl<F> Cdf<F> for Pert<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        self.beta.cdf((x - self.min) / self.range)
+    }
+
+    fn sf(&self, x: F) -> F {
+        self.beta.sf((x - self.min) / self.range)
+    }
+}
+
+impl<F> Quantile<F> for Pert<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        self.min + self.range * self.beta.quantile(p)
+    }
+}
+
+impl<F> Moments<F> for Pert<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some(self.min + self.range * self.beta.mean()?)
+    }
+
+    fn variance(&self) -> Option<F> {
+        Some(self.range * self.range * self.beta.variance()?)
+    }
+
+    fn skewness(&self) -> Option<F> {
+        self.beta.skewness()
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        self.beta.excess_kurtosis()
+    }
+}
+
+impl<F> Entropy<F> for Pert<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        self.beta.entropy() + self.range.ln()
+    }
+}
+
+impl<F> Summary<F> for Pert<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::Real {
+            lower: Bound::Included(self.min),
+            upper: Bound::Included(self.min + self.range),
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        self.beta.mode().map(|x| self.min + self.range * x)
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -185,6 +469,66 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_pert_pdf() {
+        let pert = Pert::new(-1.0, 3.0).with_mode(0.0).unwrap();
+        assert_almost_eq!(pert.pdf(1.5), 0.164794921875, 1e-14);
+        assert_almost_eq!(pert.pdf(0.0), 0.52734375, 1e-14);
+        assert_eq!(pert.pdf(-1.5), 0.0);
+        assert_eq!(pert.pdf(3.5), 0.0);
+    }
+
+    #[test]
+    fn test_pert_cdf() {
+        let pert = Pert::new(-1.0, 3.0).with_mode(0.0).unwrap();
+        assert_almost_eq!(pert.cdf(1.5), 0.9307861328125, 1e-15);
+        assert_almost_eq!(pert.sf(1.5), 0.0692138671875, 1e-15);
+        assert_eq!(pert.cdf(-1.5), 0.0);
+        assert_eq!(pert.sf(3.5), 0.0);
+    }
+
+    #[test]
+    fn test_pert_quantile() {
+        let pert = Pert::new(-1.0, 3.0).with_mode(0.0).unwrap();
+        assert_eq!(pert.quantile(0.0), -1.0);
+        assert_eq!(pert.quantile(1.0), 3.0);
+        for &p in &[1e-6, 0.3, 0.9307861328125, 0.999] {
+            assert_almost_eq!(pert.cdf(pert.quantile(p)), p, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_pert_moments() {
+        let pert = Pert::new(-1.0, 3.0).with_mode(0.0).unwrap();
+        // Mean `(min + 4 mode + max) / 6`
+        assert_almost_eq!(pert.mean().unwrap(), 1.0 / 3.0, 1e-15);
+        let beta = Beta::new(2.0, 4.0).unwrap();
+        assert_almost_eq!(
+            pert.variance().unwrap(),
+            16.0 * beta.variance().unwrap(),
+            1e-15
+        );
+        assert_eq!(pert.skewness(), beta.skewness());
+    }
+
+    #[test]
+    fn test_pert_entropy() {
+        let pert = Pert::new(0.0, 10.0).with_mode(3.0).unwrap();
+        assert_almost_eq!(pert.entropy(), 1.9761500070310035237, 1e-14);
+    }
+
+    #[test]
+    fn test_pert_summary() {
+        let d = Pert::new(0.0, 4.0).with_mode(1.0).unwrap();
+        let support = d.support();
+        assert_eq!((support.lower(), support.upper()), (0.0, 4.0));
+        assert_almost_eq!(d.mode().unwrap(), 1.0, 1e-15);
+        assert_almost_eq!(d.median(), 1.2552406818227897719, 1e-14);
+        // The uniform distribution
+        let d = Pert::new(0.0, 4.0).with_shape(0.0).with_mode(1.0).unwrap();
+        assert_eq!(d.mode(), None);
+    }
+
     #[test]
     fn distributions_can_be_compared() {
         let (min, mode, max, shape) = (1.0, 2.0, 3.0, 4.0);
@@ -210,4 +554,130 @@ mod test {
         let distr = Pert::new(0f32, 2f32).with_mode(1f32 + f32::EPSILON);
         assert!(distr.is_ok());
     }
+
+    #[test]
+    fn test_from_mean_variance() {
+        let pert = Pert::from_mean_variance(1.0, 7.0, 3.0, 1.0).unwrap();
+        assert_almost_eq!(pert.mean().unwrap(), 3.0, 1e-14);
+        assert_almost_eq!(pert.variance().unwrap(), 1.0, 1e-14);
+        // The default shape of 4 gives the variance (mean - min)(max - mean) / 7
+        let expected = Pert::new(0.0, 6.0).with_mode(1.5).unwrap();
+        let pert = Pert::from_mean_variance(0.0, 6.0, 2.0, 8.0 / 7.0).unwrap();
+        let ((a, b), (expected_a, expected_b)) = (pert.beta.params(), expected.beta.params());
+        assert_almost_eq!(a, expected_a, 1e-13);
+        assert_almost_eq!(b, expected_b, 1e-13);
+
+        assert_eq!(
+            Pert::from_mean_variance(0.0, 1.0, 0.0, 0.01),
+            Err(PertError::MeanRange)
+        );
+        assert_eq!(
+            Pert::from_mean_variance(0.0, 1.0, f64::NAN, 0.01),
+            Err(PertError::MeanRange)
+        );
+        assert_eq!(
+            Pert::from_mean_variance(0.0, 1.0, 0.5, 0.0),
+            Err(PertError::VarianceRange)
+        );
+        // Beta(0.5, 0.5) has a mode outside of (0, 1)
+        assert_eq!(
+            Pert::from_mean_variance(0.0, 1.0, 0.5, 0.125),
+            Err(PertError::VarianceRange)
+        );
+        assert_eq!(
+            Pert::from_mean_variance(1.0, 1.0, 1.0, 0.1),
+            Err(PertError::RangeTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_with_quantile() {
+        for &(mode, shape, p) in &[
+            (3.0, 4.0, 0.5),
+            (1.0, 4.0, 0.1),
+            (9.5, 2.0, 0.9),
+            (6.0, 10.0, 0.3),
+        ] {
+            let expected = Pert::new(1.0, 10.0)
+                .with_shape(shape)
+                .with_mode(mode)
+                .unwrap();
+            let x = expected.quantile(p);
+            let pert = Pert::new(1.0, 10.0)
+                .with_shape(shape)
+                .with_quantile(p, x)
+                .unwrap();
+            assert_almost_eq!(pert.quantile(p), x, 1e-12);
+            let ((a, b), (expected_a, expected_b)) = (pert.beta.params(), expected.beta.params());
+            assert_almost_eq!(a, expected_a, 1e-9);
+            assert_almost_eq!(b, expected_b, 1e-9);
+        }
+
+        let builder = || Pert::new(0.0, 1.0);
+        // With the mode at 0, the median is about 0.16
+        assert_eq!(
+            builder().with_quantile(0.5, 0.1),
+            Err(PertError::QuantileRange)
+        );
+        assert_eq!(
+            builder().with_quantile(0.0, 0.5),
+            Err(PertError::QuantileRange)
+        );
+        assert_eq!(
+            builder().with_quantile(0.5, f64::NAN),
+            Err(PertError::QuantileRange)
+        );
+        assert_eq!(
+            Pert::new(1.0, 0.0).with_quantile(0.5, 0.5),
+            Err(PertError::RangeTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_from_quantiles() {
+        for &(mode, shape) in &[(3.0, 4.0), (1.0, 4.0), (9.5, 2.0), (6.0, 10.0)] {
+            let expected = Pert::new(1.0, 10.0)
+                .with_shape(shape)
+                .with_mode(mode)
+                .unwrap();
+            let (a, b, c) = (
+                (0.1, expected.quantile(0.1)),
+                (0.5, expected.quantile(0.5)),
+                (0.8, expected.quantile(0.8)),
+            );
+            // In any order
+            let pert = Pert::from_quantiles(c, a, b, shape).unwrap();
+            assert_almost_eq!(pert.min, 1.0, 1e-9);
+            assert_almost_eq!(pert.min + pert.range, 10.0, 1e-9);
+            let ((a, b), (expected_a, expected_b)) = (pert.beta.params(), expected.beta.params());
+            assert_almost_eq!(a, expected_a, 1e-9);
+            assert_almost_eq!(b, expected_b, 1e-9);
+        }
+
+        // Median 10 with a 90% interval of [4, 30] is too skewed for the
+        // conventional shape, but not for a larger one
+        let (a, b, c) = ((0.05, 4.0), (0.5, 10.0), (0.95, 30.0));
+        assert_eq!(
+            Pert::from_quantiles(a, b, c, 4.0),
+            Err(PertError::QuantileRange)
+        );
+        let pert = Pert::from_quantiles(a, b, c, 30.0).unwrap();
+        for &(p, x) in &[a, b, c] {
+            assert_almost_eq!(pert.quantile(p), x, 1e-9);
+        }
+        assert!(pert.mode().unwrap() < 4.0);
+
+        assert_eq!(
+            Pert::from_quantiles((0.1, 1.0), (0.5, 3.0), (0.5, 4.0), 4.0),
+            Err(PertError::QuantileRange)
+        );
+        assert_eq!(
+            Pert::from_quantiles((0.1, 1.0), (0.5, 5.0), (0.9, 4.0), 4.0),
+            Err(PertError::QuantileRange)
+        );
+        assert_eq!(
+            Pert::from_quantiles((0.1, 1.0), (0.5, 3.0), (0.9, 4.0), f64::NAN),
+            Err(PertError::ShapeTooSmall)
+        );
+    }
 }
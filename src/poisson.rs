@@ -9,8 +9,17 @@
 
 //! The Poisson distribution `Poisson(λ)`.
 
-use crate::{Distribution, Exp1, Normal, StandardNormal, StandardUniform};
+use crate::divergence::deviance;
+use crate::entropy::lattice_entropy;
+use crate::fit::Counts;
+use crate::quantile::discrete_quantile;
+use crate::special::{gamma_pq, ln_poisson_raw, std_normal_quantile};
+use crate::{
+    Cdf, CharacteristicFunction, DiscretePmf, Distribution, Entropy, Exp1, FitError, KlDivergence,
+    Mgf, Moments, Normal, Quantile, StandardNormal, StandardUniform, Summary, Support,
+};
 use core::fmt;
+use num_complex::Complex;
 use num_traits::{Float, FloatConst};
 use rand::Rng;
 
@@ -86,12 +95,15 @@ impl std::error::Error for Error {}
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct KnuthMethod<F> {
+    // Stored as `-ln(exp_lambda)` loses the relative accuracy of small `lambda`
+    lambda: F,
     exp_lambda: F,
 }
 
 impl<F: Float> KnuthMethod<F> {
     pub(crate) fn new(lambda: F) -> Self {
         KnuthMethod {
+            lambda,
             exp_lambda: (-lambda).exp(),
         }
     }
@@ -183,6 +195,47 @@ where
     /// Applying this limit also solves
     /// [#1312](https://github.com/rust-random/rand/issues/1312).
     pub const MAX_LAMBDA: f64 = 1.844e19;
+
+    /// Returns the rate parameter `lambda`.
+    pub(crate) fn lambda(&self) -> F {
+        match &self.0 {
+            Method::Knuth(method) => method.lambda,
+            Method::Rejection(method) => method.lambda,
+        }
+    }
+
+    /// Fit to observed counts by maximum likelihood.
+    ///
+    /// The estimate of `lambda` is the sample mean.
+    ///
+    /// Fails if `data` is empty or its values are all zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Poisson;
+    ///
+    /// let poi = Poisson::fit(&[2, 0, 3, 1, 4]).unwrap();
+    /// assert_eq!(poi, Poisson::new(2.0).unwrap());
+    /// ```
+    pub fn fit(data: &[u64]) -> Result<Poisson<F>, FitError> {
+        Self::fit_counts(Counts::new(data.iter().map(|&k| (k, 1)))?)
+    }
+
+    /// Fit to observed counts, given as a frequency table of
+    /// `(value, frequency)` pairs, by maximum likelihood.
+    ///
+    /// See [`Poisson::fit`].
+    pub fn fit_frequencies(table: &[(u64, u64)]) -> Result<Poisson<F>, FitError> {
+        Self::fit_counts(Counts::new(table.iter().copied())?)
+    }
+
+    fn fit_counts(counts: Counts<F>) -> Result<Poisson<F>, FitError> {
+        if counts.max == 0 {
+            return Err(FitError::Constant);
+        }
+        Poisson::new(counts.mean).map_err(|_| FitError::OutOfSupport)
+    }
 }
 
 impl<F> Distribution<F> for KnuthMethod<F>
@@ -323,6 +376,141 @@ where
     }
 }
 
+impl<F> DiscretePmf<F> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn ln_pmf(&self, k: u64) -> F {
+        ln_poisson_raw(F::from(k).unwrap(), self.lambda())
+    }
+}
+
+impl<F> Cdf<F> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        if !(x >= F::zero()) {
+            return F::zero();
+        }
+        // P(X <= k) = Q(k + 1, λ)
+        gamma_pq(x.floor() + F::one(), self.lambda()).1
+    }
+
+    fn sf(&self, x: F) -> F {
+        if !(x >= F::zero()) {
+            return F::one();
+        }
+        gamma_pq(x.floor() + F::one(), self.lambda()).0
+    }
+}
+
+impl<F> Quantile<F> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        // Start from the normal approximation
+        let lambda = self.lambda();
+        let guess = lambda + std_normal_quantile(p) * lambda.sqrt();
+        discrete_quantile(self, p, F::zero(), F::infinity(), guess)
+    }
+}
+
+impl<F> Moments<F> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some(self.lambda())
+    }
+
+    fn variance(&self) -> Option<F> {
+        Some(self.lambda())
+    }
+
+    fn skewness(&self) -> Option<F> {
+        Some(self.lambda().sqrt().recip())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        Some(self.lambda().recip())
+    }
+}
+
+impl<F> Entropy<F> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        lattice_entropy(self)
+    }
+}
+
+impl<F> KlDivergence<F> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn kl_divergence(&self, other: &Self) -> F {
+        deviance(self.lambda(), other.lambda())
+    }
+}
+
+impl<F> Mgf<F> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn cgf(&self, t: F) -> Option<F> {
+        if t.is_nan() {
+            return None;
+        }
+        Some(self.lambda() * t.exp_m1())
+    }
+}
+
+impl<F> CharacteristicFunction<F> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn characteristic(&self, t: F) -> Complex<F> {
+        // exp(λ(e^it - 1)), with cos t - 1 = -2 sin²(t/2)
+        let lambda = self.lambda();
+        let s = (F::from(0.5).unwrap() * t).sin();
+        let r = (F::from(-2.0).unwrap() * lambda * s * s).exp();
+        Complex::from_polar(r, lambda * t.sin())
+    }
+}
+
+impl<F> Summary<F> for Poisson<F>
+where
+    F: Float + FloatConst,
+    StandardUniform: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::Integer {
+            lower: 0,
+            upper: None,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        // For integer λ both λ - 1 and λ are modes
+        Some(self.lambda().ceil() - F::one())
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -345,8 +533,136 @@ mod test {
         Poisson::new(-10.0).unwrap();
     }
 
+    #[test]
+    fn test_poisson_pmf() {
+        let poisson = Poisson::new(2.5).unwrap();
+        assert_almost_eq!(poisson.pmf(0), 0.082084998623898795169, 1e-16);
+        assert_almost_eq!(poisson.pmf(3), 0.21376301724973644575, 1e-15);
+        let total: f64 = (0..50).map(|k| poisson.pmf(k)).sum();
+        assert_almost_eq!(total, 1.0, 1e-14);
+
+        let poisson = Poisson::new(1e15).unwrap();
+        assert_almost_eq!(
+            poisson.ln_pmf(1_000_000_100_000_000),
+            -23.188326613993354622,
+            1e-6
+        );
+        assert_almost_eq!(Poisson::new(1e-3f32).unwrap().pmf(1), 9.990005e-4, 1e-9);
+    }
+
+    #[test]
+    fn test_poisson_cdf() {
+        let poisson = Poisson::new(2.5).unwrap();
+        assert_almost_eq!(poisson.cdf(0.0), 0.082084998623898795169, 1e-16);
+        assert_almost_eq!(poisson.cdf(3.5), 0.75757613313306596375, 1e-15);
+        assert_almost_eq!(poisson.sf(3.0), 0.24242386686693403625, 1e-15);
+        assert_almost_eq!(poisson.sf(20.0) / 4.1185531544822762179e-13, 1.0, 1e-13);
+        assert_eq!(poisson.cdf(-0.5), 0.0);
+        assert_eq!(poisson.sf(-0.5), 1.0);
+
+        let poisson = Poisson::new(1e6).unwrap();
+        assert_almost_eq!(poisson.cdf(1e6 + 1000.0), 0.84146567096342815212, 1e-13);
+    }
+
+    #[test]
+    fn test_poisson_quantile() {
+        let poisson = Poisson::new(3.5).unwrap();
+        assert_eq!(poisson.quantile(0.5), 3.0);
+        assert_eq!(poisson.quantile(1e-6), 0.0);
+        assert_eq!(poisson.quantile(0.999999), 15.0);
+        assert_eq!(poisson.quantile(0.0), 0.0);
+        assert_eq!(poisson.quantile(1.0), f64::INFINITY);
+        assert!(poisson.quantile(-0.5).is_nan());
+        let poisson = Poisson::new(1000.0).unwrap();
+        assert_eq!(poisson.quantile(0.5), 1000.0);
+        assert_eq!(poisson.quantile(0.01), 927.0);
+    }
+
+    #[test]
+    fn test_poisson_moments() {
+        let d = Poisson::new(3.5).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 3.5, 1e-15);
+        assert_almost_eq!(d.variance().unwrap(), 3.5, 1e-15);
+        assert_almost_eq!(d.skewness().unwrap(), 0.53452248382484876937, 1e-15);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 0.28571428571428571429, 1e-15);
+    }
+
+    #[test]
+    fn test_poisson_entropy() {
+        let d = Poisson::new(3.5).unwrap();
+        assert_almost_eq!(d.entropy(), 2.0151725225129722812, 1e-14);
+        let d = Poisson::new(1e-3).unwrap();
+        assert_almost_eq!(d.entropy(), 0.0079081018046324831039, 1e-16);
+        let d = Poisson::new(150.0).unwrap();
+        assert_almost_eq!(d.entropy(), 3.9236987569806772785, 1e-13);
+        let d = Poisson::new(1e6).unwrap();
+        assert_almost_eq!(d.entropy(), 8.3266937288534347938, 1e-13);
+        let d = Poisson::new(1e12).unwrap();
+        assert_almost_eq!(d.entropy(), 15.234449091168863513, 1e-13);
+    }
+
+    #[test]
+    fn test_poisson_kl_divergence() {
+        let p = Poisson::new(3.0).unwrap();
+        let q = Poisson::new(5.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 0.46752312870202795038, 1e-15);
+        assert_eq!(p.kl_divergence(&p), 0.0);
+        let p = Poisson::new(1e6).unwrap();
+        let q = Poisson::new(1e6 + 1.0).unwrap();
+        assert_almost_eq!(p.kl_divergence(&q), 4.9999966666691666647e-7, 1e-21);
+    }
+
+    #[test]
+    fn test_poisson_mgf() {
+        let d = Poisson::new(3.5).unwrap();
+        assert_almost_eq!(d.cgf(-1.0).unwrap(), -2.2124219558999518744, 1e-15);
+        assert_eq!(d.cgf(0.0), Some(0.0));
+        assert_eq!(d.cgf(f64::NAN), None);
+        let phi = d.characteristic(2.0);
+        assert_almost_eq!(phi.re, -0.0070315188377792291652, 1e-17);
+        assert_almost_eq!(phi.im, -0.00028809006412817317849, 1e-18);
+    }
+
+    #[test]
+    fn test_poisson_summary() {
+        let d = Poisson::new(3.5).unwrap();
+        assert_eq!(
+            d.support(),
+            Support::Integer {
+                lower: 0,
+                upper: None
+            }
+        );
+        assert_eq!(d.mode(), Some(3.0));
+        assert_eq!(d.median(), 3.0);
+        // Both 3 and 4 are modes
+        assert_eq!(Poisson::new(4.0).unwrap().mode(), Some(3.0));
+        assert_eq!(Poisson::new(0.5).unwrap().mode(), Some(0.0));
+    }
+
     #[test]
     fn poisson_distributions_can_be_compared() {
         assert_eq!(Poisson::new(1.0), Poisson::new(1.0));
     }
+
+    #[test]
+    fn test_poisson_fit() {
+        let d = Poisson::<f64>::fit(&[2, 0, 3, 1, 4, 5]).unwrap();
+        assert_eq!(d.lambda(), 2.5);
+        let d = Poisson::<f64>::fit_frequencies(&[(0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1)]);
+        assert_eq!(d.unwrap().lambda(), 2.5);
+
+        let mut rng = crate::test::rng(227);
+        let dist = Poisson::new(35.0).unwrap();
+        let data: [u64; 5000] = core::array::from_fn(|_| dist.sample(&mut rng) as u64);
+        let d = Poisson::<f64>::fit(&data).unwrap();
+        assert_almost_eq!(d.lambda(), 35.0, 0.3);
+
+        assert_eq!(Poisson::<f64>::fit(&[]), Err(FitError::Empty));
+        assert_eq!(
+            Poisson::<f64>::fit_frequencies(&[(3, 0)]),
+            Err(FitError::Empty)
+        );
+        assert_eq!(Poisson::<f64>::fit(&[0, 0]), Err(FitError::Constant));
+    }
 }
@@ -0,0 +1,594 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Poisson binomial distribution.
+
+use crate::quantile::solve_increasing;
+use crate::weighted::WeightedAliasIndex;
+use crate::{DiscretePmf, Distribution, Moments, StandardNormal};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use core::fmt;
+use num_complex::Complex;
+#[allow(unused_imports)]
+use num_traits::Float;
+use rand::Rng;
+
+/// The [Poisson binomial distribution](https://en.wikipedia.org/wiki/Poisson_binomial_distribution)
+/// `PoissonBinomial(p)`.
+///
+/// The number of successes in independent trials with the success
+/// probabilities `p₁, …, pₙ`, which generalizes the [`Binomial`]
+/// distribution to trials with different probabilities.
+///
+/// The probability mass function is evaluated exactly, up to rounding, by
+/// the discrete Fourier transform of the characteristic function. Unless
+/// samples follow the normal approximation below, the probabilities within
+/// `10 σ + 40` of the mean, the others summing to less than `1e-21`, are
+/// tabulated when the distribution is constructed, which takes
+/// `O(n min(n, σ))` operations for `n` trials with the standard deviation
+/// `σ`; [`PoissonBinomial::probabilities`] returns all probabilities at once.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, PoissonBinomial};
+///
+/// let pb = PoissonBinomial::new(vec![0.1, 0.5, 0.9, 0.3]).unwrap();
+/// let v = pb.sample(&mut rand::rng());
+/// println!("{} is from a Poisson binomial distribution", v);
+/// ```
+///
+/// # Implementation details
+///
+/// The sampling method depends on the number of trials and the variance:
+///
+/// - up to 16 trials, each trial is simulated with one uniform sample;
+/// - up to 1024 trials, or with a variance below 100, the tabulated
+///   probabilities are sampled with [`WeightedAliasIndex`];
+/// - otherwise, samples follow the refined normal approximation of Volkova,
+///   which corrects the normal approximation for the skewness `γ` with
+///   `P(X <= k) ≈ Φ(x) + γ (1 - x²) φ(x) / 6` at `x = (k + 1/2 - μ) / σ`; a
+///   standard normal sample `z` is transformed by the corresponding
+///   Cornish–Fisher expansion `z + γ (z² - 1) / 6`. The approximation is
+///   accurate when the variance is large.
+///
+/// [`Binomial`]: crate::Binomial
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoissonBinomial {
+    p: Vec<f64>,
+    /// The smallest number of successes in `pmf`
+    lo: u64,
+    /// The probabilities within `10 σ + 40` of the mean, unless sampled by
+    /// the refined normal approximation
+    pmf: Vec<f64>,
+    /// Tabulated probabilities below this are evaluated by exponential
+    /// tilting in `ln_pmf`
+    min_pmf: f64,
+    method: Method,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Method {
+    Direct,
+    /// The alias table of the tabulated probabilities
+    Table(WeightedAliasIndex<f64>),
+    RefinedNormal {
+        mean: f64,
+        std_dev: f64,
+        skewness: f64,
+    },
+}
+
+/// The largest number of trials that are simulated one by one.
+const DIRECT_MAX_N: usize = 16;
+
+/// The largest number of trials that are always sampled from the table.
+const TABLE_MAX_N: usize = 1024;
+
+/// The smallest variance for the refined normal approximation.
+const NORMAL_MIN_VARIANCE: f64 = 100.0;
+
+/// Tabulated probabilities below this fraction of the largest one are not
+/// accurate relative to their size.
+const MIN_RELATIVE_PMF: f64 = 1e-3;
+
+/// Error type returned from [`PoissonBinomial::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A probability is not in `[0, 1]` or is `nan`.
+    ProbabilityOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ProbabilityOutOfRange => {
+                "a probability is not in [0, 1] in Poisson binomial distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl PoissonBinomial {
+    /// Construct a new `PoissonBinomial` with the success probabilities `p`
+    /// of the trials.
+    ///
+    /// Requires all probabilities to be in `[0, 1]`. Without trials, the
+    /// number of successes is always zero.
+    pub fn new(p: Vec<f64>) -> Result<PoissonBinomial, Error> {
+        if !p.iter().all(|&p| (0.0..=1.0).contains(&p)) {
+            return Err(Error::ProbabilityOutOfRange);
+        }
+        let mut dist = PoissonBinomial {
+            p,
+            lo: 0,
+            pmf: Vec::new(),
+            min_pmf: 0.0,
+            method: Method::Direct,
+        };
+        let n = dist.p.len();
+        let variance = dist.variance().unwrap();
+
+        if n > TABLE_MAX_N && variance >= NORMAL_MIN_VARIANCE {
+            // Without a table, `ln_pmf` evaluates every probability by tilting
+            dist.method = Method::RefinedNormal {
+                mean: dist.mean().unwrap(),
+                std_dev: variance.sqrt(),
+                skewness: dist.skewness().unwrap_or(0.0),
+            };
+            return Ok(dist);
+        }
+
+        let (lo, pmf) = dist.tabulate();
+        let max = pmf.iter().fold(0.0, |m: f64, &f| m.max(f));
+        dist.lo = lo;
+        dist.min_pmf = MIN_RELATIVE_PMF * max;
+        if n > DIRECT_MAX_N {
+            dist.method = Method::Table(WeightedAliasIndex::new(pmf.clone()).unwrap());
+        }
+        dist.pmf = pmf;
+        Ok(dist)
+    }
+
+    /// Returns the probabilities `P(X = k)` for `k = 0, …, n`.
+    ///
+    /// These have an absolute error of a small multiple of the machine
+    /// epsilon; those further than `10 σ + 40` from the mean, which sum to
+    /// less than `1e-21`, are returned as zero. [`DiscretePmf::ln_pmf`]
+    /// evaluates small probabilities accurately.
+    ///
+    /// Unless the probabilities are tabulated for sampling, they are
+    /// evaluated on each call, which takes `O(n min(n, σ))` operations.
+    pub fn probabilities(&self) -> Vec<f64> {
+        let mut pmf = vec![0.0; self.p.len() + 1];
+        if self.pmf.is_empty() {
+            let (lo, table) = self.tabulate();
+            pmf[lo as usize..][..table.len()].copy_from_slice(&table);
+        } else {
+            pmf[self.lo as usize..][..self.pmf.len()].copy_from_slice(&self.pmf);
+        }
+        pmf
+    }
+
+    /// The smallest number of successes within `10 σ + 40` of the mean and
+    /// the probabilities from there to the largest one.
+    fn tabulate(&self) -> (u64, Vec<f64>) {
+        let mean = self.mean().unwrap();
+        let half_width = half_width(self.variance().unwrap());
+        let (certain, possible) = self.bounds();
+        let lo = certain.max((mean - half_width).floor().max(0.0) as u64);
+        let hi = possible.min((mean + half_width).ceil() as u64);
+        let m = (hi - lo + 1) as usize;
+        let xi = characteristic_values(&self.p, m);
+        let pmf = (lo..=hi)
+            .map(|k| pmf_from_characteristic(&xi, m, k))
+            .collect();
+        (lo, pmf)
+    }
+
+    /// The number of certain trials and the number of possible trials, the
+    /// smallest and largest number of successes.
+    fn bounds(&self) -> (u64, u64) {
+        let certain = self.p.iter().filter(|&&p| p == 1.0).count() as u64;
+        let possible = self.p.iter().filter(|&&p| p > 0.0).count() as u64;
+        (certain, possible)
+    }
+
+    /// `ln P(X = k)` by exponential tilting, for small probabilities or
+    /// without a table.
+    ///
+    /// With the tilted probabilities `pⱼ e^θ / (1 - pⱼ + pⱼ e^θ)`,
+    /// `P(X = k)` is `e^(-θ k) ∏ (1 - pⱼ + pⱼ e^θ)` times the tilted
+    /// probability of `k`. For the `θ` at which the tilted mean is `k`, the
+    /// tilted probability of `k` is close to the largest one, so that its
+    /// discrete Fourier transform is accurate.
+    fn ln_pmf_tilted(&self, k: u64) -> f64 {
+        let (certain, possible) = self.bounds();
+        if k < certain || k > possible {
+            return f64::NEG_INFINITY;
+        }
+        if k == certain {
+            return self
+                .p
+                .iter()
+                .filter(|&&p| p < 1.0)
+                .map(|&p| (-p).ln_1p())
+                .sum();
+        }
+        if k == possible {
+            return self.p.iter().filter(|&&p| p > 0.0).map(|&p| p.ln()).sum();
+        }
+        // `ln(1 - p + p e^θ)` and the tilted probability of a trial
+        let tilt = |p: f64, theta: f64| {
+            if p == 0.0 || p == 1.0 {
+                return (p * theta, p);
+            }
+            let (a, b) = ((-p).ln_1p(), p.ln() + theta);
+            let ln_norm = a.max(b) + (-(a - b).abs()).exp().ln_1p();
+            (ln_norm, (b - ln_norm).exp())
+        };
+        let k_f = k as f64;
+        let tilted_mean = |theta| self.p.iter().map(|&p| tilt(p, theta).1).sum::<f64>() - k_f;
+        // `|θ|` stays below about 750 + ln n, as `|ln(pⱼ / (1 - pⱼ))|` does
+        let theta = match solve_increasing(tilted_mean, 0.0) {
+            Some(theta) => theta,
+            None => return f64::NAN,
+        };
+        let ln_norm: f64 = self.p.iter().map(|&p| tilt(p, theta).0).sum();
+        let tilted: Vec<f64> = self.p.iter().map(|&p| tilt(p, theta).1).collect();
+        let variance = tilted.iter().map(|&p| p * (1.0 - p)).sum();
+        // The aliased probabilities of `k ± m, k ± 2m, …` are negligible
+        let m = (half_width(variance).ceil() as usize + 1).min(self.p.len() + 1);
+        let xi = characteristic_values(&tilted, m);
+        pmf_from_characteristic(&xi, m, k).ln() - theta * k_f + ln_norm
+    }
+}
+
+/// The distance `10 σ + 40` from the mean beyond which the probabilities of
+/// a Poisson binomial distribution with the given variance sum to less than
+/// `1e-21`, by Bernstein's inequality.
+fn half_width(variance: f64) -> f64 {
+    10.0 * variance.sqrt() + 40.0
+}
+
+/// The values `∏ (1 - pⱼ + pⱼ e^(iωl))` of the characteristic function at
+/// `ωl`, for `ω = 2π / m` and `l = 0, …, ⌊m / 2⌋`, the others being their
+/// complex conjugates.
+fn characteristic_values(p: &[f64], m: usize) -> Vec<Complex<f64>> {
+    (0..=m / 2)
+        .map(|l| {
+            let t = 2.0 * PI * l as f64 / m as f64;
+            let s = (0.5 * t).sin();
+            let (sin_t, cos_t) = t.sin_cos();
+            // In polar form, as for the binomial distribution
+            let (ln_r, theta) = p.iter().fold((0.0, 0.0), |(ln_r, theta), &p| {
+                let q = 1.0 - p;
+                (
+                    ln_r + 0.5 * (-4.0 * p * q * s * s).ln_1p(),
+                    theta + (p * sin_t).atan2(q + p * cos_t),
+                )
+            });
+            Complex::from_polar(ln_r.exp(), theta)
+        })
+        .collect()
+}
+
+/// The sum of the probabilities `P(X = k + jm)` over all integers `j`, by the
+/// inverse discrete Fourier transform of the `characteristic_values`,
+/// clamped to be non-negative.
+///
+/// This is `P(X = k)` when the other probabilities are negligible.
+fn pmf_from_characteristic(xi: &[Complex<f64>], m: usize, k: u64) -> f64 {
+    let k = (k % m as u64) as usize;
+    let sum = xi.iter().enumerate().skip(1).fold(0.0, |sum, (l, xi)| {
+        // The angle reduced modulo 2π, for accuracy
+        let t = 2.0 * PI * ((l * k) % m) as f64 / m as f64;
+        let term = (xi * Complex::from_polar(1.0, -t)).re;
+        // The term for l = m / 2 is its own conjugate
+        if 2 * l == m {
+            sum + term
+        } else {
+            sum + 2.0 * term
+        }
+    });
+    ((1.0 + sum) / m as f64).max(0.0)
+}
+
+impl Distribution<u64> for PoissonBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        match &self.method {
+            Method::Direct => self.p.iter().filter(|&&p| rng.random::<f64>() < p).count() as u64,
+            Method::Table(alias) => self.lo + alias.sample(rng) as u64,
+            &Method::RefinedNormal {
+                mean,
+                std_dev,
+                skewness,
+            } => {
+                let z: f64 = rng.sample(StandardNormal);
+                let x = z + skewness * (z * z - 1.0) / 6.0;
+                // The smallest k with k + 1/2 >= μ + σ x
+                let k = (mean + std_dev * x - 0.5).ceil();
+                k.max(0.0).min(self.p.len() as f64) as u64
+            }
+        }
+    }
+}
+
+impl PartialEq for PoissonBinomial {
+    fn eq(&self, other: &Self) -> bool {
+        self.p == other.p
+    }
+}
+
+/// Tabulated probabilities are looked up, except those below `1e-3` times
+/// the largest one. The others, and all of them without a table, are
+/// evaluated by a discrete Fourier transform of an exponentially tilted
+/// distribution, which takes `O(n min(n, σ'))` operations, for the standard
+/// deviation `σ'` of the tilted distribution.
+impl DiscretePmf<f64> for PoissonBinomial {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        let index = k.checked_sub(self.lo).map(|i| i as usize);
+        match index.and_then(|i| self.pmf.get(i)) {
+            Some(&f) if f >= self.min_pmf => f.ln(),
+            _ => self.ln_pmf_tilted(k),
+        }
+    }
+}
+
+impl Moments<f64> for PoissonBinomial {
+    fn mean(&self) -> Option<f64> {
+        Some(self.p.iter().sum())
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some(self.p.iter().map(|&p| p * (1.0 - p)).sum())
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let var = self.variance().unwrap();
+        if var == 0.0 {
+            return None;
+        }
+        let c3: f64 = self
+            .p
+            .iter()
+            .map(|&p| p * (1.0 - p) * (1.0 - 2.0 * p))
+            .sum();
+        Some(c3 / (var * var.sqrt()))
+    }
+
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let var = self.variance().unwrap();
+        if var == 0.0 {
+            return None;
+        }
+        let c4: f64 = self
+            .p
+            .iter()
+            .map(|&p| {
+                let pq = p * (1.0 - p);
+                pq * (1.0 - 6.0 * pq)
+            })
+            .sum();
+        Some(c4 / (var * var))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    /// The probabilities by successive convolution.
+    fn convolve(p: &[f64]) -> Vec<f64> {
+        let mut pmf = vec![1.0];
+        for &p in p {
+            let mut next = vec![0.0; pmf.len() + 1];
+            for (k, &f) in pmf.iter().enumerate() {
+                next[k] += f * (1.0 - p);
+                next[k + 1] += f * p;
+            }
+            pmf = next;
+        }
+        pmf
+    }
+
+    /// The logarithms of the probabilities by successive convolution.
+    fn ln_convolve(p: &[f64]) -> Vec<f64> {
+        let ln_add = |a: f64, b: f64| {
+            if a == f64::NEG_INFINITY {
+                b
+            } else {
+                a.max(b) + (-(a - b).abs()).exp().ln_1p()
+            }
+        };
+        let mut ln_pmf = vec![0.0];
+        for &p in p {
+            let mut next = vec![f64::NEG_INFINITY; ln_pmf.len() + 1];
+            for (k, &l) in ln_pmf.iter().enumerate() {
+                next[k] = ln_add(next[k], l + (-p).ln_1p());
+                next[k + 1] = ln_add(next[k + 1], l + p.ln());
+            }
+            ln_pmf = next;
+        }
+        ln_pmf
+    }
+
+    fn probabilities(n: usize) -> Vec<f64> {
+        (0..n).map(|i| ((i * 37) % 100) as f64 / 99.0).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(matches!(
+            PoissonBinomial::new(probabilities(16)).unwrap().method,
+            Method::Direct
+        ));
+        assert!(matches!(
+            PoissonBinomial::new(probabilities(17)).unwrap().method,
+            Method::Table(..)
+        ));
+        let pb = PoissonBinomial::new(probabilities(1025)).unwrap();
+        assert!(matches!(pb.method, Method::RefinedNormal { .. }));
+        assert!(pb.pmf.is_empty());
+        // Construction takes linear time without a table
+        let pb = PoissonBinomial::new(probabilities(1_000_000)).unwrap();
+        assert!(matches!(pb.method, Method::RefinedNormal { .. }));
+        assert!(pb.sample(&mut crate::test::rng(256)) <= 1_000_000);
+        // Many trials with a small variance
+        let pb = PoissonBinomial::new(vec![1e-4; 2000]).unwrap();
+        assert!(matches!(pb.method, Method::Table(..)));
+        assert_eq!(pb.clone(), pb);
+        assert_ne!(pb, PoissonBinomial::new(vec![1e-4; 1999]).unwrap());
+        assert_eq!(
+            PoissonBinomial::new(vec![0.5, 1.5]).unwrap_err(),
+            Error::ProbabilityOutOfRange
+        );
+        assert_eq!(
+            PoissonBinomial::new(vec![f64::NAN]).unwrap_err(),
+            Error::ProbabilityOutOfRange
+        );
+    }
+
+    #[test]
+    fn test_pmf() {
+        for &n in &[0, 1, 2, 5, 16, 100] {
+            let p = probabilities(n);
+            let expected = convolve(&p);
+            let pb = PoissonBinomial::new(p).unwrap();
+            let pmf = pb.probabilities();
+            assert_eq!(pmf.len(), n + 1);
+            for (k, (&f, &e)) in pmf.iter().zip(expected.iter()).enumerate() {
+                assert_almost_eq!(f, e, 1e-14);
+                assert_almost_eq!(pb.pmf(k as u64), e, 1e-14);
+            }
+            assert_eq!(pb.pmf(n as u64 + 1), 0.0);
+        }
+
+        let pb = PoissonBinomial::new(vec![0.2; 10]).unwrap();
+        let binomial = crate::Binomial::new(10, 0.2).unwrap();
+        for k in 0..=10 {
+            assert_almost_eq!(pb.pmf(k), binomial.pmf(k), 1e-15);
+        }
+
+        // Far below the largest probability
+        let pb = PoissonBinomial::new(vec![0.5; 100]).unwrap();
+        assert_almost_eq!(pb.ln_pmf(100), -69.31471805599453, 1e-12);
+        assert_almost_eq!(pb.ln_pmf(90), -38.832394693715884, 1e-12);
+    }
+
+    #[test]
+    fn test_ln_pmf_tail() {
+        // Probabilities below the table, including those of certain and
+        // impossible trials
+        let p = probabilities(400);
+        let expected = ln_convolve(&p);
+        let pb = PoissonBinomial::new(p).unwrap();
+        assert!(pb.lo > 0 || pb.pmf.len() < 401);
+        for (k, &e) in expected.iter().enumerate() {
+            let ln_f = pb.ln_pmf(k as u64);
+            if e == f64::NEG_INFINITY {
+                assert_eq!(ln_f, e);
+            } else {
+                assert_almost_eq!(ln_f, e, 1e-12 * e.abs().max(1.0));
+            }
+        }
+
+        let pb = PoissonBinomial::new(vec![0.3; 2000]).unwrap();
+        let binomial = crate::Binomial::new(2000, 0.3).unwrap();
+        for &k in &[0, 1, 5, 100, 600, 1500, 1999, 2000] {
+            let e = binomial.ln_pmf(k);
+            assert_almost_eq!(pb.ln_pmf(k), e, 1e-11 * e.abs().max(1.0));
+        }
+        // Evaluated on demand without a table
+        let pmf = pb.probabilities();
+        assert_eq!(pmf.len(), 2001);
+        for &k in &[500, 600, 700] {
+            assert_almost_eq!(pmf[k], binomial.pmf(k as u64), 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_moments() {
+        let pb = PoissonBinomial::new(vec![0.1, 0.5, 0.8]).unwrap();
+        assert_almost_eq!(pb.mean().unwrap(), 1.4, 1e-15);
+        assert_almost_eq!(pb.variance().unwrap(), 0.09 + 0.25 + 0.16, 1e-15);
+        // Central moments of the distribution from its probabilities
+        let pmf = convolve(&[0.1, 0.5, 0.8]);
+        let central = |j: i32| -> f64 {
+            pmf.iter()
+                .enumerate()
+                .map(|(k, f)| f * (k as f64 - 1.4).powi(j))
+                .sum()
+        };
+        let var = central(2);
+        assert_almost_eq!(pb.skewness().unwrap(), central(3) / var.powf(1.5), 1e-14);
+        assert_almost_eq!(
+            pb.excess_kurtosis().unwrap(),
+            central(4) / (var * var) - 3.0,
+            1e-14
+        );
+        let pb = PoissonBinomial::new(vec![0.0, 1.0]).unwrap();
+        assert_eq!(pb.skewness(), None);
+    }
+
+    #[test]
+    fn test_sample() {
+        const SAMPLES: usize = 50_000;
+        let mut rng = crate::test::rng(252);
+        // Each sampling method
+        for &n in &[10, 500, 4000] {
+            let pb = PoissonBinomial::new(probabilities(n)).unwrap();
+            let (mean, var) = (pb.mean().unwrap(), pb.variance().unwrap());
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..SAMPLES {
+                let x = pb.sample(&mut rng);
+                assert!(x <= n as u64);
+                let x = x as f64;
+                sum += x;
+                sum_sq += (x - mean) * (x - mean);
+            }
+            assert_almost_eq!(sum / SAMPLES as f64, mean, 0.005 * mean);
+            assert_almost_eq!(sum_sq / SAMPLES as f64, var, 0.03 * var);
+        }
+
+        // Many trials with a small variance
+        let pb = PoissonBinomial::new(vec![1e-4; 2000]).unwrap();
+        let mut rng = crate::test::rng(255);
+        let mut hist = [0u64; 5];
+        for _ in 0..SAMPLES {
+            let x = pb.sample(&mut rng) as usize;
+            if x < hist.len() {
+                hist[x] += 1;
+            }
+        }
+        // binomial(2000, k) 10^(-4k) (1 - 10^(-4))^(2000 - k)
+        let expected = [
+            0.8187225652655495,
+            0.16376088914202414,
+            0.016369537823527663,
+        ];
+        for (&h, &e) in hist.iter().zip(expected.iter()) {
+            assert_almost_eq!(h as f64 / SAMPLES as f64, e, 0.005);
+        }
+        assert!(hist[3] + hist[4] < 150);
+
+        let pb = PoissonBinomial::new(vec![]).unwrap();
+        assert_eq!(pb.sample(&mut rng), 0);
+        let pb = PoissonBinomial::new(vec![1.0; 20]).unwrap();
+        assert_eq!(pb.sample(&mut rng), 20);
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Quantile functions and sampling by inversion.
+
+use crate::utils::find_root;
+use crate::{Cdf, ContinuousPdf};
+use num_traits::Float;
+use rand::distr::{Distribution, Open01};
+use rand::Rng;
+
+/// The quantile function (inverse CDF) of a univariate distribution.
+///
+/// `quantile(p)` is the smallest `x` in the support with `cdf(x) >= p`. For
+/// continuous distributions this is the inverse of [`Cdf::cdf`]; for discrete
+/// distributions the result is always an integer-valued outcome.
+///
+/// `quantile(0)` and `quantile(1)` are the lower and upper bounds of the
+/// support (possibly infinite). A probability outside `[0, 1]`, or NaN,
+/// yields NaN.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Exp, Quantile};
+///
+/// let exp = Exp::new(2.0f64).unwrap();
+/// assert_eq!(exp.quantile(0.0), 0.0);
+/// assert!((exp.quantile(0.5) - core::f64::consts::LN_2 / 2.0).abs() < 1e-15);
+///
+/// // Each sample consumes exactly one uniform variate
+/// let x = exp.sample_by_inversion(&mut rand::rng());
+/// assert!(x > 0.0);
+/// ```
+pub trait Quantile<F: Float> {
+    /// Evaluate the quantile function at probability `p`.
+    fn quantile(&self, p: F) -> F;
+
+    /// Generate a sample by transforming a single uniform variate on the
+    /// open interval `(0, 1)` through [`Quantile::quantile`].
+    ///
+    /// Unlike [`Distribution::sample`], which may use rejection and consume a
+    /// variable number of random values, this always draws exactly one
+    /// uniform per sample and is monotone in it. This makes it suitable for
+    /// common random numbers, antithetic variates and copula constructions.
+    fn sample_by_inversion<R: Rng + ?Sized>(&self, rng: &mut R) -> F
+    where
+        Open01: Distribution<F>,
+    {
+        self.quantile(rng.sample(Open01))
+    }
+}
+
+/// Invert the CDF of a continuous distribution with support `(lo, hi)`
+/// numerically.
+///
+/// The root is bracketed starting from `guess` and refined with Brent's
+/// method on a transformed scale (logit for bounded supports, logarithmic
+/// for half-bounded supports, linear with step `scale` for the real line),
+/// then polished with Newton steps using the density. For `p > 0.5` the
+/// survival function is used so upper-tail quantiles keep full precision.
+///
+/// Handles `p` outside of `(0, 1)` as documented on [`Quantile`].
+pub(crate) fn invert_cdf<F, D>(dist: &D, p: F, lo: F, hi: F, guess: F, scale: F) -> F
+where
+    F: Float,
+    D: Cdf<F> + ContinuousPdf<F> + ?Sized,
+{
+    let (zero, one, half) = (F::zero(), F::one(), F::from(0.5).unwrap());
+    if !(p > zero && p < one) {
+        return if p == zero {
+            lo
+        } else if p == one {
+            hi
+        } else {
+            F::nan()
+        };
+    }
+    let upper = p > half;
+    let q = one - p;
+    let g = |x: F| {
+        if upper {
+            q - dist.sf(x)
+        } else {
+            dist.cdf(x) - p
+        }
+    };
+
+    let to_x = |t: F| {
+        if lo.is_finite() && hi.is_finite() {
+            lo + (hi - lo) / (one + (-t).exp())
+        } else if lo.is_finite() {
+            lo + t.exp()
+        } else if hi.is_finite() {
+            hi - (-t).exp()
+        } else {
+            guess + scale * t
+        }
+    };
+    let from_x = |x: F| {
+        if lo.is_finite() && hi.is_finite() {
+            let u = (x - lo) / (hi - lo);
+            (u / (one - u)).ln()
+        } else if lo.is_finite() {
+            (x - lo).ln()
+        } else if hi.is_finite() {
+            -(hi - x).ln()
+        } else {
+            (x - guess) / scale
+        }
+    };
+
+    // Bracket the root in t
+    let t0 = from_x(guess);
+    let t0 = if t0.is_finite() { t0 } else { zero };
+    let g0 = g(to_x(t0));
+    if g0 == zero {
+        return to_x(t0);
+    }
+    let dir = if g0 < zero { one } else { -one };
+    let mut a;
+    let mut b = t0;
+    let mut step = one;
+    loop {
+        a = b;
+        b = t0 + dir * step;
+        // This may reach a bound of the support, where `g` has the sign
+        // needed to complete the bracket
+        let gb = g(to_x(b));
+        if (gb >= zero) == (dir > zero) {
+            break;
+        }
+        if !(step < F::max_value()) {
+            return to_x(b);
+        }
+        step = step + step;
+    }
+    let t = find_root(|t| g(to_x(t)), a, b, F::min_positive_value());
+    let mut x = to_x(t);
+
+    // Polish in x: the transformed scale may lose a few ulps
+    for _ in 0..3 {
+        let gx = g(x);
+        let d = dist.pdf(x);
+        if gx == zero || !(d > zero) {
+            break;
+        }
+        let x1 = x - gx / d;
+        if !(x1 > lo && x1 < hi && g(x1).abs() < gx.abs()) {
+            break;
+        }
+        x = x1;
+    }
+    x
+}
+
+/// Compute the quantile of a discrete distribution supported on the integers
+/// in `[lo, hi]`, where `hi` may be infinite.
+///
+/// Returns the smallest integer `k` with `cdf(k) >= p`, found by galloping
+/// from `guess` and then bisecting. For `p > 0.5` the equivalent condition
+/// `sf(k) <= 1 - p` is used instead.
+pub(crate) fn discrete_quantile<F, D>(dist: &D, p: F, lo: F, hi: F, guess: F) -> F
+where
+    F: Float,
+    D: Cdf<F> + ?Sized,
+{
+    let (one, half) = (F::one(), F::from(0.5).unwrap());
+    if !(p >= F::zero() && p <= one) {
+        return F::nan();
+    }
+    if p == one {
+        return hi;
+    }
+    let q = one - p;
+    let pred = |k: F| {
+        if p > half {
+            dist.sf(k) <= q
+        } else {
+            dist.cdf(k) >= p
+        }
+    };
+
+    let guess = if guess.is_finite() { guess.round() } else { lo };
+    let k = guess.max(lo).min(hi);
+    // Find a (predicate false, or below lo) and b (predicate true)
+    let (mut a, mut b);
+    let mut step = one;
+    if pred(k) {
+        b = k;
+        loop {
+            a = b - step;
+            if a < lo {
+                a = lo - one;
+                break;
+            }
+            if !pred(a) {
+                break;
+            }
+            b = a;
+            step = step + step;
+        }
+    } else {
+        a = k;
+        loop {
+            b = a + step;
+            if !(b < hi) {
+                b = hi;
+                break;
+            }
+            if pred(b) {
+                break;
+            }
+            a = b;
+            step = step + step;
+        }
+    }
+    while b - a > one {
+        let mid = ((a + b) * half).floor();
+        if !(mid > a && mid < b) {
+            break;
+        }
+        if pred(mid) {
+            b = mid;
+        } else {
+            a = mid;
+        }
+    }
+    b
+}
+
+/// Validate two `(probability, value)` quantile constraints and order them by
+/// probability.
+///
+/// Returns `None` unless the probabilities are distinct and in `(0, 1)` and
+/// the values are finite and strictly increasing with the probabilities.
+pub(crate) fn order_quantiles<F: Float>(a: (F, F), b: (F, F)) -> Option<((F, F), (F, F))> {
+    let (lower, upper) = if a.0 < b.0 { (a, b) } else { (b, a) };
+    let valid = lower.0 > F::zero()
+        && upper.0 < F::one()
+        && lower.0 < upper.0
+        && lower.1.is_finite()
+        && upper.1.is_finite()
+        && lower.1 < upper.1;
+    if valid {
+        Some((lower, upper))
+    } else {
+        None
+    }
+}
+
+/// Solve `f(t) = 0` for a function `f` increasing on the real line.
+///
+/// The root is bracketed by steps of doubling length from `guess` and then
+/// located with Brent's method to within `F::epsilon()`. Returns `None` if no
+/// bracket is found within `2^11` of `guess` or `f` yields NaN.
+pub(crate) fn solve_increasing<F, G>(mut f: G, guess: F) -> Option<F>
+where
+    F: Float,
+    G: FnMut(F) -> F,
+{
+    let (zero, one) = (F::zero(), F::one());
+    let f0 = f(guess);
+    if f0 == zero {
+        return Some(guess);
+    }
+    if f0.is_nan() {
+        return None;
+    }
+    let dir = if f0 < zero { one } else { -one };
+    let mut a;
+    let mut b = guess;
+    let mut step = one;
+    for _ in 0..12 {
+        a = b;
+        b = guess + dir * step;
+        let fb = f(b);
+        if fb.is_nan() {
+            return None;
+        }
+        if (fb >= zero) == (dir > zero) {
+            return Some(find_root(f, a, b, F::epsilon()));
+        }
+        step = step + step;
+    }
+    None
+}
@@ -8,7 +8,14 @@
 
 //! The Skew Normal distribution `SN(ξ, ω, α)`.
 
-use crate::{Distribution, StandardNormal};
+use crate::entropy::numeric_entropy;
+use crate::quantile::invert_cdf;
+use crate::special::{ln_erfc, std_normal_cdf, LN_SQRT_2PI};
+use crate::utils::{find_root, integrate};
+use crate::{
+    Cdf, ContinuousPdf, Distribution, Entropy, Moments, Quantile, StandardNormal, Summary, Support,
+};
+use core::f64::consts::{FRAC_2_PI, PI};
 use core::fmt;
 use num_traits::Float;
 use rand::Rng;
@@ -132,6 +139,13 @@ where
     pub fn shape(&self) -> F {
         self.shape
     }
+
+    /// `δ √(2 / π)` with `δ = α / √(1 + α²)`, the mean of the standardized
+    /// distribution.
+    fn standard_mean(&self) -> F {
+        let delta = self.shape / (F::one() + self.shape * self.shape).sqrt();
+        delta * F::from(FRAC_2_PI).unwrap().sqrt()
+    }
 }
 
 impl<F> Distribution<F> for SkewNormal<F>
@@ -161,6 +175,186 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for SkewNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        let z = (x - self.location) / self.scale;
+        // ln(2 * Phi(y)) = ln(erfc(-y / sqrt(2)))
+        let ln_two_phi = ln_erfc(-self.shape * z / F::from(core::f64::consts::SQRT_2).unwrap());
+        -F::from(0.5).unwrap() * z * z - F::from(LN_SQRT_2PI).unwrap() - self.scale.ln()
+            + ln_two_phi
+    }
+}
+
+impl<F> Cdf<F> for SkewNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        let z = (x - self.location) / self.scale;
+        if self.shape < F::zero() {
+            standard_upper(-z, -self.shape)
+        } else {
+            standard_lower(z, self.shape)
+        }
+    }
+
+    fn sf(&self, x: F) -> F {
+        let z = (x - self.location) / self.scale;
+        if self.shape < F::zero() {
+            standard_lower(-z, -self.shape)
+        } else {
+            standard_upper(z, self.shape)
+        }
+    }
+}
+
+impl<F> Quantile<F> for SkewNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        let (lo, hi) = (F::neg_infinity(), F::infinity());
+        invert_cdf(self, p, lo, hi, self.location, self.scale)
+    }
+}
+
+impl<F> Moments<F> for SkewNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some(self.location + self.scale * self.standard_mean())
+    }
+
+    fn variance(&self) -> Option<F> {
+        let m = self.standard_mean();
+        Some(self.scale * self.scale * (F::one() - m * m))
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let m = self.standard_mean();
+        let v = F::one() - m * m;
+        let c = F::from((4.0 - PI) / 2.0).unwrap();
+        Some(c * m * m * m / (v * v.sqrt()))
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let m2 = self.standard_mean().powi(2);
+        let v = F::one() - m2;
+        let c = F::from(2.0 * (PI - 3.0)).unwrap();
+        Some(c * m2 * m2 / (v * v))
+    }
+}
+
+impl<F> Entropy<F> for SkewNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // For large `|α|` the density drops sharply at the location
+        numeric_entropy(
+            |x| self.ln_pdf(x),
+            F::neg_infinity(),
+            self.location,
+            self.scale,
+        )
+    }
+}
+
+impl<F> Summary<F> for SkewNormal<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::real_line()
+    }
+
+    fn mode(&self) -> Option<F> {
+        // For the standardized distribution with |α| the mode is the root of
+        // the derivative of the log-density, -z + |α| φ(|α|z) / Φ(|α|z),
+        // which lies in [0, |α| φ(0) / Φ(0)] since φ / Φ is decreasing
+        let a = self.shape.abs();
+        let c = F::from(FRAC_2_PI).unwrap().sqrt();
+        let sqrt_2 = F::from(core::f64::consts::SQRT_2).unwrap();
+        let slope = |z: F| {
+            let y = a * z;
+            let ln_ratio = -F::from(0.5).unwrap() * y * y - ln_erfc(-y / sqrt_2);
+            a * c * ln_ratio.exp() - z
+        };
+        let z = if a == F::zero() {
+            F::zero()
+        } else {
+            find_root(slope, F::zero(), a * c, F::zero())
+        };
+        let z = if self.shape < F::zero() { -z } else { z };
+        Some(self.location + self.scale * z)
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
+/// `P(Z <= z)` for `Z ~ SN(0, 1, α)` with `α >= 0`.
+fn standard_lower<F: Float>(z: F, alpha: F) -> F {
+    let (zero, one, half) = (F::zero(), F::one(), F::from(0.5).unwrap());
+    let pi = F::from(core::f64::consts::PI).unwrap();
+    if alpha == zero {
+        return std_normal_cdf(z);
+    }
+    if z < zero {
+        // Φ(z) - 2 T(z, α) = (1/π) ∫₀^atan(1/α) exp(-z² / (2 sin²θ)) dθ
+        let integral = integrate(
+            |theta: F| (-half * z * z / (theta.sin() * theta.sin())).exp(),
+            zero,
+            alpha.recip().atan(),
+        );
+        integral / pi
+    } else if z <= one {
+        // F(0) = atan(1/α) / π, plus the integral of the density over (0, z)
+        let integral = integrate(
+            |t: F| {
+                let ln_density = -half * t * t - F::from(LN_SQRT_2PI).unwrap();
+                F::from(2.0).unwrap() * ln_density.exp() * std_normal_cdf(alpha * t)
+            },
+            zero,
+            z,
+        );
+        alpha.recip().atan() / pi + integral
+    } else {
+        one - standard_upper(z, alpha)
+    }
+}
+
+/// `P(Z > z)` for `Z ~ SN(0, 1, α)` with `α >= 0`.
+fn standard_upper<F: Float>(z: F, alpha: F) -> F {
+    let (zero, half) = (F::zero(), F::from(0.5).unwrap());
+    if alpha == zero {
+        return std_normal_cdf(-z);
+    }
+    if z > F::one() {
+        // Φ(-z) + 2 T(z, α), where Owen's T function is
+        // T(h, a) = (1/2π) ∫₀^atan(a) exp(-h² / (2 cos²θ)) dθ
+        let integral = integrate(
+            |theta: F| (-half * z * z / (theta.cos() * theta.cos())).exp(),
+            zero,
+            alpha.atan(),
+        );
+        std_normal_cdf(-z) + integral / F::from(core::f64::consts::PI).unwrap()
+    } else {
+        F::one() - standard_lower(z, alpha)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,6 +368,82 @@ mod tests {
         assert_eq!(buf, expected);
     }
 
+    #[test]
+    fn skew_normal_pdf() {
+        let skew_normal = SkewNormal::new(2.0, 3.0, 4.0).unwrap();
+        assert_almost_eq!(skew_normal.pdf(1.0), 0.022947723001308547956, 1e-15);
+        let skew_normal = SkewNormal::new(0.0, 1.0, 5.0).unwrap();
+        assert_almost_eq!(skew_normal.ln_pdf(-40.0), -20806.44307225083513, 1e-9);
+        let normal = SkewNormal::new(1.0, 2.0, 0.0).unwrap();
+        assert_almost_eq!(normal.pdf(1.0), 0.19947114020071633897, 1e-15);
+    }
+
+    #[test]
+    fn skew_normal_cdf() {
+        let skew_normal = SkewNormal::new(2.0, 3.0, 4.0).unwrap();
+        assert_almost_eq!(skew_normal.cdf(1.0), 0.0076668042007373292642, 1e-15);
+        assert_almost_eq!(skew_normal.cdf(2.5), 0.16110338540079669862, 1e-15);
+        assert_almost_eq!(skew_normal.sf(2.5), 0.83889661459920330138, 1e-15);
+        assert_almost_eq!(skew_normal.sf(20.0) / 1.9731752900753962814e-9, 1.0, 1e-13);
+        assert_almost_eq!(skew_normal.cdf(-1.0) / 8.1796903390645501249e-7, 1.0, 1e-13);
+        let skew_normal = SkewNormal::new(2.0, 3.0, -4.0).unwrap();
+        assert_almost_eq!(skew_normal.sf(3.0), 0.0076668042007373292642, 1e-15);
+        assert_almost_eq!(
+            skew_normal.cdf(-16.0) / 1.9731752900753962814e-9,
+            1.0,
+            1e-13
+        );
+        // Close to a half-normal distribution
+        let skew_normal = SkewNormal::new(0.0, 1.0, 1000.0).unwrap();
+        assert_almost_eq!(skew_normal.cdf(0.5), 0.38292492254802620728, 1e-15);
+        assert_almost_eq!(skew_normal.cdf(-0.001), 6.6476052223743344156e-5, 1e-18);
+        assert_almost_eq!(skew_normal.sf(3.0), 0.0026997960632601890533, 1e-17);
+        let normal = SkewNormal::new(1.0, 2.0, 0.0).unwrap();
+        assert_eq!(normal.cdf(1.0), 0.5);
+    }
+
+    #[test]
+    fn skew_normal_quantile() {
+        let d = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+        assert_almost_eq!(d.quantile(0.3), 1.7269811583896476663, 1e-13);
+        assert_almost_eq!(d.quantile(0.99), 6.1516586070978004938, 1e-13);
+        assert_eq!(d.quantile(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn skew_normal_moments() {
+        let d = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 2.5138795132120960289, 1e-14);
+        assert_almost_eq!(d.variance().unwrap(), 1.7081688194767071649, 1e-14);
+        assert_almost_eq!(d.skewness().unwrap(), 0.66702357015240795331, 1e-14);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 0.50977012944941361572, 1e-14);
+    }
+
+    #[test]
+    fn skew_normal_entropy() {
+        let d = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+        assert_almost_eq!(d.entropy(), 1.6459821496007588532, 1e-13);
+        let d = SkewNormal::new(0.0, 1.0, 20.0).unwrap();
+        assert_almost_eq!(d.entropy(), 0.76177543809271121814, 1e-13);
+        let d = SkewNormal::new(0.0, 1.0, 1000.0).unwrap();
+        assert_almost_eq!(d.entropy(), 0.72651199942728625356, 1e-13);
+        let d = SkewNormal::new(0.0, 1.0, 0.0).unwrap();
+        assert_almost_eq!(d.entropy(), 1.4189385332046727418, 1e-13);
+    }
+
+    #[test]
+    fn skew_normal_summary() {
+        let d = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+        assert_eq!(d.support(), Support::real_line());
+        assert_almost_eq!(d.mode().unwrap(), 1.0 + 2.0 * 0.47339562936681362681, 1e-14);
+        let d = SkewNormal::new(1.0, 2.0, -3.0).unwrap();
+        assert_almost_eq!(d.mode().unwrap(), 1.0 - 2.0 * 0.47339562936681362681, 1e-14);
+        let d = SkewNormal::new(0.0, 1.0, 1000.0).unwrap();
+        assert_almost_eq!(d.mode().unwrap(), 0.00476151390234292, 1e-14);
+        let d = SkewNormal::new(1.0, 2.0, 0.0).unwrap();
+        assert_eq!((d.mode(), d.median()), (Some(1.0), 1.0));
+    }
+
     #[test]
     #[should_panic]
     fn invalid_scale_nan() {
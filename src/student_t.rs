@@ -9,8 +9,13 @@
 
 //! The Student's t-distribution.
 
+use crate::quantile::invert_cdf;
+use crate::special::{bd0, beta_pq, ln_minus_digamma, stirlerr, LN_SQRT_2PI};
+use crate::{
+    Cdf, ContinuousPdf, Distribution, Entropy, Exp1, Moments, Open01, Quantile, StandardNormal,
+    Summary, Support,
+};
 use crate::{ChiSquared, ChiSquaredError};
-use crate::{Distribution, Exp1, Open01, StandardNormal};
 use num_traits::Float;
 use rand::Rng;
 #[cfg(feature = "serde")]
@@ -87,6 +92,168 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for StudentT<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        // Written in terms of `stirlerr` and `bd0` (as in R's `dt`) rather
+        // than a ratio of gamma functions, which would cancel catastrophically
+        // for large degrees of freedom.
+        let n = self.dof;
+        let half = F::from(0.5).unwrap();
+        let t = -bd0(half * n, half * (n + F::one())) + stirlerr(half * (n + F::one()))
+            - stirlerr(half * n);
+        let x2n = x * x / n;
+        let (l_x2n, u) = if x2n > F::epsilon().recip() {
+            let l_x2n = x.abs().ln() - half * n.ln();
+            (l_x2n, n * l_x2n)
+        } else if x2n > F::from(0.2).unwrap() {
+            let l_x2n = half * x2n.ln_1p();
+            (l_x2n, n * l_x2n)
+        } else {
+            let u = -bd0(half * n, half * (n + x * x)) + half * x * x;
+            (half * x2n.ln_1p(), u)
+        };
+        t - u - (F::from(LN_SQRT_2PI).unwrap() + l_x2n)
+    }
+}
+
+impl<F> Cdf<F> for StudentT<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        let tail = tail(self.dof, x);
+        if x < F::zero() {
+            tail
+        } else {
+            F::one() - tail
+        }
+    }
+
+    fn sf(&self, x: F) -> F {
+        let tail = tail(self.dof, x);
+        if x > F::zero() {
+            tail
+        } else {
+            F::one() - tail
+        }
+    }
+}
+
+impl<F> Quantile<F> for StudentT<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        let (zero, one) = (F::zero(), F::one());
+        invert_cdf(self, p, F::neg_infinity(), F::infinity(), zero, one)
+    }
+}
+
+impl<F> Moments<F> for StudentT<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        if !(self.dof > F::one()) {
+            return None;
+        }
+        Some(F::zero())
+    }
+
+    fn variance(&self) -> Option<F> {
+        let two = F::from(2.0).unwrap();
+        if !(self.dof > two) {
+            return None;
+        }
+        Some(self.dof / (self.dof - two))
+    }
+
+    fn skewness(&self) -> Option<F> {
+        if !(self.dof > F::from(3.0).unwrap()) {
+            return None;
+        }
+        Some(F::zero())
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let four = F::from(4.0).unwrap();
+        if !(self.dof > four) {
+            return None;
+        }
+        Some(F::from(6.0).unwrap() / (self.dof - four))
+    }
+}
+
+impl<F> Entropy<F> for StudentT<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // (ν+1)/2 (ψ((ν+1)/2) - ψ(ν/2)) + ln(√ν B(ν/2, 1/2)), with the
+        // digamma and log-gamma differences expanded around x = ν/2 so that
+        // the result tends smoothly to the normal entropy.
+        let half = F::from(0.5).unwrap();
+        let x = half * self.dof;
+        let x_half = x + half;
+        half * self.dof.recip().ln_1p()
+            + x_half * (ln_minus_digamma(x) - ln_minus_digamma(x_half))
+            + F::from(LN_SQRT_2PI + 0.5).unwrap()
+            + stirlerr(x)
+            - stirlerr(x_half)
+    }
+}
+
+impl<F> Summary<F> for StudentT<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::real_line()
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some(F::zero())
+    }
+
+    fn median(&self) -> F {
+        F::zero()
+    }
+}
+
+/// `P(T > |x|) = I_{n / (n + x²)}(n / 2, 1 / 2) / 2` for `n` degrees of
+/// freedom.
+fn tail<F: Float>(n: F, x: F) -> F {
+    let half = F::from(0.5).unwrap();
+    let x2n = x * x / n;
+    let (p, q) = if x2n.is_infinite() {
+        (F::zero(), F::one())
+    } else {
+        ((F::one() + x2n).recip(), x2n / (F::one() + x2n))
+    };
+    half * beta_pq(half * n, half, p, q).0
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -100,6 +267,69 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_t_pdf() {
+        let t = StudentT::new(11.0).unwrap();
+        assert_almost_eq!(t.pdf(1.5), 0.12767740343870699503, 1e-14);
+        assert_almost_eq!(t.pdf(0.0), 0.38998975705668928754, 1e-14);
+        let cauchy = StudentT::new(1.0).unwrap();
+        assert_almost_eq!(cauchy.pdf(-0.5), 0.25464790894703253723, 1e-14);
+        let large = StudentT::new(1e12).unwrap();
+        assert_almost_eq!(large.ln_pdf(2.0), -2.9189385332029227418, 1e-12);
+    }
+
+    #[test]
+    fn test_t_cdf() {
+        let t = StudentT::new(11.0).unwrap();
+        assert_almost_eq!(t.cdf(1.5), 0.91912099147227303171, 1e-15);
+        assert_almost_eq!(t.sf(1.5), 0.080879008527726968292, 1e-15);
+        assert_almost_eq!(t.cdf(-1.5), 0.080879008527726968292, 1e-15);
+        assert_eq!(t.cdf(0.0), 0.5);
+        assert_almost_eq!(t.sf(1e4) / 6.2808205287762028538e-40, 1.0, 1e-12);
+        let cauchy = StudentT::new(1.0).unwrap();
+        assert_almost_eq!(cauchy.cdf(1e300), 1.0, 1e-16);
+    }
+
+    #[test]
+    fn test_t_quantile() {
+        let t = StudentT::new(3.0).unwrap();
+        assert_almost_eq!(t.quantile(0.975), 3.1824463052837084359, 1e-14);
+        assert_almost_eq!(t.quantile(1e-10), -2225.7692846830931927, 1e-10);
+        assert_eq!(t.quantile(0.0), f64::NEG_INFINITY);
+        assert_eq!(t.quantile(1.0), f64::INFINITY);
+        assert!(t.quantile(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_t_moments() {
+        let d = StudentT::new(5.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 0.0, 1e-15);
+        assert_almost_eq!(d.variance().unwrap(), 1.6666666666666666667, 1e-15);
+        assert_almost_eq!(d.skewness().unwrap(), 0.0, 1e-15);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 6.0, 1e-15);
+        let d = StudentT::new(2.0).unwrap();
+        assert_eq!(d.mean(), Some(0.0));
+        assert_eq!(d.variance(), None);
+        assert_eq!(StudentT::new(1.0).unwrap().mean(), None);
+    }
+
+    #[test]
+    fn test_t_entropy() {
+        let d = StudentT::new(1.0).unwrap();
+        assert_almost_eq!(d.entropy(), 2.5310242469692907930, 1e-15);
+        let d = StudentT::new(5.0).unwrap();
+        assert_almost_eq!(d.entropy(), 1.6275026724143959811, 1e-14);
+        let d = StudentT::new(1e10).unwrap();
+        assert_almost_eq!(d.entropy(), 1.4189385333046727418, 1e-15);
+    }
+
+    #[test]
+    fn test_t_summary() {
+        let d = StudentT::new(3.0).unwrap();
+        assert_eq!(d.support(), Support::real_line());
+        assert_eq!((d.mode(), d.median()), (Some(0.0), 0.0));
+    }
+
     #[test]
     fn student_t_distributions_can_be_compared() {
         assert_eq!(StudentT::new(1.0), StudentT::new(1.0));
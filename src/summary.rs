@@ -0,0 +1,142 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support, mode and median of univariate distributions.
+
+use core::ops::Bound;
+use num_traits::Float;
+
+/// The support of a univariate distribution: the set of values it takes.
+///
+/// A finite endpoint of a [`Support::Real`] interval is
+/// [`Bound::Included`] if the density is finite there and
+/// [`Bound::Excluded`] if it is not defined or unbounded there, so for
+/// example the support of [`Exp`](crate::Exp) is `[0, ∞)` while that of
+/// [`LogNormal`](crate::LogNormal) is `(0, ∞)`. Infinite endpoints are
+/// [`Bound::Unbounded`].
+///
+/// # Example
+///
+/// ```
+/// use core::ops::Bound;
+/// use rand_distr::{Binomial, Pareto, Summary, Support};
+///
+/// let pareto = Pareto::new(2.0f64, 3.0).unwrap();
+/// assert_eq!(
+///     pareto.support(),
+///     Support::Real { lower: Bound::Included(2.0), upper: Bound::Unbounded }
+/// );
+///
+/// let binomial = Binomial::new(10, 0.3).unwrap();
+/// let support = binomial.support();
+/// assert_eq!(support, Support::Integer { lower: 0, upper: Some(10) });
+/// assert_eq!((support.lower(), support.upper()), (0.0, 10.0));
+/// assert!(support.contains(3.0) && !support.contains(3.5));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Support<F> {
+    /// An interval of the real line.
+    Real {
+        /// The lower endpoint.
+        lower: Bound<F>,
+        /// The upper endpoint.
+        upper: Bound<F>,
+    },
+    /// The integers from `lower` to `upper` inclusive.
+    Integer {
+        /// The least value.
+        lower: u64,
+        /// The greatest value, or `None` if there is none.
+        upper: Option<u64>,
+    },
+}
+
+impl<F: Float> Support<F> {
+    /// The whole real line.
+    pub(crate) fn real_line() -> Self {
+        Support::Real {
+            lower: Bound::Unbounded,
+            upper: Bound::Unbounded,
+        }
+    }
+
+    /// The infimum of the support, which is `-inf` if it is unbounded below.
+    pub fn lower(&self) -> F {
+        match *self {
+            Support::Real { lower, .. } => match lower {
+                Bound::Included(x) | Bound::Excluded(x) => x,
+                Bound::Unbounded => F::neg_infinity(),
+            },
+            Support::Integer { lower, .. } => F::from(lower).unwrap(),
+        }
+    }
+
+    /// The supremum of the support, which is `inf` if it is unbounded above.
+    pub fn upper(&self) -> F {
+        match *self {
+            Support::Real { upper, .. } => match upper {
+                Bound::Included(x) | Bound::Excluded(x) => x,
+                Bound::Unbounded => F::infinity(),
+            },
+            Support::Integer { upper, .. } => match upper {
+                Some(n) => F::from(n).unwrap(),
+                None => F::infinity(),
+            },
+        }
+    }
+
+    /// Whether `x` is in the support.
+    pub fn contains(&self, x: F) -> bool {
+        let above = match *self {
+            Support::Real { lower, .. } => match lower {
+                Bound::Included(a) => x >= a,
+                Bound::Excluded(a) => x > a,
+                Bound::Unbounded => x > F::neg_infinity(),
+            },
+            Support::Integer { .. } => x == x.floor() && x >= self.lower(),
+        };
+        let below = match *self {
+            Support::Real { upper, .. } => match upper {
+                Bound::Included(b) => x <= b,
+                Bound::Excluded(b) => x < b,
+                Bound::Unbounded => x < F::infinity(),
+            },
+            Support::Integer { .. } => x <= self.upper() && x < F::infinity(),
+        };
+        above && below
+    }
+}
+
+/// The support, mode and median of a univariate distribution.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Gamma, Summary};
+///
+/// let gamma = Gamma::new(3.0f64, 2.0).unwrap();
+/// assert_eq!(gamma.mode(), Some(4.0));
+/// assert!((gamma.median() - 5.348120627447123).abs() < 1e-14);
+/// ```
+pub trait Summary<F: Float> {
+    /// The set of values the distribution takes.
+    fn support(&self) -> Support<F>;
+
+    /// The most likely value: the maximum of the density or mass function.
+    ///
+    /// This is `None` if there is no single such value: if the density is
+    /// constant on an interval (as for a [`Beta`](crate::Beta) distribution
+    /// with `α = β = 1`) or unbounded at both ends of the support (as for
+    /// `α, β < 1`). Where a mass function attains its maximum at two
+    /// consecutive integers the smaller is returned. A density which is
+    /// unbounded at one end of the support has its mode there.
+    fn mode(&self) -> Option<F>;
+
+    /// The median: the least `x` with `cdf(x) >= 1/2`.
+    fn median(&self) -> F;
+}
@@ -0,0 +1,188 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Estimators of the heaviness of the upper tail of a distribution.
+//!
+//! The estimators use the `k` largest of `n` observations and estimate the
+//! extreme value index `ξ`: a distribution whose upper tail decays like
+//! `x^(-α)` has `ξ = 1 / α`, as does the [`Pareto`] distribution with shape
+//! `α`, while a light tail like the exponential one has `ξ = 0` and a bounded
+//! one has `ξ < 0`. The number `k` trades the bias of including observations
+//! from outside the tail against the variance of using few observations.
+//!
+//! - [`hill`]: the Hill estimator, for heavy tails with `ξ > 0`
+//! - [`pickands`]: the Pickands estimator, for any `ξ`
+//!
+//! To sample from a fitted tail, [`Pareto::fit_hill`] calibrates a
+//! [`Pareto`] distribution to the observations above a threshold with the
+//! Hill estimator, and [`Gumbel::fit_block_maxima`] and
+//! [`Frechet::fit_block_maxima`] fit extreme value distributions to the
+//! maxima of blocks of observations.
+//!
+//! The estimators find the order statistics they need by partially
+//! reordering `data` in place, in linear time.
+//!
+//! # Example
+//!
+//! ```
+//! use rand_distr::{tail, Distribution, Pareto};
+//!
+//! let pareto = Pareto::new(1.0, 2.0).unwrap();
+//! let mut data: Vec<f64> = pareto.sample_iter(rand::rng()).take(10_000).collect();
+//! let xi = tail::hill(&mut data, 1000).unwrap();
+//! println!("the tail index is about {}", 1.0 / xi);
+//! ```
+//!
+//! [`Pareto`]: crate::Pareto
+//! [`Pareto::fit_hill`]: crate::Pareto::fit_hill
+//! [`Gumbel::fit_block_maxima`]: crate::Gumbel::fit_block_maxima
+//! [`Frechet::fit_block_maxima`]: crate::Frechet::fit_block_maxima
+
+use crate::FitError;
+use num_traits::Float;
+
+/// Fails if `data` is empty or contains a value that is not finite.
+fn check<F: Float>(data: &[F]) -> Result<(), FitError> {
+    if data.is_empty() {
+        return Err(FitError::Empty);
+    }
+    if data.iter().any(|x| !x.is_finite()) {
+        return Err(FitError::OutOfSupport);
+    }
+    Ok(())
+}
+
+/// Reorder `data` so that `data[index]` is the order statistic at `index`,
+/// with no greater values before and no lesser values after it.
+fn select<F: Float>(data: &mut [F], index: usize) -> F {
+    data.select_nth_unstable_by(index, |a, b| a.partial_cmp(b).unwrap());
+    data[index]
+}
+
+/// The Hill estimator together with its threshold, the `k + 1`-th largest
+/// observation.
+pub(crate) fn hill_with_threshold<F: Float>(data: &mut [F], k: usize) -> Result<(F, F), FitError> {
+    check(data)?;
+    if k == 0 || k >= data.len() {
+        return Err(FitError::InvalidSize);
+    }
+    let index = data.len() - k - 1;
+    let threshold = select(data, index);
+    if !(threshold > F::zero()) {
+        return Err(FitError::OutOfSupport);
+    }
+    // ln(x / threshold), in terms of the relative difference so that it
+    // keeps its accuracy for observations close to the threshold
+    let sum = data[index + 1..].iter().fold(F::zero(), |sum, &x| {
+        sum + ((x - threshold) / threshold).ln_1p()
+    });
+    Ok((threshold, sum / F::from(k).unwrap()))
+}
+
+/// The Hill estimator of the extreme value index `ξ > 0` from the `k`
+/// largest observations.
+///
+/// This is the mean of `ln(x / u)` over the `k` largest observations `x`,
+/// for the threshold `u` given by the `k + 1`-th largest observation; it is
+/// the maximum-likelihood estimate of `1 / α` for a Pareto tail above `u`.
+///
+/// Fails if `data` is empty or contains a value that is not finite, if `k`
+/// is zero or not less than the number of observations, or if the threshold
+/// is not positive.
+pub fn hill<F: Float>(data: &mut [F], k: usize) -> Result<F, FitError> {
+    hill_with_threshold(data, k).map(|(_, xi)| xi)
+}
+
+/// The Pickands estimator of the extreme value index `ξ` from the `4k`
+/// largest observations.
+///
+/// For the `k`-th, `2k`-th and `4k`-th largest observations `x₁ ≥ x₂ ≥ x₄`,
+/// this is `ln((x₁ - x₂) / (x₂ - x₄)) / ln 2`. Unlike the Hill estimator it
+/// is consistent for all `ξ`, but it has a larger variance.
+///
+/// Fails if `data` is empty or contains a value that is not finite, if `k`
+/// is zero or `4k` is greater than the number of observations, or if two of
+/// the three order statistics are equal.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::tail;
+///
+/// // Uniform observations have a bounded tail with ξ = -1
+/// let mut data: Vec<f64> = (0..1000).map(|i| i as f64).collect();
+/// let xi = tail::pickands(&mut data, 100).unwrap();
+/// assert!((xi + 1.0).abs() < 0.01);
+/// ```
+pub fn pickands<F: Float>(data: &mut [F], k: usize) -> Result<F, FitError> {
+    check(data)?;
+    let n = data.len();
+    if k == 0 || k > n / 4 {
+        return Err(FitError::InvalidSize);
+    }
+    let (i4, i2, i1) = (n - 4 * k, n - 2 * k, n - k);
+    let x4 = select(data, i4);
+    let x2 = select(&mut data[i4..], i2 - i4);
+    let x1 = select(&mut data[i2..], i1 - i2);
+    if x1 == x2 || x2 == x4 {
+        return Err(FitError::Constant);
+    }
+    let ln_2 = F::from(core::f64::consts::LN_2).unwrap();
+    Ok(((x1 - x2) / (x2 - x4)).ln() / ln_2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Distribution, Exp, Pareto};
+
+    #[test]
+    fn test_hill() {
+        let mut data = [4.0, 1.0, 8.0, 2.0, 16.0];
+        let xi = hill(&mut data, 2).unwrap();
+        assert_almost_eq!(xi, 1.5 * core::f64::consts::LN_2, 1e-15);
+        assert_eq!(hill_with_threshold(&mut data, 2).unwrap().0, 4.0);
+
+        let mut rng = crate::test::rng(232);
+        let dist = Pareto::new(1.0, 2.0).unwrap();
+        let mut data: [f64; 10_000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        assert_almost_eq!(hill(&mut data, 2000).unwrap(), 0.5, 0.03);
+
+        assert_eq!(hill::<f64>(&mut [], 1), Err(FitError::Empty));
+        assert_eq!(hill(&mut [1.0, 2.0], 0), Err(FitError::InvalidSize));
+        assert_eq!(hill(&mut [1.0, 2.0], 2), Err(FitError::InvalidSize));
+        assert_eq!(hill(&mut [1.0, f64::NAN], 1), Err(FitError::OutOfSupport));
+        assert_eq!(hill(&mut [-1.0, 2.0], 1), Err(FitError::OutOfSupport));
+    }
+
+    #[test]
+    fn test_pickands() {
+        // The order statistics are 8, 6 and 2
+        let mut data = [5.0, 9.0, 2.0, 7.0, 3.0, 4.0, 6.0, 8.0];
+        let xi = pickands(&mut data, 2).unwrap();
+        assert_almost_eq!(xi, -1.0, 1e-15);
+
+        let mut rng = crate::test::rng(233);
+        let dist = Pareto::new(1.0, 2.0).unwrap();
+        let mut data: [f64; 10_000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        assert_almost_eq!(pickands(&mut data, 2000).unwrap(), 0.5, 0.15);
+        let dist = Exp::new(1.0).unwrap();
+        let mut data: [f64; 10_000] = core::array::from_fn(|_| dist.sample(&mut rng));
+        assert_almost_eq!(pickands(&mut data, 2000).unwrap(), 0.0, 0.15);
+
+        assert_eq!(pickands::<f64>(&mut [], 1), Err(FitError::Empty));
+        assert_eq!(
+            pickands(&mut [1.0, 2.0, 3.0], 1),
+            Err(FitError::InvalidSize)
+        );
+        assert_eq!(
+            pickands(&mut [1.0, 2.0, 3.0, 3.0], 1),
+            Err(FitError::Constant)
+        );
+    }
+}
@@ -7,8 +7,11 @@
 // except according to those terms.
 //! The triangular distribution.
 
-use crate::{Distribution, StandardUniform};
+use crate::{
+    Cdf, ContinuousPdf, Distribution, Entropy, Moments, Quantile, StandardUniform, Summary, Support,
+};
 use core::fmt;
+use core::ops::Bound;
 use num_traits::Float;
 use rand::Rng;
 
@@ -89,6 +92,13 @@ where
         }
         Ok(Triangular { min, max, mode })
     }
+
+    /// `a² + b² + c² - ab - ac - bc`, i.e. 18 times the variance.
+    fn spread(&self) -> F {
+        let (a, b, c) = (self.min, self.max, self.mode);
+        let half = F::from(0.5).unwrap();
+        half * ((a - b) * (a - b) + (a - c) * (a - c) + (b - c) * (b - c))
+    }
 }
 
 impl<F> Distribution<F> for Triangular<F>
@@ -110,6 +120,179 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for Triangular<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn pdf(&self, x: F) -> F {
+        let (min, max, mode) = (self.min, self.max, self.mode);
+        if !(x >= min && x <= max) {
+            return F::zero();
+        }
+        let range = max - min;
+        if range == F::zero() {
+            // Degenerate distribution: all mass is at a single point.
+            return F::infinity();
+        }
+        let two = F::from(2.).unwrap();
+        if x < mode {
+            two * (x - min) / (range * (mode - min))
+        } else if x > mode {
+            two * (max - x) / (range * (max - mode))
+        } else {
+            two / range
+        }
+    }
+
+    fn ln_pdf(&self, x: F) -> F {
+        let (min, max, mode) = (self.min, self.max, self.mode);
+        if !(x >= min && x <= max) {
+            return F::neg_infinity();
+        }
+        let range = max - min;
+        if range == F::zero() {
+            return F::infinity();
+        }
+        // The logarithms of the factors, which do not underflow or overflow
+        // as their product may
+        let ln_2_range = F::from(2.).unwrap().ln() - range.ln();
+        if x < mode {
+            ln_2_range + (x - min).ln() - (mode - min).ln()
+        } else if x > mode {
+            ln_2_range + (max - x).ln() - (max - mode).ln()
+        } else {
+            ln_2_range
+        }
+    }
+}
+
+impl<F> Cdf<F> for Triangular<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        let (min, max, mode) = (self.min, self.max, self.mode);
+        if x >= max {
+            return F::one();
+        }
+        if !(x > min) {
+            return F::zero();
+        }
+        if x < mode {
+            (x - min) * (x - min) / ((max - min) * (mode - min))
+        } else {
+            F::one() - (max - x) * (max - x) / ((max - min) * (max - mode))
+        }
+    }
+
+    fn sf(&self, x: F) -> F {
+        let (min, max, mode) = (self.min, self.max, self.mode);
+        if x >= max {
+            return F::zero();
+        }
+        if !(x > min) {
+            return F::one();
+        }
+        if x < mode {
+            F::one() - (x - min) * (x - min) / ((max - min) * (mode - min))
+        } else {
+            (max - x) * (max - x) / ((max - min) * (max - mode))
+        }
+    }
+}
+
+impl<F> Quantile<F> for Triangular<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        if !(p >= F::zero() && p <= F::one()) {
+            return F::nan();
+        }
+        let diff_mode_min = self.mode - self.min;
+        let range = self.max - self.min;
+        let p_range = p * range;
+        if p_range < diff_mode_min {
+            self.min + (p_range * diff_mode_min).sqrt()
+        } else {
+            self.max - ((F::one() - p) * range * (self.max - self.mode)).sqrt()
+        }
+    }
+}
+
+impl<F> Moments<F> for Triangular<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some((self.min + self.max + self.mode) / F::from(3.0).unwrap())
+    }
+
+    fn variance(&self) -> Option<F> {
+        Some(self.spread() / F::from(18.0).unwrap())
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let (a, b, c) = (self.min, self.max, self.mode);
+        let spread = self.spread();
+        if spread == F::zero() {
+            return None;
+        }
+        let num =
+            F::from(2.0).unwrap().sqrt() * (a + b - c - c) * (a + a - b - c) * (a - b - b + c);
+        Some(num / (F::from(5.0).unwrap() * spread * spread.sqrt()))
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        if self.spread() == F::zero() {
+            return None;
+        }
+        Some(F::from(-0.6).unwrap())
+    }
+}
+
+impl<F> Entropy<F> for Triangular<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        let half = F::from(0.5).unwrap();
+        half + (half * (self.max - self.min)).ln()
+    }
+}
+
+impl<F> Summary<F> for Triangular<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::Real {
+            lower: Bound::Included(self.min),
+            upper: Bound::Included(self.max),
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some(self.mode)
+    }
+
+    fn median(&self) -> F {
+        let half = F::from(0.5).unwrap();
+        let range = self.max - self.min;
+        if self.mode - self.min >= half * range {
+            self.min + (half * range * (self.mode - self.min)).sqrt()
+        } else {
+            self.max - (half * range * (self.max - self.mode)).sqrt()
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -139,6 +322,93 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_triangular_pdf() {
+        let d = Triangular::new(0.0, 4.0, 1.0).unwrap();
+        assert_eq!(d.pdf(0.5), 0.25);
+        assert_eq!(d.pdf(1.0), 0.5);
+        assert_eq!(d.pdf(2.5), 0.25);
+        assert_eq!(d.pdf(4.0), 0.0);
+        assert_eq!(d.pdf(-1.0), 0.0);
+        assert_eq!(d.ln_pdf(5.0), f64::NEG_INFINITY);
+        assert_almost_eq!(d.ln_pdf(0.5), 0.25f64.ln(), 1e-15);
+        assert_almost_eq!(d.ln_pdf(1.0), 0.5f64.ln(), 1e-15);
+        assert_eq!(d.ln_pdf(0.0), f64::NEG_INFINITY);
+        let d = Triangular::new(1.0, 1.0, 1.0).unwrap();
+        assert_eq!(d.pdf(1.0), f64::INFINITY);
+        // The density underflows close to the bounds
+        let d = Triangular::new(0.0, 1e300, 1e300).unwrap();
+        assert_eq!(d.pdf(1e-20), 0.0);
+        assert_almost_eq!(
+            d.ln_pdf(1e-20),
+            2f64.ln() - 20.0 * 10f64.ln() - 600.0 * 10f64.ln(),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_triangular_cdf() {
+        let d = Triangular::new(0.0, 4.0, 1.0).unwrap();
+        assert_eq!(d.cdf(0.5), 0.0625);
+        assert_eq!(d.cdf(1.0), 0.25);
+        assert_eq!(d.sf(2.5), 0.1875);
+        assert_eq!(d.cdf(4.0), 1.0);
+        assert_eq!(d.sf(-1.0), 1.0);
+        let d = Triangular::new(0.0, 1.0, 0.0).unwrap();
+        assert_eq!(d.cdf(0.0), 0.0);
+        assert_eq!(d.sf(0.5), 0.25);
+        let d = Triangular::new(1.0, 1.0, 1.0).unwrap();
+        assert_eq!(d.cdf(1.0), 1.0);
+        assert_eq!(d.cdf(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_triangular_quantile() {
+        let d = Triangular::new(1.0, 5.0, 2.0).unwrap();
+        assert_eq!(d.quantile(0.0), 1.0);
+        assert_eq!(d.quantile(0.25), 2.0);
+        assert_almost_eq!(d.quantile(0.1), 1.6324555320336758664, 1e-15);
+        assert_almost_eq!(d.quantile(0.9), 3.9045548849896678947, 1e-15);
+        assert_eq!(d.quantile(1.0), 5.0);
+        assert!(d.quantile(1.5).is_nan());
+        for &p in &[0.01, 0.3, 0.5, 0.99] {
+            assert_almost_eq!(d.cdf(d.quantile(p)), p, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_triangular_moments() {
+        let d = Triangular::new(1.0, 5.0, 2.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 2.6666666666666666667, 1e-15);
+        assert_almost_eq!(d.variance().unwrap(), 0.72222222222222222222, 1e-15);
+        assert_almost_eq!(d.skewness().unwrap(), 0.42240398337455022261, 1e-15);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), -0.6, 1e-15);
+        let d = Triangular::new(1.0, 1.0, 1.0).unwrap();
+        assert_eq!(d.variance(), Some(0.0));
+        assert_eq!(d.skewness(), None);
+    }
+
+    #[test]
+    fn test_triangular_entropy() {
+        // Independent of the mode
+        for &mode in &[0.0, 1.0, 4.0] {
+            let d = Triangular::new(0.0, 4.0, mode).unwrap();
+            assert_almost_eq!(d.entropy(), 0.5 + core::f64::consts::LN_2, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_triangular_summary() {
+        let d = Triangular::new(0.0, 4.0, 1.0).unwrap();
+        let support = d.support();
+        assert_eq!((support.lower(), support.upper()), (0.0, 4.0));
+        assert!(support.contains(4.0) && !support.contains(4.5));
+        assert_eq!(d.mode(), Some(1.0));
+        assert_almost_eq!(d.median(), 1.5505102572168219018, 1e-15);
+        let d = Triangular::new(0.0, 4.0, 3.0).unwrap();
+        assert_almost_eq!(d.median(), 4.0 - 1.5505102572168219018, 1e-15);
+    }
+
     #[test]
     fn triangular_distributions_can_be_compared() {
         assert_eq!(
@@ -25,7 +25,7 @@ use rand::Rng;
 /// * `F_DIFF`: precomputed values of $f(x_i) - f(x_{i+1})$
 /// * `pdf`: the probability density function
 /// * `zero_case`: manual sampling from the tail when we chose the
-///    bottom box (i.e. i == 0)
+///   bottom box (i.e. i == 0)
 #[inline(always)] // Forced inlining improves the perf by 25-50%
 pub(crate) fn ziggurat<R: Rng + ?Sized, P, Z>(
     rng: &mut R,
@@ -75,3 +75,159 @@ where
         }
     }
 }
+
+/// Integrate `f` over the finite interval `[a, b]` with the tanh-sinh
+/// (double exponential) quadrature rule.
+///
+/// The rule converges very rapidly for integrands which are analytic in the
+/// interior of the interval, including those with integrable singularities
+/// at the end points; `f` is never evaluated at `a` or `b` themselves. The
+/// step size is halved until successive estimates agree to near machine
+/// precision.
+pub(crate) fn integrate<F, G>(mut f: G, a: F, b: F) -> F
+where
+    F: Float,
+    G: FnMut(F) -> F,
+{
+    const MAX_LEVEL: i32 = 8;
+    let half = F::from(0.5).unwrap();
+    let pi_2 = F::from(core::f64::consts::FRAC_PI_2).unwrap();
+    let d = half * (b - a);
+    if d == F::zero() {
+        return F::zero();
+    }
+    let tol = F::from(64.0).unwrap() * F::epsilon();
+    let center = f(a + d);
+
+    // Sum of the weighted integrand at the nodes `±t`, for `t > 0`.
+    let mut pair = |t: F| -> (F, bool) {
+        let s = pi_2 * t.sinh();
+        let e = (-(s + s)).exp();
+        // 1 - tanh(s), without cancellation.
+        let delta = d * (e + e) / (F::one() + e);
+        let (x_lo, x_hi) = (a + delta, b - delta);
+        if x_lo == a || x_hi == b {
+            return (F::zero(), true);
+        }
+        let w = pi_2 * t.cosh() * F::from(4.0).unwrap() * e / ((F::one() + e) * (F::one() + e));
+        let value = (f(x_lo) + f(x_hi)) * w;
+        (value, false)
+    };
+
+    // Sum the nodes at `t = k h` for `k = first, first + step, …`, stopping
+    // once the nodes have merged with the end points or, beyond `t = 3`
+    // (where the nodes are within `1e-13 (b - a)` of the end points), the
+    // terms have become negligible.
+    let three = F::from(3.0).unwrap();
+    let mut add_nodes = |sum: &mut F, h: F, first: i32, step: i32| {
+        let mut k = first;
+        loop {
+            let t = h * F::from(k).unwrap();
+            let (value, done) = pair(t);
+            *sum = *sum + value;
+            if done || (t > three && !(value.abs() > F::epsilon() * sum.abs())) {
+                break;
+            }
+            k += step;
+        }
+    };
+
+    let mut h = F::one();
+    let mut sum = pi_2 * center;
+    add_nodes(&mut sum, h, 1, 1);
+    let mut estimate = d * h * sum;
+
+    for _ in 0..MAX_LEVEL {
+        h = h * half;
+        add_nodes(&mut sum, h, 1, 2);
+        let next = d * h * sum;
+        let converged = (next - estimate).abs() <= tol * next.abs();
+        estimate = next;
+        if converged {
+            break;
+        }
+    }
+    estimate
+}
+
+/// Find a root of `f` in the interval `[a, b]` with Brent's method, given
+/// that `f(a)` and `f(b)` do not have the same sign.
+///
+/// Combines bisection with secant and inverse quadratic interpolation steps;
+/// the root is located to within a few units in the last place, or to within
+/// `abs_tol` if that is larger.
+pub(crate) fn find_root<F, G>(mut f: G, a: F, b: F, abs_tol: F) -> F
+where
+    F: Float,
+    G: FnMut(F) -> F,
+{
+    const MAX_ITER: usize = 300;
+    let (zero, one, two) = (F::zero(), F::one(), F::from(2.0).unwrap());
+    let half = F::from(0.5).unwrap();
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (f(a), f(b));
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+    for _ in 0..MAX_ITER {
+        if (fb > zero && fc > zero) || (fb < zero && fc < zero) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tol = two * F::epsilon() * b.abs() + half * abs_tol;
+        let m = half * (c - b);
+        if m.abs() <= tol || fb == zero {
+            break;
+        }
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            // Attempt interpolation
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (two * m * s, one - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (two * m * q * (q - r) - (b - a) * (r - one)),
+                    (q - one) * (r - one) * (s - one),
+                )
+            };
+            if p > zero {
+                q = -q;
+            } else {
+                p = -p;
+            }
+            if two * p < (F::from(3.0).unwrap() * m * q - (tol * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = m;
+            }
+        } else {
+            d = m;
+            e = m;
+        }
+        a = b;
+        fa = fb;
+        b = if d.abs() > tol {
+            b + d
+        } else if m > zero {
+            b + tol
+        } else {
+            b - tol
+        };
+        fb = f(b);
+    }
+    b
+}
@@ -8,8 +8,17 @@
 
 //! The Weibull distribution `Weibull(λ, k)`
 
-use crate::{Distribution, OpenClosed01};
+use crate::fit::Welford;
+use crate::moments::{excess_kurtosis_from_raw, skewness_from_raw, variance_from_raw};
+use crate::quantile::order_quantiles;
+use crate::special::ln_gamma;
+use crate::utils::find_root;
+use crate::{
+    Cdf, ContinuousPdf, Distribution, Entropy, FitError, FitOptions, Fitted, Moments, OpenClosed01,
+    Quantile, Summary, Support,
+};
 use core::fmt;
+use core::ops::Bound;
 use num_traits::Float;
 use rand::Rng;
 
@@ -53,13 +62,21 @@ where
     scale: F,
 }
 
-/// Error type returned from [`Weibull::new`].
+/// Error type returned from [`Weibull::new`], [`Weibull::from_mean_variance`]
+/// and [`Weibull::from_quantiles`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// `scale <= 0` or `nan`.
     ScaleTooSmall,
     /// `shape <= 0` or `nan`.
     ShapeTooSmall,
+    /// `mean <= 0` or `nan`.
+    MeanOutOfRange,
+    /// `variance <= 0`, infinite or `nan`.
+    VarianceOutOfRange,
+    /// The quantiles are invalid or not achievable.
+    QuantilesOutOfRange,
 }
 
 impl fmt::Display for Error {
@@ -67,6 +84,11 @@ impl fmt::Display for Error {
         f.write_str(match self {
             Error::ScaleTooSmall => "scale is not positive in Weibull distribution",
             Error::ShapeTooSmall => "shape is not positive in Weibull distribution",
+            Error::MeanOutOfRange => "mean is not positive in Weibull distribution",
+            Error::VarianceOutOfRange => {
+                "variance is not positive and finite in Weibull distribution"
+            }
+            Error::QuantilesOutOfRange => "quantiles are not achievable in Weibull distribution",
         })
     }
 }
@@ -92,6 +114,176 @@ where
             scale,
         })
     }
+
+    /// Fit to observations by maximum likelihood, with the default
+    /// [`FitOptions`].
+    ///
+    /// See [`Weibull::fit_with`].
+    pub fn fit(data: &[F]) -> Result<Fitted<Weibull<F>, F>, FitError> {
+        Self::fit_with(data, FitOptions::new())
+    }
+
+    /// Fit to observations by maximum likelihood.
+    ///
+    /// The estimate of the shape `k` solves
+    /// `Σ xᵏ ln x / Σ xᵏ - 1 / k = mean(ln x)`, which is found by Newton's
+    /// method from the estimate `π / (√6 σ)` for the standard deviation `σ`
+    /// of `ln x`; the estimate of the scale is then `(mean(xᵏ))^(1 / k)`.
+    ///
+    /// Fails if `data` is empty, its values are all equal or any is not
+    /// positive and finite, or if the iteration does not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Weibull;
+    ///
+    /// let fitted = Weibull::fit(&[0.6, 1.1, 1.3, 2.0, 2.4]).unwrap();
+    /// println!("{:?} after {} iterations", fitted.distribution, fitted.iterations);
+    /// ```
+    pub fn fit_with(data: &[F], options: FitOptions<F>) -> Result<Fitted<Weibull<F>, F>, FitError> {
+        let mut logs = Welford::new();
+        let mut max = F::zero();
+        for &x in data {
+            if !(x > F::zero() && x.is_finite()) {
+                return Err(FitError::OutOfSupport);
+            }
+            logs.push(x.ln());
+            max = max.max(x);
+        }
+        if logs.count == 0 {
+            return Err(FitError::Empty);
+        }
+        if logs.m2 == F::zero() {
+            return Err(FitError::Constant);
+        }
+        let n = F::from(logs.count).unwrap();
+        // Work with `y = x / max`, so that `yᵏ` can neither overflow nor
+        // all underflow
+        let mean_ln_y = logs.mean - max.ln();
+
+        let pi = F::from(core::f64::consts::PI).unwrap();
+        let mut shape = pi / (F::from(6.0).unwrap() * logs.variance()).sqrt();
+        let mut iterations = 0;
+        loop {
+            if iterations == options.max_iterations() {
+                return Err(FitError::NoConvergence);
+            }
+            iterations += 1;
+            let (mut s0, mut s1, mut s2) = (F::zero(), F::zero(), F::zero());
+            for &x in data {
+                let ln_y = (x / max).ln();
+                let p = (shape * ln_y).exp();
+                s0 = s0 + p;
+                s1 = s1 + p * ln_y;
+                s2 = s2 + p * ln_y * ln_y;
+            }
+            let (r1, r2) = (s1 / s0, s2 / s0);
+            let inv = shape.recip();
+            let g = r1 - inv - mean_ln_y;
+            let dg = r2 - r1 * r1 + inv * inv;
+            let mut next = shape - g / dg;
+            if !(next > F::zero()) {
+                next = shape * F::from(0.5).unwrap();
+            }
+            if !next.is_finite() {
+                return Err(FitError::NoConvergence);
+            }
+            let converged = options.converged(shape, next);
+            shape = next;
+            if converged {
+                break;
+            }
+        }
+
+        let s0 = data
+            .iter()
+            .fold(F::zero(), |sum, &x| sum + (x / max).powf(shape));
+        let scale = max * (s0 / n).powf(shape.recip());
+        let log_likelihood =
+            n * (shape.ln() - shape * scale.ln() + (shape - F::one()) * logs.mean - F::one());
+        Ok(Fitted {
+            distribution: Weibull::new(scale, shape).map_err(|_| FitError::NoConvergence)?,
+            iterations,
+            log_likelihood,
+        })
+    }
+
+    /// Construct a Weibull distribution with the given `mean` and `variance`.
+    ///
+    /// The shape `k` solves `Γ(1 + 2/k) / Γ(1 + 1/k)² = 1 + variance / mean²`,
+    /// which is found numerically, and the scale is then
+    /// `mean / Γ(1 + 1/k)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Moments, Weibull};
+    ///
+    /// let weibull: Weibull<f64> = Weibull::from_mean_variance(2.0, 1.0).unwrap();
+    /// assert!((weibull.mean().unwrap() - 2.0).abs() < 1e-12);
+    /// assert!((weibull.variance().unwrap() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn from_mean_variance(mean: F, variance: F) -> Result<Weibull<F>, Error> {
+        if !(mean > F::zero()) {
+            return Err(Error::MeanOutOfRange);
+        }
+        if !(variance > F::zero() && variance.is_finite()) {
+            return Err(Error::VarianceOutOfRange);
+        }
+        // In terms of `t = 1 / k`, the squared coefficient of variation
+        // increases from zero at `t = 0`.
+        let target = (variance / (mean * mean)).ln_1p();
+        if !target.is_finite() {
+            return Err(Error::VarianceOutOfRange);
+        }
+        let two = F::from(2.0).unwrap();
+        let f = |t: F| ln_gamma(F::one() + two * t) - two * ln_gamma(F::one() + t) - target;
+        let mut hi = F::one();
+        while f(hi) < F::zero() {
+            hi = hi * two;
+        }
+        let t = find_root(f, F::zero(), hi, F::zero());
+        Weibull::new(mean / ln_gamma(F::one() + t).exp(), t.recip())
+    }
+
+    /// Construct a Weibull distribution from two quantiles.
+    ///
+    /// Each of `a` and `b` is a pair `(p, x)` requiring the quantile at
+    /// probability `p` to be `x`, for example `(0.5, median)`. The
+    /// probabilities must be distinct and in `(0, 1)`, and the values positive,
+    /// finite and in the same order as the probabilities.
+    ///
+    /// As `ln x = ln λ + ln(-ln(1 - p)) / k` for the quantile `x` at `p`, the
+    /// shape `k` and scale `λ` follow from the two quantiles in closed form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::{Quantile, Weibull};
+    ///
+    /// let weibull: Weibull<f64> = Weibull::from_quantiles((0.1, 2.0), (0.9, 8.0)).unwrap();
+    /// assert!((weibull.quantile(0.1) - 2.0).abs() < 1e-12);
+    /// assert!((weibull.quantile(0.9) - 8.0).abs() < 1e-12);
+    /// ```
+    pub fn from_quantiles(a: (F, F), b: (F, F)) -> Result<Weibull<F>, Error> {
+        let ((p1, x1), (p2, x2)) = order_quantiles(a, b).ok_or(Error::QuantilesOutOfRange)?;
+        if !(x1 > F::zero()) {
+            return Err(Error::QuantilesOutOfRange);
+        }
+        let (w1, w2) = (-(-p1).ln_1p(), -(-p2).ln_1p());
+        let inv_shape = (x2 / x1).ln() / (w2 / w1).ln();
+        let scale = x1 / w1.powf(inv_shape);
+        if !(inv_shape > F::zero() && scale > F::zero() && scale.is_finite()) {
+            return Err(Error::QuantilesOutOfRange);
+        }
+        Ok(Weibull { inv_shape, scale })
+    }
+
+    /// The raw moment `E[(X / scale)^k] = Γ(1 + k / shape)`.
+    fn raw_moment(&self, k: f64) -> F {
+        ln_gamma(F::one() + F::from(k).unwrap() * self.inv_shape).exp()
+    }
 }
 
 impl<F> Distribution<F> for Weibull<F>
@@ -105,6 +297,134 @@ where
     }
 }
 
+impl<F> ContinuousPdf<F> for Weibull<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn ln_pdf(&self, x: F) -> F {
+        let shape = self.inv_shape.recip();
+        if x < F::zero() {
+            return F::neg_infinity();
+        }
+        if x == F::zero() {
+            return if shape < F::one() {
+                F::infinity()
+            } else if shape == F::one() {
+                -self.scale.ln()
+            } else {
+                F::neg_infinity()
+            };
+        }
+        let z = x / self.scale;
+        (shape / self.scale).ln() + (shape - F::one()) * z.ln() - z.powf(shape)
+    }
+}
+
+impl<F> Cdf<F> for Weibull<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::zero();
+        }
+        -(-(x / self.scale).powf(self.inv_shape.recip())).exp_m1()
+    }
+
+    fn sf(&self, x: F) -> F {
+        if !(x > F::zero()) {
+            return F::one();
+        }
+        (-(x / self.scale).powf(self.inv_shape.recip())).exp()
+    }
+}
+
+impl<F> Quantile<F> for Weibull<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        if !(p >= F::zero() && p <= F::one()) {
+            return F::nan();
+        }
+        self.scale * (-(-p).ln_1p()).powf(self.inv_shape)
+    }
+}
+
+impl<F> Moments<F> for Weibull<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some(self.scale * self.raw_moment(1.0))
+    }
+
+    fn variance(&self) -> Option<F> {
+        let m = |k| self.raw_moment(k);
+        Some(self.scale * self.scale * variance_from_raw(m(1.0), m(2.0)))
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let m = |k| self.raw_moment(k);
+        Some(skewness_from_raw(m(1.0), m(2.0), m(3.0)))
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let m = |k| self.raw_moment(k);
+        Some(excess_kurtosis_from_raw(m(1.0), m(2.0), m(3.0), m(4.0)))
+    }
+}
+
+impl<F> Entropy<F> for Weibull<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // The Euler–Mascheroni constant γ
+        let euler = F::from(0.57721566490153286061).unwrap();
+        euler * (F::one() - self.inv_shape) + (self.scale * self.inv_shape).ln() + F::one()
+    }
+}
+
+impl<F> Summary<F> for Weibull<F>
+where
+    F: Float,
+    OpenClosed01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        // The density is unbounded at zero for shapes less than one
+        let lower = if self.inv_shape <= F::one() {
+            Bound::Included(F::zero())
+        } else {
+            Bound::Excluded(F::zero())
+        };
+        Support::Real {
+            lower,
+            upper: Bound::Unbounded,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        if self.inv_shape < F::one() {
+            Some(self.scale * (F::one() - self.inv_shape).powf(self.inv_shape))
+        } else {
+            Some(F::zero())
+        }
+    }
+
+    fn median(&self) -> F {
+        self.scale
+            * F::from(core::f64::consts::LN_2)
+                .unwrap()
+                .powf(self.inv_shape)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,8 +479,142 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pdf() {
+        let d = Weibull::new(2.0, 0.5).unwrap();
+        assert_almost_eq!(d.pdf(1.5), 0.12142254263444528823, 1e-16);
+        assert_eq!(d.pdf(0.0), f64::INFINITY);
+        let d = Weibull::new(1.0, 3.0).unwrap();
+        assert_almost_eq!(d.pdf(1.5), 0.23097229860374573422, 1e-15);
+        assert_eq!(d.pdf(0.0), 0.0);
+        assert_eq!(d.pdf(-1.0), 0.0);
+    }
+
+    #[test]
+    fn cdf() {
+        let d = Weibull::new(2.0, 0.5).unwrap();
+        assert_almost_eq!(d.cdf(1.5), 0.57937997394588521020, 1e-15);
+        assert_almost_eq!(d.sf(1.5), 0.42062002605411478980, 1e-15);
+        assert_eq!(d.cdf(0.0), 0.0);
+        assert_eq!(d.sf(-1.0), 1.0);
+    }
+
+    #[test]
+    fn quantile() {
+        let d = Weibull::new(2.0, 1.5).unwrap();
+        assert_almost_eq!(d.quantile(0.3), 1.0058774298314367347, 1e-15);
+        assert_almost_eq!(d.quantile(0.999), 7.2541738246789526143, 1e-14);
+        assert_eq!(d.quantile(0.0), 0.0);
+        assert_eq!(d.quantile(1.0), f64::INFINITY);
+        assert!(d.quantile(1.1).is_nan());
+    }
+
+    #[test]
+    fn moments() {
+        let d = Weibull::new(2.0, 1.5).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 1.8054905859018672226, 1e-13);
+        assert_almost_eq!(d.variance().unwrap(), 1.5027611392557280087, 1e-13);
+        assert_almost_eq!(d.skewness().unwrap(), 1.0719865728909562844, 1e-13);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 1.3904035615957883165, 1e-13);
+    }
+
+    #[test]
+    fn entropy() {
+        let d = Weibull::new(2.0, 1.5).unwrap();
+        assert_almost_eq!(d.entropy(), 1.4800872940856252143, 1e-15);
+        // Exponential with rate 1/2
+        let d = Weibull::new(2.0, 1.0).unwrap();
+        assert_almost_eq!(d.entropy(), 1.0 + core::f64::consts::LN_2, 1e-15);
+    }
+
+    #[test]
+    fn summary() {
+        let d = Weibull::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(d.mode().unwrap(), 1.7471609294725977381, 1e-15);
+        assert_almost_eq!(d.median(), 1.7699940890010354375, 1e-15);
+        assert!(d.support().contains(0.0));
+        let d = Weibull::new(2.0, 0.5).unwrap();
+        assert_eq!(d.mode(), Some(0.0));
+        assert!(!d.support().contains(0.0));
+    }
+
     #[test]
     fn weibull_distributions_can_be_compared() {
         assert_eq!(Weibull::new(1.0, 2.0), Weibull::new(1.0, 2.0));
     }
+
+    #[test]
+    fn test_weibull_fit() {
+        let fitted = Weibull::fit(&[0.6, 1.1, 1.3, 2.0, 2.4]).unwrap();
+        let d = fitted.distribution;
+        assert_almost_eq!(d.inv_shape.recip(), 2.5266470537292268028, 1e-12);
+        assert_almost_eq!(d.scale, 1.6741838329449625478, 1e-12);
+        assert_almost_eq!(fitted.log_likelihood, -4.7148510362710503220, 1e-12);
+
+        let mut rng = crate::test::rng(225);
+        for &(scale, shape) in &[(1e-3, 0.4), (2.0, 1.5), (1e6, 25.0)] {
+            let dist = Weibull::new(scale, shape).unwrap();
+            let data: [f64; 5000] = core::array::from_fn(|_| dist.sample(&mut rng));
+            let d = Weibull::fit(&data).unwrap().distribution;
+            assert_almost_eq!(d.inv_shape * shape, 1.0, 0.05);
+            assert_almost_eq!(d.scale / scale, 1.0, 0.05);
+        }
+
+        assert_eq!(Weibull::<f64>::fit(&[]), Err(FitError::Empty));
+        assert_eq!(Weibull::fit(&[1.5, 1.5]), Err(FitError::Constant));
+        assert_eq!(Weibull::fit(&[1.5, -1.0]), Err(FitError::OutOfSupport));
+        let options = FitOptions::new().with_max_iterations(1);
+        assert_eq!(
+            Weibull::fit_with(&[0.6, 1.1, 1.3, 2.0, 2.4], options),
+            Err(FitError::NoConvergence)
+        );
+    }
+
+    #[test]
+    fn test_from_mean_variance() {
+        for &(scale, shape) in &[(1.0, 1.0), (2.0, 0.5), (0.5, 3.5), (10.0, 20.0)] {
+            let expected = Weibull::new(scale, shape).unwrap();
+            let (mean, variance) = (expected.mean().unwrap(), expected.variance().unwrap());
+            let weibull = Weibull::from_mean_variance(mean, variance).unwrap();
+            assert_almost_eq!(weibull.inv_shape.recip(), shape, 1e-10 * shape);
+            assert_almost_eq!(weibull.scale, scale, 1e-10 * scale);
+        }
+
+        assert_eq!(
+            Weibull::from_mean_variance(-1.0, 1.0),
+            Err(Error::MeanOutOfRange)
+        );
+        assert_eq!(
+            Weibull::from_mean_variance(1.0, 0.0),
+            Err(Error::VarianceOutOfRange)
+        );
+        assert_eq!(
+            Weibull::from_mean_variance(1.0, f64::NAN),
+            Err(Error::VarianceOutOfRange)
+        );
+        assert_eq!(
+            Weibull::from_mean_variance(1e-200, 1e200),
+            Err(Error::VarianceOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_from_quantiles() {
+        for &(scale, shape) in &[(1.0, 1.0), (2.0, 0.5), (0.5, 3.5), (10.0, 20.0)] {
+            let expected = Weibull::new(scale, shape).unwrap();
+            let (x1, x2) = (expected.quantile(0.5), expected.quantile(0.95));
+            let weibull = Weibull::from_quantiles((0.5, x1), (0.95, x2)).unwrap();
+            assert_almost_eq!(weibull.inv_shape.recip(), shape, 1e-12 * shape);
+            assert_almost_eq!(weibull.scale, scale, 1e-12 * scale);
+        }
+
+        assert_eq!(
+            Weibull::from_quantiles((0.1, -1.0), (0.9, 1.0)),
+            Err(Error::QuantilesOutOfRange)
+        );
+        assert_eq!(
+            Weibull::from_quantiles((0.1, 1.0), (0.1, 2.0)),
+            Err(Error::QuantilesOutOfRange)
+        );
+    }
 }
@@ -0,0 +1,383 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Wishart and inverse Wishart distributions.
+
+use crate::linalg::{
+    cholesky, factorize, invert_lower, lower_gram, lower_mul, lower_outer, MatrixError,
+};
+use crate::{ChiSquared, Distribution, Exp1, Open01, StandardNormal};
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+
+/// The [Wishart distribution](https://en.wikipedia.org/wiki/Wishart_distribution)
+/// `W(Σ, ν)` of symmetric, positive-definite `N × N` matrices.
+///
+/// The distribution of the scatter matrix `∑ Xᵢ Xᵢᵀ` of `ν` independent
+/// samples `Xᵢ` of the [`MultivariateNormal`](crate::MultivariateNormal)
+/// distribution with mean zero and covariance matrix `Σ`, generalized to
+/// real degrees of freedom `ν`. It has the mean `ν Σ`, and with `N = 1` it
+/// is the [`ChiSquared`] distribution with `ν` degrees of freedom scaled by
+/// `Σ`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, Wishart};
+///
+/// let wishart = Wishart::new([[1.0, 0.5], [0.5, 1.0]], 5.0).unwrap();
+/// let m = wishart.sample(&mut rand::rng());
+/// println!("{:?} is from a Wishart distribution", m);
+/// ```
+///
+/// # Implementation details
+///
+/// Samples are `L A Aᵀ Lᵀ` for the Cholesky factor `L` of the scale matrix
+/// and a random lower triangular matrix `A` given by the Bartlett
+/// decomposition: the squares of its diagonal entries `Aᵢᵢ` follow the
+/// [`ChiSquared`] distribution with `ν - i` degrees of freedom, for
+/// `i = 0, …, N - 1`, and its entries below the diagonal are independent
+/// samples of [`StandardNormal`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "F: serde::Serialize")))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "F: serde::Deserialize<'de>"))
+)]
+pub struct Wishart<F, const N: usize>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// The Cholesky factor of the scale matrix
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[[serde_with::Same; N]; N]>")
+    )]
+    factor: [[F; N]; N],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    chi: [ChiSquared<F>; N],
+}
+
+/// The [inverse Wishart distribution](https://en.wikipedia.org/wiki/Inverse-Wishart_distribution)
+/// `W⁻¹(Ψ, ν)` of symmetric, positive-definite `N × N` matrices.
+///
+/// The distribution of the inverse of a matrix following the [`Wishart`]
+/// distribution `W(Ψ⁻¹, ν)`. It is the conjugate prior of the covariance
+/// matrix of the [`MultivariateNormal`](crate::MultivariateNormal)
+/// distribution. Its mean is `Ψ / (ν - N - 1)` for `ν > N + 1`.
+///
+/// # Example
+///
+/// ```
+/// use rand_distr::{Distribution, InverseWishart};
+///
+/// let inverse_wishart = InverseWishart::new([[1.0, 0.5], [0.5, 1.0]], 5.0).unwrap();
+/// let m = inverse_wishart.sample(&mut rand::rng());
+/// println!("{:?} is from an inverse Wishart distribution", m);
+/// ```
+///
+/// # Implementation details
+///
+/// With the Cholesky factor `C` of `Ψ⁻¹` and the Bartlett matrix `A` as for
+/// the [`Wishart`] distribution, samples are `Mᵀ M` for the lower triangular
+/// matrix `M = A⁻¹ C⁻¹`, so that only triangular matrices are inverted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "F: serde::Serialize")))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "F: serde::Deserialize<'de>"))
+)]
+pub struct InverseWishart<F, const N: usize>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// The inverse of the Cholesky factor of the inverse of the scale matrix
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[[serde_with::Same; N]; N]>")
+    )]
+    inv_factor: [[F; N]; N],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; N]>")
+    )]
+    chi: [ChiSquared<F>; N],
+}
+
+/// Error type returned from [`Wishart::new`] and [`InverseWishart::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `N == 0`.
+    BadDimension,
+    /// The scale matrix contains a value that is infinite or `nan`.
+    NotFinite,
+    /// The scale matrix is not symmetric.
+    NotSymmetric,
+    /// The scale matrix is not positive-definite.
+    NotPositiveDefinite,
+    /// `dof < N`, infinite or `nan`.
+    DofOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::BadDimension => "zero dimensions in Wishart distribution",
+            Error::NotFinite => "non-finite scale matrix in Wishart distribution",
+            Error::NotSymmetric => "scale matrix is not symmetric in Wishart distribution",
+            Error::NotPositiveDefinite => {
+                "scale matrix is not positive-definite in Wishart distribution"
+            }
+            Error::DofOutOfRange => {
+                "degrees of freedom are less than the dimension or not finite in Wishart distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<MatrixError> for Error {
+    fn from(error: MatrixError) -> Self {
+        match error {
+            MatrixError::Infinite => Error::NotFinite,
+            MatrixError::Asymmetric => Error::NotSymmetric,
+            MatrixError::Indefinite => Error::NotPositiveDefinite,
+        }
+    }
+}
+
+/// Check the parameters, and return the Cholesky factor of the scale matrix
+/// and the distributions of the squared diagonal of the Bartlett matrix.
+#[allow(clippy::type_complexity)]
+fn bartlett_params<F, const N: usize>(
+    scale: [[F; N]; N],
+    dof: F,
+) -> Result<([[F; N]; N], [ChiSquared<F>; N]), Error>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    if N == 0 {
+        return Err(Error::BadDimension);
+    }
+    let mut factor = scale;
+    factorize(&mut factor[..])?;
+    if !(dof >= F::from(N).unwrap() && dof.is_finite()) {
+        return Err(Error::DofOutOfRange);
+    }
+    // All degrees of freedom are at least 1
+    let chi = core::array::from_fn(|i| ChiSquared::new(dof - F::from(i).unwrap()).unwrap());
+    Ok((factor, chi))
+}
+
+/// Sample the lower triangular matrix `A` of the Bartlett decomposition.
+fn bartlett<F, R, const N: usize>(chi: &[ChiSquared<F>; N], rng: &mut R) -> [[F; N]; N]
+where
+    F: Float,
+    R: Rng + ?Sized,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    let mut a = [[F::zero(); N]; N];
+    for (i, row) in a.iter_mut().enumerate() {
+        for x in row[..i].iter_mut() {
+            *x = rng.sample(StandardNormal);
+        }
+        row[i] = chi[i].sample(rng).sqrt();
+    }
+    a
+}
+
+impl<F, const N: usize> Wishart<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `Wishart` with the given `scale` matrix and degrees of
+    /// freedom `dof`.
+    ///
+    /// Requires `N >= 1`, a finite, symmetric and positive-definite scale
+    /// matrix, which is checked by computing its Cholesky decomposition, and
+    /// finite `dof >= N`.
+    pub fn new(scale: [[F; N]; N], dof: F) -> Result<Wishart<F, N>, Error> {
+        let (factor, chi) = bartlett_params(scale, dof)?;
+        Ok(Wishart { factor, chi })
+    }
+}
+
+impl<F, const N: usize> Distribution<[[F; N]; N]> for Wishart<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [[F; N]; N] {
+        let a = bartlett(&self.chi, rng);
+        let mut m = [[F::zero(); N]; N];
+        lower_mul(&self.factor[..], &a[..], &mut m[..]);
+        let mut x = [[F::zero(); N]; N];
+        lower_outer(&m[..], &mut x[..]);
+        x
+    }
+}
+
+impl<F, const N: usize> InverseWishart<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `InverseWishart` with the given `scale` matrix and
+    /// degrees of freedom `dof`.
+    ///
+    /// Requires `N >= 1`, a finite, symmetric and positive-definite scale
+    /// matrix, which is checked by computing its Cholesky decomposition, and
+    /// finite `dof >= N`.
+    pub fn new(scale: [[F; N]; N], dof: F) -> Result<InverseWishart<F, N>, Error> {
+        let (mut factor, chi) = bartlett_params(scale, dof)?;
+        // Ψ⁻¹ = L⁻ᵀ L⁻¹ for Ψ = L Lᵀ
+        invert_lower(&mut factor[..]);
+        let mut inv_factor = [[F::zero(); N]; N];
+        lower_gram(&factor[..], &mut inv_factor[..]);
+        if !cholesky(&mut inv_factor[..]) {
+            return Err(Error::NotPositiveDefinite);
+        }
+        invert_lower(&mut inv_factor[..]);
+        Ok(InverseWishart { inv_factor, chi })
+    }
+}
+
+impl<F, const N: usize> Distribution<[[F; N]; N]> for InverseWishart<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [[F; N]; N] {
+        let mut a = bartlett(&self.chi, rng);
+        invert_lower(&mut a[..]);
+        let mut m = [[F::zero(); N]; N];
+        lower_mul(&a[..], &self.inv_factor[..], &mut m[..]);
+        let mut x = [[F::zero(); N]; N];
+        lower_gram(&m[..], &mut x[..]);
+        x
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let wishart = Wishart::new([[4.0, 2.0], [2.0, 10.0]], 2.0).unwrap();
+        assert_eq!(wishart.factor, [[2.0, 0.0], [1.0, 3.0]]);
+        assert_eq!(wishart.chi[1], ChiSquared::new(1.0).unwrap());
+        // Ψ⁻¹ = [[1, 0], [0, 4]]
+        let inverse_wishart = InverseWishart::new([[1.0, 0.0], [0.0, 0.25]], 2.5).unwrap();
+        assert_eq!(inverse_wishart.inv_factor, [[1.0, 0.0], [0.0, 0.5]]);
+
+        let scale = [[1.0, 0.0], [0.0, 1.0]];
+        assert_eq!(Wishart::<f64, 0>::new([], 1.0), Err(Error::BadDimension));
+        assert_eq!(
+            Wishart::new([[1.0, 0.0], [0.0, f64::NAN]], 2.0),
+            Err(Error::NotFinite)
+        );
+        assert_eq!(
+            Wishart::new([[1.0, 0.1], [0.0, 1.0]], 2.0),
+            Err(Error::NotSymmetric)
+        );
+        assert_eq!(
+            InverseWishart::new([[1.0, 2.0], [2.0, 1.0]], 2.0),
+            Err(Error::NotPositiveDefinite)
+        );
+        assert_eq!(Wishart::new(scale, 1.9), Err(Error::DofOutOfRange));
+        assert_eq!(
+            InverseWishart::new(scale, f64::INFINITY),
+            Err(Error::DofOutOfRange)
+        );
+        assert_eq!(
+            InverseWishart::new(scale, f64::NAN),
+            Err(Error::DofOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_one_dimension() {
+        // W([[σ²]], ν) is σ² χ²(ν), and W⁻¹([[ψ]], ν) is ψ / χ²(ν)
+        let mut rng = crate::test::rng(241);
+        let wishart = Wishart::new([[2.0]], 3.0).unwrap();
+        let inverse_wishart = InverseWishart::new([[2.0]], 3.0).unwrap();
+        let chi = ChiSquared::new(3.0).unwrap();
+        for _ in 0..100 {
+            let [[x]] = wishart.sample(&mut rng);
+            let [[y]] = inverse_wishart.sample(&mut rng);
+            assert!(x > 0.0 && y > 0.0);
+        }
+        let mut a = crate::test::rng(242);
+        let mut b = crate::test::rng(242);
+        let [[x]] = inverse_wishart.sample(&mut a);
+        assert_almost_eq!(x, 2.0 / chi.sample(&mut b), 1e-14);
+    }
+
+    #[test]
+    fn test_mean() {
+        const SAMPLES: usize = 100_000;
+        let scale = [[2.0, -0.6], [-0.6, 1.0]];
+        let mut rng = crate::test::rng(243);
+
+        let dof = 5.0;
+        let wishart = Wishart::new(scale, dof).unwrap();
+        let mut sum = [[0.0; 2]; 2];
+        for _ in 0..SAMPLES {
+            let x = wishart.sample(&mut rng);
+            assert_eq!(x[0][1], x[1][0]);
+            for (s, x) in sum.iter_mut().flatten().zip(x.iter().flatten()) {
+                *s += x;
+            }
+        }
+        for (s, x) in sum.iter().flatten().zip(scale.iter().flatten()) {
+            assert_almost_eq!(s / SAMPLES as f64, dof * x, 0.05);
+        }
+
+        let dof = 8.0;
+        let inverse_wishart = InverseWishart::new(scale, dof).unwrap();
+        let mut sum = [[0.0; 2]; 2];
+        for _ in 0..SAMPLES {
+            let x = inverse_wishart.sample(&mut rng);
+            for (s, x) in sum.iter_mut().flatten().zip(x.iter().flatten()) {
+                *s += x;
+            }
+        }
+        for (s, x) in sum.iter().flatten().zip(scale.iter().flatten()) {
+            assert_almost_eq!(s / SAMPLES as f64, x / (dof - 3.0), 0.005);
+        }
+    }
+}
@@ -8,7 +8,14 @@
 
 //! The Zeta distribution.
 
-use crate::{Distribution, StandardUniform};
+use crate::fit::{bracketed_root, Counts};
+use crate::moments::{excess_kurtosis_from_raw, skewness_from_raw, variance_from_raw};
+use crate::quantile::discrete_quantile;
+use crate::special::{harmonic, power_log_sum, power_sum, zeta};
+use crate::{
+    Cdf, DiscretePmf, Distribution, Entropy, FitError, FitOptions, Fitted, Moments, Quantile,
+    StandardUniform, Summary, Support,
+};
 use core::fmt;
 use num_traits::Float;
 use rand::{distr::OpenClosed01, Rng};
@@ -112,6 +119,105 @@ where
             b: two.powf(s_minus_1),
         })
     }
+
+    /// Fit to observations by maximum likelihood, with the default
+    /// [`FitOptions`].
+    ///
+    /// See [`Zeta::fit_with`].
+    pub fn fit(data: &[u64]) -> Result<Fitted<Zeta<F>, F>, FitError> {
+        Self::fit_with(data, FitOptions::new())
+    }
+
+    /// Fit to observations by maximum likelihood.
+    ///
+    /// The estimate of `s` solves `-ζ'(s) / ζ(s) = mean(ln x)`, where the
+    /// left side is `E[ln X]` and decreases from `+inf` at `s = 1` to zero,
+    /// and is found by the method of false position.
+    ///
+    /// Fails if `data` is empty, its values are all one or any is zero, or
+    /// if the iteration does not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Zeta;
+    ///
+    /// let fitted = Zeta::<f64>::fit(&[1, 1, 1, 2, 1, 3, 2, 1, 12, 1]).unwrap();
+    /// println!("{:?} after {} iterations", fitted.distribution, fitted.iterations);
+    /// ```
+    pub fn fit_with(data: &[u64], options: FitOptions<F>) -> Result<Fitted<Zeta<F>, F>, FitError> {
+        Self::fit_counts(Counts::new(data.iter().map(|&k| (k, 1)))?, options)
+    }
+
+    /// Fit to observations, given as a frequency table of
+    /// `(value, frequency)` pairs, by maximum likelihood with the default
+    /// [`FitOptions`].
+    ///
+    /// See [`Zeta::fit_with`].
+    pub fn fit_frequencies(table: &[(u64, u64)]) -> Result<Fitted<Zeta<F>, F>, FitError> {
+        Self::fit_frequencies_with(table, FitOptions::new())
+    }
+
+    /// Fit to observations, given as a frequency table of
+    /// `(value, frequency)` pairs, by maximum likelihood.
+    ///
+    /// See [`Zeta::fit_with`].
+    pub fn fit_frequencies_with(
+        table: &[(u64, u64)],
+        options: FitOptions<F>,
+    ) -> Result<Fitted<Zeta<F>, F>, FitError> {
+        Self::fit_counts(Counts::new(table.iter().copied())?, options)
+    }
+
+    fn fit_counts(
+        counts: Counts<F>,
+        options: FitOptions<F>,
+    ) -> Result<Fitted<Zeta<F>, F>, FitError> {
+        if counts.min == 0 {
+            return Err(FitError::OutOfSupport);
+        }
+        if counts.max == 1 {
+            return Err(FitError::Constant);
+        }
+        let (one, two) = (F::one(), F::from(2.0).unwrap());
+        let g = |s: F| power_log_sum(s, one, F::infinity()) / zeta(s) - counts.mean_ln;
+        // Bracket the root, doubling `s` or halving `s - 1` from `s = 2`
+        let (mut lo, mut hi) = (two, two);
+        if g(two) > F::zero() {
+            while g(hi) > F::zero() {
+                lo = hi;
+                hi = hi + hi;
+                if !hi.is_finite() {
+                    return Err(FitError::NoConvergence);
+                }
+            }
+        } else {
+            while g(lo) <= F::zero() {
+                hi = lo;
+                lo = one + (lo - one) / two;
+                if lo == one {
+                    return Err(FitError::NoConvergence);
+                }
+            }
+        }
+        let (s, iterations) = bracketed_root(g, lo, hi, options)?;
+        let log_likelihood = -counts.total * (s * counts.mean_ln + zeta(s).ln());
+        Ok(Fitted {
+            distribution: Zeta::new(s).map_err(|_| FitError::NoConvergence)?,
+            iterations,
+            log_likelihood,
+        })
+    }
+
+    /// The raw moment `E[X^k] = ζ(s - k) / ζ(s)`, if `k < s - 1`.
+    fn raw_moment(&self, k: f64) -> Option<F> {
+        let k = F::from(k).unwrap();
+        if !(k < self.s_minus_1) {
+            return None;
+        }
+        let s = self.s_minus_1 + F::one();
+        Some(zeta(s - k) / zeta(s))
+    }
 }
 
 impl<F> Distribution<F> for Zeta<F>
@@ -143,6 +249,122 @@ where
     }
 }
 
+impl<F> DiscretePmf<F> for Zeta<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    OpenClosed01: Distribution<F>,
+{
+    fn ln_pmf(&self, k: u64) -> F {
+        if k == 0 {
+            return F::neg_infinity();
+        }
+        let s = self.s_minus_1 + F::one();
+        -s * F::from(k).unwrap().ln() - zeta(s).ln()
+    }
+}
+
+impl<F> Cdf<F> for Zeta<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    OpenClosed01: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        let k = x.floor();
+        if !(k >= F::one()) {
+            return F::zero();
+        }
+        let s = self.s_minus_1 + F::one();
+        harmonic(k, s) / zeta(s)
+    }
+
+    fn sf(&self, x: F) -> F {
+        let k = x.floor();
+        if !(k >= F::one()) {
+            return F::one();
+        }
+        let s = self.s_minus_1 + F::one();
+        power_sum(s, k + F::one(), F::infinity()) / zeta(s)
+    }
+}
+
+impl<F> Quantile<F> for Zeta<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    OpenClosed01: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        let one = F::one();
+        discrete_quantile(self, p, one, F::infinity(), one)
+    }
+}
+
+impl<F> Moments<F> for Zeta<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    OpenClosed01: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        self.raw_moment(1.0)
+    }
+
+    fn variance(&self) -> Option<F> {
+        let (m1, m2) = (self.raw_moment(1.0)?, self.raw_moment(2.0)?);
+        Some(variance_from_raw(m1, m2))
+    }
+
+    fn skewness(&self) -> Option<F> {
+        let (m1, m2) = (self.raw_moment(1.0)?, self.raw_moment(2.0)?);
+        Some(skewness_from_raw(m1, m2, self.raw_moment(3.0)?))
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        let (m1, m2) = (self.raw_moment(1.0)?, self.raw_moment(2.0)?);
+        let (m3, m4) = (self.raw_moment(3.0)?, self.raw_moment(4.0)?);
+        Some(excess_kurtosis_from_raw(m1, m2, m3, m4))
+    }
+}
+
+impl<F> Entropy<F> for Zeta<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    OpenClosed01: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // -ln p(k) = s ln k + ln ζ(s), where ζ(s) - 1 is kept separate so
+        // that the entropy keeps its relative precision for large `s`
+        let s = self.s_minus_1 + F::one();
+        let tail = power_sum(s, F::from(2.0).unwrap(), F::infinity());
+        tail.ln_1p() + s * power_log_sum(s, F::one(), F::infinity()) / (F::one() + tail)
+    }
+}
+
+impl<F> Summary<F> for Zeta<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+    OpenClosed01: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::Integer {
+            lower: 1,
+            upper: None,
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        Some(F::one())
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,8 +418,100 @@ mod tests {
         test_samples(Zeta::new(2.0).unwrap(), 0f64, &[2.0, 1.0, 1.0, 1.0]);
     }
 
+    #[test]
+    fn zeta_pmf() {
+        let d = Zeta::new(2.0).unwrap();
+        assert_almost_eq!(d.pmf(1), 0.60792710185402662866, 1e-15);
+        assert_almost_eq!(d.pmf(3), 0.067547455761558510546, 1e-15);
+        assert_eq!(d.pmf(0), 0.0);
+        let d = Zeta::new(1.5).unwrap();
+        assert_almost_eq!(d.ln_pmf(1_000_000), -21.683525739677196384, 1e-12);
+    }
+
+    #[test]
+    fn zeta_cdf() {
+        let d = Zeta::new(2.0).unwrap();
+        assert_almost_eq!(d.cdf(1.0), 0.60792710185402662866, 1e-15);
+        assert_almost_eq!(d.cdf(3.5), 0.82745633307909180013, 1e-15);
+        assert_almost_eq!(d.sf(3.0), 0.17254366692090819987, 1e-15);
+        assert_almost_eq!(d.sf(1e6), 6.0792679789057702283e-7, 1e-20);
+        assert_eq!(d.cdf(0.5), 0.0);
+        assert_eq!(d.sf(0.0), 1.0);
+    }
+
+    #[test]
+    fn zeta_quantile() {
+        let d = Zeta::new(2.0).unwrap();
+        assert_eq!(d.quantile(0.5), 1.0);
+        assert_eq!(d.quantile(0.7), 2.0);
+        assert_eq!(d.quantile(0.9), 6.0);
+        assert_eq!(d.quantile(0.99), 61.0);
+        assert_eq!(d.quantile(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn zeta_moments() {
+        let d = Zeta::new(6.0).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 1.0192508249092675815, 1e-10);
+        assert_almost_eq!(d.variance().unwrap(), 0.025000184166324160044, 1e-10);
+        assert_almost_eq!(d.skewness().unwrap(), 11.700091181667591898, 1e-10);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 306.19002439080363966, 1e-10);
+        let d = Zeta::new(3.0).unwrap();
+        assert!(d.mean().is_some());
+        assert_eq!(d.variance(), None);
+    }
+
+    #[test]
+    fn zeta_entropy() {
+        let d = Zeta::new(2.0).unwrap();
+        assert_almost_eq!(d.entropy(), 1.6376222886598109603, 1e-15);
+        let d = Zeta::new(1.5).unwrap();
+        assert_almost_eq!(d.entropy(), 3.2181129364131871073, 1e-14);
+        let d = Zeta::new(30.0).unwrap();
+        assert_almost_eq!(d.entropy(), 2.0297796030349550140e-8, 1e-22);
+    }
+
+    #[test]
+    fn zeta_summary() {
+        let d = Zeta::new(2.0).unwrap();
+        assert_eq!(
+            d.support(),
+            Support::Integer {
+                lower: 1,
+                upper: None
+            }
+        );
+        assert_eq!((d.mode(), d.median()), (Some(1.0), 1.0));
+    }
+
     #[test]
     fn zeta_distributions_can_be_compared() {
         assert_eq!(Zeta::new(1.0), Zeta::new(1.0));
     }
+
+    #[test]
+    fn test_zeta_fit() {
+        let data = [1, 1, 1, 2, 1, 3, 2, 1, 12, 1];
+        let fitted = Zeta::<f64>::fit(&data).unwrap();
+        let s = fitted.distribution.s_minus_1 + 1.0;
+        assert_almost_eq!(s, 2.0908347246951163935, 1e-11);
+        assert_almost_eq!(fitted.log_likelihood, -14.884549680045074939, 1e-11);
+        let table = [(1, 6), (2, 2), (3, 1), (12, 1)];
+        let from_table = Zeta::<f64>::fit_frequencies(&table).unwrap();
+        assert_almost_eq!(from_table.distribution.s_minus_1, s - 1.0, 1e-14);
+
+        let mut rng = crate::test::rng(231);
+        for &s in &[1.1f64, 2.0, 6.0] {
+            let dist = Zeta::new(s).unwrap();
+            let data: [u64; 5000] = core::array::from_fn(|_| dist.sample(&mut rng) as u64);
+            let d = Zeta::<f64>::fit(&data).unwrap().distribution;
+            assert_almost_eq!(d.s_minus_1 + 1.0, s, 0.05 * s);
+        }
+
+        assert_eq!(Zeta::<f64>::fit(&[]), Err(FitError::Empty));
+        assert_eq!(Zeta::<f64>::fit(&[1, 1]), Err(FitError::Constant));
+        assert_eq!(Zeta::<f64>::fit(&[0, 2]), Err(FitError::OutOfSupport));
+        let options = FitOptions::<f64>::new().with_max_iterations(1);
+        assert_eq!(Zeta::fit_with(&data, options), Err(FitError::NoConvergence));
+    }
 }
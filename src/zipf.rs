@@ -8,7 +8,14 @@
 
 //! The Zipf distribution.
 
-use crate::{Distribution, StandardUniform};
+use crate::fit::{bracketed_root, Counts};
+use crate::moments::{excess_kurtosis_from_raw, skewness_from_raw, variance_from_raw};
+use crate::quantile::discrete_quantile;
+use crate::special::{harmonic, power_log_sum, power_sum};
+use crate::{
+    Cdf, DiscretePmf, Distribution, Entropy, FitError, FitOptions, Fitted, Moments, Quantile,
+    StandardUniform, Summary, Support,
+};
 use core::fmt;
 use num_traits::Float;
 use rand::Rng;
@@ -58,6 +65,7 @@ where
     F: Float,
     StandardUniform: Distribution<F>,
 {
+    n: F,
     s: F,
     t: F,
     q: F,
@@ -116,7 +124,96 @@ where
             F::one() + n.ln()
         };
         debug_assert!(t > F::zero());
-        Ok(Zipf { s, t, q })
+        Ok(Zipf { n, s, t, q })
+    }
+
+    /// Fit the exponent `s` to observed ranks in `1..=n` by maximum
+    /// likelihood, with the default [`FitOptions`].
+    ///
+    /// See [`Zipf::fit_with`].
+    pub fn fit(n: F, data: &[u64]) -> Result<Fitted<Zipf<F>, F>, FitError> {
+        Self::fit_with(n, data, FitOptions::new())
+    }
+
+    /// Fit the exponent `s` to observed ranks in `1..=n` by maximum
+    /// likelihood.
+    ///
+    /// The estimate of `s` solves `E[ln X] = mean(ln x)`, where the left side
+    /// decreases with `s`, and is found by the method of false position. If
+    /// the mean is at least that of the uniform distribution `s = 0`, the
+    /// estimate is `s = 0`, the boundary of the parameter space.
+    ///
+    /// Fails if `data` is empty, its values are all one or any is not in
+    /// `1..=n`, or if the iteration does not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_distr::Zipf;
+    ///
+    /// let fitted = Zipf::fit(10.0, &[1, 1, 1, 2, 1, 3, 2, 1, 5, 1]).unwrap();
+    /// println!("{:?} after {} iterations", fitted.distribution, fitted.iterations);
+    /// ```
+    pub fn fit_with(
+        n: F,
+        data: &[u64],
+        options: FitOptions<F>,
+    ) -> Result<Fitted<Zipf<F>, F>, FitError> {
+        Self::fit_counts(n, Counts::new(data.iter().map(|&k| (k, 1)))?, options)
+    }
+
+    /// Fit the exponent `s` to observed ranks in `1..=n`, given as a
+    /// frequency table of `(rank, frequency)` pairs, by maximum likelihood
+    /// with the default [`FitOptions`].
+    ///
+    /// See [`Zipf::fit_with`].
+    pub fn fit_frequencies(n: F, table: &[(u64, u64)]) -> Result<Fitted<Zipf<F>, F>, FitError> {
+        Self::fit_frequencies_with(n, table, FitOptions::new())
+    }
+
+    /// Fit the exponent `s` to observed ranks in `1..=n`, given as a
+    /// frequency table of `(rank, frequency)` pairs, by maximum likelihood.
+    ///
+    /// See [`Zipf::fit_with`].
+    pub fn fit_frequencies_with(
+        n: F,
+        table: &[(u64, u64)],
+        options: FitOptions<F>,
+    ) -> Result<Fitted<Zipf<F>, F>, FitError> {
+        Self::fit_counts(n, Counts::new(table.iter().copied())?, options)
+    }
+
+    fn fit_counts(
+        n: F,
+        counts: Counts<F>,
+        options: FitOptions<F>,
+    ) -> Result<Fitted<Zipf<F>, F>, FitError> {
+        let n = n.floor();
+        if counts.min == 0 || !(F::from(counts.max).unwrap() <= n) {
+            return Err(FitError::OutOfSupport);
+        }
+        if counts.max == 1 {
+            return Err(FitError::Constant);
+        }
+        let g = |s: F| power_log_sum(s, F::one(), n) / harmonic(n, s) - counts.mean_ln;
+        let (s, iterations) = if g(F::zero()) <= F::zero() {
+            (F::zero(), 0)
+        } else {
+            let mut hi = F::one();
+            while g(hi) > F::zero() {
+                hi = hi + hi;
+                if !hi.is_finite() {
+                    return Err(FitError::NoConvergence);
+                }
+            }
+            bracketed_root(g, F::zero(), hi, options)?
+        };
+        let log_likelihood = -counts.total * (s * counts.mean_ln + harmonic(n, s).ln());
+        Ok(Fitted {
+            distribution: Zipf::new(n, s).map_err(|_| FitError::NoConvergence)?,
+            iterations,
+            log_likelihood,
+        })
     }
 
     /// Inverse cumulative density function
@@ -132,6 +229,12 @@ where
             (pt - one).exp()
         }
     }
+
+    /// The raw moment `E[X^k] = H(n, s - k) / H(n, s)`.
+    fn raw_moment(&self, k: f64) -> F {
+        let n = self.n.floor();
+        harmonic(n, self.s - F::from(k).unwrap()) / harmonic(n, self.s)
+    }
 }
 
 impl<F> Distribution<F> for Zipf<F>
@@ -158,6 +261,131 @@ where
     }
 }
 
+impl<F> DiscretePmf<F> for Zipf<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn ln_pmf(&self, k: u64) -> F {
+        let n = self.n.floor();
+        let k = F::from(k).unwrap();
+        if k < F::one() || k > n {
+            return F::neg_infinity();
+        }
+        -self.s * k.ln() - harmonic(n, self.s).ln()
+    }
+}
+
+impl<F> Cdf<F> for Zipf<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn cdf(&self, x: F) -> F {
+        let (n, k) = (self.n.floor(), x.floor());
+        if !(k >= F::one()) {
+            return F::zero();
+        }
+        if k >= n {
+            return F::one();
+        }
+        harmonic(k, self.s) / harmonic(n, self.s)
+    }
+
+    fn sf(&self, x: F) -> F {
+        let (n, k) = (self.n.floor(), x.floor());
+        if !(k >= F::one()) {
+            return F::one();
+        }
+        if k >= n {
+            return F::zero();
+        }
+        power_sum(self.s, k + F::one(), n) / harmonic(n, self.s)
+    }
+}
+
+impl<F> Quantile<F> for Zipf<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn quantile(&self, p: F) -> F {
+        let one = F::one();
+        discrete_quantile(self, p, one, self.n.floor(), one)
+    }
+}
+
+impl<F> Moments<F> for Zipf<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn mean(&self) -> Option<F> {
+        Some(self.raw_moment(1.0))
+    }
+
+    fn variance(&self) -> Option<F> {
+        Some(variance_from_raw(
+            self.raw_moment(1.0),
+            self.raw_moment(2.0),
+        ))
+    }
+
+    fn skewness(&self) -> Option<F> {
+        if self.n < F::from(2.0).unwrap() {
+            return None;
+        }
+        let m = |k| self.raw_moment(k);
+        Some(skewness_from_raw(m(1.0), m(2.0), m(3.0)))
+    }
+
+    fn excess_kurtosis(&self) -> Option<F> {
+        if self.n < F::from(2.0).unwrap() {
+            return None;
+        }
+        let m = |k| self.raw_moment(k);
+        Some(excess_kurtosis_from_raw(m(1.0), m(2.0), m(3.0), m(4.0)))
+    }
+}
+
+impl<F> Entropy<F> for Zipf<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn entropy(&self) -> F {
+        // -ln p(k) = s ln k + ln H(n, s)
+        let norm = harmonic(self.n, self.s);
+        norm.ln() + self.s * power_log_sum(self.s, F::one(), self.n) / norm
+    }
+}
+
+impl<F> Summary<F> for Zipf<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn support(&self) -> Support<F> {
+        Support::Integer {
+            lower: 1,
+            upper: Some(self.n.floor().to_u64().unwrap_or(u64::MAX)),
+        }
+    }
+
+    fn mode(&self) -> Option<F> {
+        // For s = 0 the distribution is uniform
+        if self.s > F::zero() || self.n < F::from(2.0).unwrap() {
+            Some(F::one())
+        } else {
+            None
+        }
+    }
+
+    fn median(&self) -> F {
+        self.quantile(F::from(0.5).unwrap())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,8 +465,113 @@ mod tests {
         test_samples(Zipf::new(10., 2.0).unwrap(), 0f64, &[1.0, 2.0, 3.0, 2.0]);
     }
 
+    #[test]
+    fn zipf_pmf() {
+        let d = Zipf::new(10., 1.5).unwrap();
+        assert_almost_eq!(d.pmf(1), 0.50116860155416165790, 1e-15);
+        assert_almost_eq!(d.pmf(4), 0.062646075194270207238, 1e-15);
+        assert_eq!(d.pmf(0), 0.0);
+        assert_eq!(d.pmf(11), 0.0);
+        let total: f64 = (1..=10).map(|k| d.pmf(k)).sum();
+        assert_almost_eq!(total, 1.0, 1e-15);
+
+        let uniform = Zipf::new(10., 0.).unwrap();
+        assert_almost_eq!(uniform.pmf(7), 0.1, 1e-16);
+        let d = Zipf::new(1e12, 1.0).unwrap();
+        assert_almost_eq!(d.ln_pmf(1), -3.3396140197223325285, 1e-14);
+        let d = Zipf::new(10.0f32, 2.0).unwrap();
+        assert_almost_eq!(d.pmf(2), 0.16131450, 1e-7);
+    }
+
+    #[test]
+    fn zipf_cdf() {
+        let d = Zipf::new(10., 1.5).unwrap();
+        assert_almost_eq!(d.cdf(1.0), 0.50116860155416165790, 1e-15);
+        assert_almost_eq!(d.cdf(4.5), 0.83745447742568967980, 1e-15);
+        assert_almost_eq!(d.sf(4.0), 0.16254552257431032020, 1e-15);
+        assert_eq!(d.cdf(0.5), 0.0);
+        assert_eq!(d.cdf(10.0), 1.0);
+        assert_eq!(d.sf(10.0), 0.0);
+        let d = Zipf::new(1e12, 1.0).unwrap();
+        assert_almost_eq!(d.sf(1e6), 0.48976865038772780992, 1e-14);
+    }
+
+    #[test]
+    fn zipf_quantile() {
+        let d = Zipf::new(10.0, 1.5).unwrap();
+        assert_eq!(d.quantile(0.3), 1.0);
+        assert_eq!(d.quantile(0.6), 2.0);
+        assert_eq!(d.quantile(0.9), 6.0);
+        assert_eq!(d.quantile(0.0), 1.0);
+        assert_eq!(d.quantile(1.0), 10.0);
+    }
+
+    #[test]
+    fn zipf_moments() {
+        let d = Zipf::new(10.0, 1.5).unwrap();
+        assert_almost_eq!(d.mean().unwrap(), 2.5163664955948890798, 1e-13);
+        assert_almost_eq!(d.variance().unwrap(), 4.9282952177572817433, 1e-13);
+        assert_almost_eq!(d.skewness().unwrap(), 1.6785770495408337254, 1e-13);
+        assert_almost_eq!(d.excess_kurtosis().unwrap(), 2.071312446161207096, 1e-13);
+    }
+
+    #[test]
+    fn zipf_entropy() {
+        let d = Zipf::new(100.0, 1.5).unwrap();
+        assert_almost_eq!(d.entropy(), 2.5055428519938776462, 1e-14);
+        let d = Zipf::new(1e12, 0.5).unwrap();
+        assert_almost_eq!(d.entropy(), 27.324177904574869108, 1e-12);
+        let d = Zipf::new(10.0, 0.0).unwrap();
+        assert_almost_eq!(d.entropy(), core::f64::consts::LN_10, 1e-15);
+        assert_eq!(Zipf::new(1.0, 2.0).unwrap().entropy(), 0.0);
+    }
+
+    #[test]
+    fn zipf_summary() {
+        let d = Zipf::new(10.0, 1.5).unwrap();
+        let support = Support::Integer {
+            lower: 1,
+            upper: Some(10),
+        };
+        assert_eq!(d.support(), support);
+        assert_eq!((d.mode(), d.median()), (Some(1.0), 1.0));
+        assert_eq!(Zipf::new(10.0, 0.0).unwrap().mode(), None);
+    }
+
     #[test]
     fn zipf_distributions_can_be_compared() {
         assert_eq!(Zipf::new(1.0, 2.0), Zipf::new(1.0, 2.0));
     }
+
+    #[test]
+    fn test_zipf_fit() {
+        let data = [1, 1, 1, 2, 1, 3, 2, 1, 5, 1];
+        let fitted = Zipf::fit(10.0, &data).unwrap();
+        assert_almost_eq!(fitted.distribution.s, 1.9725732524959641058, 1e-11);
+        assert_almost_eq!(fitted.log_likelihood, -12.568316437056252827, 1e-11);
+        let table = [(1, 6), (2, 2), (3, 1), (5, 1)];
+        let from_table = Zipf::fit_frequencies(10.0, &table).unwrap();
+        assert_almost_eq!(from_table.distribution.s, fitted.distribution.s, 1e-14);
+        // Ranks that are more uniform than the uniform distribution
+        let fitted = Zipf::fit(10.0, &[9, 10]).unwrap();
+        assert_eq!((fitted.distribution.s, fitted.iterations), (0.0, 0));
+
+        let mut rng = crate::test::rng(230);
+        for &(n, s) in &[(10.0, 0.5), (1000.0, 1.0), (1e6, 2.5)] {
+            let dist = Zipf::new(n, s).unwrap();
+            let data: [u64; 5000] = core::array::from_fn(|_| dist.sample(&mut rng) as u64);
+            let d = Zipf::fit(n, &data).unwrap().distribution;
+            assert_almost_eq!(d.s, s, 0.05);
+        }
+
+        assert_eq!(Zipf::fit(10.0, &[]), Err(FitError::Empty));
+        assert_eq!(Zipf::fit(10.0, &[1, 1]), Err(FitError::Constant));
+        assert_eq!(Zipf::fit(10.0, &[0, 2]), Err(FitError::OutOfSupport));
+        assert_eq!(Zipf::fit(10.0, &[2, 11]), Err(FitError::OutOfSupport));
+        let options = FitOptions::new().with_max_iterations(1);
+        assert_eq!(
+            Zipf::fit_with(10.0, &data, options),
+            Err(FitError::NoConvergence)
+        );
+    }
 }